@@ -7,8 +7,80 @@ pub const MAX_DELAY_SECONDS: i64 = 365 * SECONDS_PER_DAY;
 // Default number of seconds until a transaction expires.
 pub const DEFAULT_GRACE_PERIOD: i64 = 14 * SECONDS_PER_DAY;
 
+// Maximum guardians change period and action expiry of a [crate::GlobalState].
+pub const MAX_GUARDIANS_PERIOD: i64 = 365 * SECONDS_PER_DAY;
+
+// Notice period before changed global thresholds can be activated.
+pub const GLOBAL_THRESHOLDS_NOTICE_PERIOD: i64 = 3 * SECONDS_PER_DAY;
+
 // Constant declaring that there is no ETA of the transaction
 pub const NO_ETA: i64 = -1;
 
 // Anchor discriminator for set_frozen instruction
 pub const SET_FROZEN_DISCRIMINATOR: [u8; 8] = [62, 87, 99, 96, 206, 47, 204, 18];
+
+// Current layout version of [crate::GlobalState] accounts.
+pub const GLOBAL_STATE_VERSION: u8 = 1;
+
+// Current layout version of [crate::Smallet] accounts.
+pub const SMALLET_VERSION: u8 = 2;
+
+// Current layout version of [crate::Transaction] accounts.
+pub const TRANSACTION_VERSION: u8 = 4;
+
+// Current layout version of [crate::GuardianAction] accounts.
+pub const GUARDIAN_ACTION_VERSION: u8 = 1;
+
+// Current layout version of [crate::SubaccountInfo] accounts.
+pub const SUBACCOUNT_INFO_VERSION: u8 = 1;
+
+// Maximum number of value tiers a smallet can hold.
+pub const MAX_VALUE_TIERS: usize = 8;
+
+// Maximum number of per-action guardian quorums a smallet can hold.
+pub const MAX_GUARDIAN_QUORUMS: usize = 8;
+
+// Owner role allowing to propose transactions.
+pub const ROLE_PROPOSER: u8 = 1 << 0;
+
+// Owner role allowing to approve transactions, directly or through a session.
+pub const ROLE_APPROVER: u8 = 1 << 1;
+
+// Owner role allowing to execute approved transactions.
+pub const ROLE_EXECUTOR: u8 = 1 << 2;
+
+// Owner role allowing to freeze the smallet.
+pub const ROLE_FREEZER: u8 = 1 << 3;
+
+// Owner role allowing to invoke instructions as an owner invoker.
+pub const ROLE_INVOKER: u8 = 1 << 4;
+
+// All owner roles. Owners without explicit roles hold every role.
+pub const ROLE_ALL: u8 = ROLE_PROPOSER | ROLE_APPROVER | ROLE_EXECUTOR | ROLE_FREEZER | ROLE_INVOKER;
+
+// Domain of the original singleton [crate::GlobalState].
+pub const DEFAULT_DOMAIN: u64 = 0;
+
+// Maximum number of prerequisites of a [crate::Transaction].
+pub const MAX_PREREQUISITES: usize = 8;
+
+// Maximum number of assertions of a [crate::Transaction].
+pub const MAX_ASSERTIONS: usize = 16;
+
+// The address lookup table program, AddressLookupTab1e1111111111111111111111111.
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: anchor_lang::prelude::Pubkey =
+    anchor_lang::prelude::Pubkey::new_from_array([
+        2, 119, 166, 175, 151, 51, 155, 122, 200, 141, 24, 146, 201, 4, 70, 245, 0, 2, 48, 146,
+        102, 246, 46, 83, 193, 24, 36, 73, 130, 0, 0, 0,
+    ]);
+
+// The Token-2022 program, TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb, whose transfers
+// share the layout of SPL token ones.
+pub const TOKEN_2022_PROGRAM_ID: anchor_lang::prelude::Pubkey =
+    anchor_lang::prelude::Pubkey::new_from_array([
+        6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108, 205, 218, 182, 26, 252,
+        77, 131, 185, 13, 39, 254, 189, 249, 40, 216, 161, 139, 252,
+    ]);
+
+// Size of the metadata preceding the addresses of an address lookup table.
+pub const LOOKUP_TABLE_META_SIZE: usize = 56;
@@ -19,6 +19,15 @@ pub struct WalletCreateEvent {
     // Unix timestamp when the event was emitted
     pub timestamp: i64,
 }
+// Emitted when a smallet account is resized
+#[event]
+pub struct WalletResizeEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // The new account size in bytes
+    pub space: u64,
+    pub timestamp: i64,
+}
 // Emitted when owners of a smallet are changed.
 #[event]
 pub struct WalletSetOwnersEvent {
@@ -30,6 +39,34 @@ pub struct WalletSetOwnersEvent {
     // Unix timestamp when event was emitted
     pub timestamp: i64,
 }
+// Emitted when the roles of the owners of a smallet are changed.
+#[event]
+pub struct WalletSetOwnerRolesEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // The new role bitmask of every owner
+    pub roles: Vec<u8>,
+    pub timestamp: i64,
+}
+// Emitted when enough guardians agreed and a guardian action is performed
+#[event]
+pub struct GuardianActionPerformEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // The performed guardian action
+    pub guardian_action: Pubkey,
+    pub action_type: GuardianActionType,
+    pub timestamp: i64,
+}
+// Emitted when guardians of a smallet are changed.
+#[event]
+pub struct WalletSetGuardiansEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // The new guardians of the smallet
+    pub guardians: Vec<Pubkey>,
+    pub timestamp: i64,
+}
 // Emitted when the threshold of a smallet is changed
 #[event]
 pub struct WalletChangeThresholdEvent {
@@ -39,6 +76,15 @@ pub struct WalletChangeThresholdEvent {
     pub threshold: u64,
     pub timestamp: i64,
 }
+// Emitted when the value tiers of a smallet are changed
+#[event]
+pub struct WalletSetValueTiersEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // The new value tiers
+    pub value_tiers: Vec<ValueTier>,
+    pub timestamp: i64,
+}
 // Emitted when a transaction is proposed
 #[event]
 pub struct TransactionCreateEvent {
@@ -54,6 +100,49 @@ pub struct TransactionCreateEvent {
     // Transaction ETA
     pub eta: i64,
     pub timestamp: i64,
+    // Time after which the transaction can no longer be approved or executed
+    pub expires_at: i64,
+    // Address lookup tables referenced by the instructions
+    pub lookup_tables: Vec<Pubkey>,
+    // Accounts of the instructions resolved from the lookup tables
+    pub lookup_accounts: Vec<TXLookupAccountMeta>,
+    // Addresses the lookup accounts resolved to at creation
+    pub lookup_addresses: Vec<Pubkey>,
+    // Transactions that must be executed before this one
+    pub prerequisites: Vec<u64>,
+    // Assertions checked before the execution
+    pub pre_assertions: Vec<TXAssertion>,
+    // Assertions checked after the execution
+    pub post_assertions: Vec<TXAssertion>,
+}
+// Emitted when a transaction is cancelled by its proposer
+#[event]
+pub struct TransactionCancelEvent {
+    #[index]
+    pub smallet: Pubkey,
+    #[index]
+    pub transaction: Pubkey,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+// Emitted when the expiry of a pending transaction is recorded
+#[event]
+pub struct TransactionExpireEvent {
+    #[index]
+    pub smallet: Pubkey,
+    #[index]
+    pub transaction: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+// Emitted when the sequential mode of a smallet is changed
+#[event]
+pub struct WalletSetSequentialEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // Whether transactions must be executed in order
+    pub sequential: bool,
+    pub timestamp: i64,
 }
 // Emitted when a transaction is approved
 #[event]
@@ -84,6 +173,24 @@ pub struct TransactionExecuteEvent {
     pub transaction: Pubkey,
     pub executor: Pubkey,
     pub timestamp: i64,
+    // Index of the first instruction executed by this call
+    pub first_step: u64,
+    // Last return data of each executed instruction, if any
+    pub return_data: Vec<Option<TXReturnData>>,
+}
+// Emitted when a single instruction of a transaction is executed
+#[event]
+pub struct TransactionStepExecuteEvent {
+    #[index]
+    pub smallet: Pubkey,
+    #[index]
+    pub transaction: Pubkey,
+    pub executor: Pubkey,
+    // Index of the executed instruction
+    pub step: u64,
+    pub timestamp: i64,
+    // Last return data of the instruction, if any
+    pub return_data: Option<TXReturnData>,
 }
 // Emitted when a owner change auto-sign session expires
 #[event]
@@ -101,3 +208,116 @@ pub struct OwnerSetFrozenEvent {
     pub frozen: bool,
     pub timestamp: i64,
 }
+// Emitted when a single owner or the emergency key freezes the smallet
+#[event]
+pub struct EmergencyFreezeEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // Owner or emergency key that froze the smallet
+    pub freezer: Pubkey,
+    pub timestamp: i64,
+}
+// Emitted when the emergency key of a smallet is changed
+#[event]
+pub struct WalletSetEmergencyKeyEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // The new emergency key, if any
+    pub emergency_key: Option<Pubkey>,
+    pub timestamp: i64,
+}
+// Emitted when the lock duration of a smallet is changed
+#[event]
+pub struct WalletSetLockDurationEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // Seconds after which a lock expires, 0 if never
+    pub lock_duration: i64,
+    pub timestamp: i64,
+}
+// Emitted when an owner sends a heartbeat
+#[event]
+pub struct OwnerHeartbeatEvent {
+    #[index]
+    pub smallet: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+// Emitted when the inactivity period of a smallet is changed
+#[event]
+pub struct WalletSetInactivityPeriodEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // Seconds of owner inactivity before guardians may take over, 0 if disabled
+    pub inactivity_period: i64,
+    pub timestamp: i64,
+}
+// Emitted when the guardian policy of a smallet is changed
+#[event]
+pub struct WalletSetGuardianPolicyEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // The new guardian policy, None if the global one applies
+    pub guardian_policy: Option<GuardianPolicy>,
+    pub timestamp: i64,
+}
+// Emitted when the guardian quorums of a smallet are changed
+#[event]
+pub struct WalletSetGuardianQuorumsEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // The new per-action guardian quorums
+    pub guardian_quorums: Vec<GuardianQuorum>,
+    pub timestamp: i64,
+}
+// Emitted when a smallet is closed
+#[event]
+pub struct WalletCloseEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // Recipient of the smallet rent
+    pub recipient: Pubkey,
+    pub timestamp: i64,
+}
+// Emitted when new global thresholds are scheduled
+#[event]
+pub struct GlobalThresholdsScheduledEvent {
+    #[index]
+    pub global_state: Pubkey,
+    // The scheduled thresholds
+    pub thresholds: GuardianPolicy,
+    // Time from which the thresholds can be activated
+    pub activate_at: i64,
+    pub timestamp: i64,
+}
+// Emitted when scheduled global thresholds take effect
+#[event]
+pub struct GlobalThresholdsActivatedEvent {
+    #[index]
+    pub global_state: Pubkey,
+    // The thresholds now in effect
+    pub thresholds: GuardianPolicy,
+    pub timestamp: i64,
+}
+// Emitted when the minimum delay of a smallet is changed
+#[event]
+pub struct WalletSetMinimumDelayEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // The new minimum delay
+    pub minimum_delay: i64,
+    // When the new minimum delay takes effect
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
+// Emitted when the grace period of a smallet is changed
+#[event]
+pub struct WalletSetGracePeriodEvent {
+    #[index]
+    pub smallet: Pubkey,
+    // The new grace period
+    pub grace_period: i64,
+    // When the new grace period takes effect
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
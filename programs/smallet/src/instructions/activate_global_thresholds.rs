@@ -0,0 +1,43 @@
+//! Instruction handler for smallet:activate_global_thresholds
+
+use crate::*;
+
+// Instruction handler for smallet::activate_global_thresholds
+pub fn handler(ctx: Context<ActivateGlobalThresholds>) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    let thresholds = global_state.pending_thresholds;
+    global_state.guardians_change_period = thresholds.change_period;
+    global_state.guardians_action_expires_time = thresholds.action_expires_time;
+    global_state.min_agree_permyriad = thresholds.min_agree_permyriad;
+    global_state.pending_thresholds = GuardianPolicy::default();
+    global_state.thresholds_activate_at = 0;
+
+    emit!(GlobalThresholdsActivatedEvent {
+        global_state: ctx.accounts.global_state.key(),
+        thresholds,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for ActivateGlobalThresholds<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.global_state.thresholds_activate_at != 0,
+            NoPendingThresholds
+        );
+        invariant!(
+            Clock::get()?.unix_timestamp >= self.global_state.thresholds_activate_at,
+            ThresholdsNoticePeriodNotPassed
+        );
+        Ok(())
+    }
+}
+
+// Accounts for [smallet::activate_global_thresholds].
+#[derive(Accounts)]
+pub struct ActivateGlobalThresholds<'info> {
+    // The [GlobalState].
+    #[account(mut)]
+    pub global_state: Account<'info, GlobalState>,
+}
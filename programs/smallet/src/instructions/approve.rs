@@ -10,11 +10,14 @@ pub fn handler(ctx: Context<Approve>) -> Result<()> {
         .try_owner_index(ctx.accounts.owner.key())?;
     ctx.accounts.transaction.signers[owner_index] = true;
 
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.smallet.record_owner_activity(now);
+
     emit!(TransactionApproveEvent {
         smallet: ctx.accounts.smallet.key(),
         transaction: ctx.accounts.transaction.key(),
         owner: ctx.accounts.owner.key(),
-        timestamp: Clock::get()?.unix_timestamp
+        timestamp: now
     });
     Ok(())
 }
@@ -32,6 +35,11 @@ impl<'info> Validate<'info> for Approve<'info> {
             }
         }
         invariant!(!frozen_check || !self.smallet.frozen, AccountFrozen);
+        // Only guardian recovery works while the Smallet is locked
+        invariant!(
+            !self.smallet.is_locked(Clock::get()?.unix_timestamp),
+            AccountLocked
+        );
 
         // The TX in question should belong to the smallet
         assert_keys_eq!(self.smallet, self.transaction.smallet);
@@ -43,6 +51,14 @@ impl<'info> Validate<'info> for Approve<'info> {
         );
         // No point in approving/unapproving if the TX is already executed (duh)
         invariant!(self.transaction.executed_at == -1, AlreadyExecuted);
+        invariant!(self.transaction.cancelled_at == 0, TransactionCancelled);
+        invariant!(
+            !self.transaction.is_expired(Clock::get()?.unix_timestamp),
+            TransactionExpired
+        );
+        // Only approvers may change approvals
+        self.smallet
+            .try_owner_index_with_role(self.owner.key(), ROLE_APPROVER)?;
 
         Ok(())
     }
@@ -51,6 +67,7 @@ impl<'info> Validate<'info> for Approve<'info> {
 #[derive(Accounts)]
 pub struct Approve<'info> {
     // The [Smallet].
+    #[account(mut)]
     pub smallet: Account<'info, Smallet>,
     // The [Transaction].
     #[account(mut, has_one = smallet)]
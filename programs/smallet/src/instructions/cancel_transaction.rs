@@ -0,0 +1,57 @@
+//! Instruction handler for smallet:cancel_transaction
+
+use crate::*;
+
+// Instruction handler for smallet::cancel_transaction
+pub fn handler(ctx: Context<CancelTransaction>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let smallet = &mut ctx.accounts.smallet;
+    let tx = &mut ctx.accounts.transaction;
+    tx.cancelled_at = now;
+    smallet.pending_transactions = smallet.pending_transactions.saturating_sub(1);
+    smallet.record_owner_activity(now);
+
+    emit!(TransactionCancelEvent {
+        smallet: ctx.accounts.smallet.key(),
+        transaction: ctx.accounts.transaction.key(),
+        proposer: ctx.accounts.proposer.key(),
+        timestamp: now
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CancelTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.smallet.frozen, AccountFrozen);
+        invariant!(
+            !self.smallet.is_locked(Clock::get()?.unix_timestamp),
+            AccountLocked
+        );
+        assert_keys_eq!(self.proposer, self.transaction.proposer, "proposer");
+        // The proposer may have lost the role since proposing
+        self.smallet
+            .try_owner_index_with_role(self.proposer.key(), ROLE_PROPOSER)?;
+        // Transactions of a previous owner set are already settled
+        invariant!(
+            self.smallet.owner_set_seqno == self.transaction.owner_set_seqno,
+            OwnerSetChanged
+        );
+        invariant!(self.transaction.executed_at == -1, AlreadyExecuted);
+        invariant!(self.transaction.cancelled_at == 0, TransactionCancelled);
+        invariant!(self.transaction.expired_at == 0, TransactionExpired);
+        Ok(())
+    }
+}
+
+// Accounts for [smallet::cancel_transaction].
+#[derive(Accounts)]
+pub struct CancelTransaction<'info> {
+    // The [Smallet].
+    #[account(mut)]
+    pub smallet: Account<'info, Smallet>,
+    // The [Transaction] to cancel.
+    #[account(mut, has_one = smallet)]
+    pub transaction: Account<'info, Transaction>,
+    // The proposer of the [Transaction].
+    pub proposer: Signer<'info>,
+}
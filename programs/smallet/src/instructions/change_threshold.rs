@@ -7,11 +7,8 @@ pub fn handler(ctx: Context<Auth>, threshold: u64) -> Result<()> {
     // Smallet is frozen
     invariant!(!ctx.accounts.smallet.frozen, AccountFrozen);
 
-    invariant!(
-        threshold <= ctx.accounts.smallet.owners.len() as u64,
-        InvalidThreshold
-    );
     let smallet = &mut ctx.accounts.smallet;
+    validate_owner_set(&smallet.owners, &smallet.current_roles(), threshold)?;
     smallet.threshold = threshold;
 
     emit!(WalletChangeThresholdEvent {
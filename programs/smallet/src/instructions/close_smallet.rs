@@ -0,0 +1,69 @@
+//! Instruction handler for smallet:close_smallet
+
+use crate::*;
+
+// Instruction handler for smallet::close_smallet
+// `remaining_accounts` are pairs of a [SubaccountInfo] of the smallet and its subaccount.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CloseSmallet<'info>>) -> Result<()> {
+    let smallet = &ctx.accounts.smallet;
+    invariant!(smallet.pending_transactions == 0, PendingTransactions);
+
+    for pair in ctx.remaining_accounts.chunks(2) {
+        assert_keys_eq!(*pair[0].owner, crate::ID, SubaccountOwnerMismatch);
+        let info = load_subaccount_info(&pair[0].try_borrow_data()?)?;
+        let subaccount = unwrap_opt!(pair.get(1), SubaccountOwnerMismatch);
+        assert_keys_eq!(info.smallet, smallet.key(), SubaccountOwnerMismatch);
+
+        if info.subaccount_type == SubaccountType::Derived {
+            let (address, _bump) = Pubkey::find_program_address(
+                &[
+                    b"SmalletDerived" as &[u8],
+                    &smallet.key().to_bytes(),
+                    &info.index.to_le_bytes(),
+                ],
+                &crate::ID,
+            );
+            assert_keys_eq!(address, subaccount.key(), SubaccountOwnerMismatch);
+            // Closing would strand anything the subaccount still holds
+            invariant!(
+                subaccount.lamports() == 0 && subaccount.data_is_empty(),
+                SubaccountNotEmpty
+            );
+        }
+    }
+
+    emit!(WalletCloseEvent {
+        smallet: smallet.key(),
+        recipient: ctx.accounts.recipient.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+// Accounts for [smallet::close_smallet].
+#[derive(Accounts)]
+pub struct CloseSmallet<'info> {
+    // The [Smallet]
+    #[account(mut, signer, close = recipient)]
+    pub smallet: Account<'info, Smallet>,
+    /// CHECK: Any account may receive the rent.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+impl<'info> Validate<'info> for CloseSmallet<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.smallet.to_account_info().is_signer,
+            "smallet.is_signer"
+        );
+        // Smallet is frozen
+        invariant!(!self.smallet.frozen, AccountFrozen);
+        // Smallet is locked
+        invariant!(
+            !self.smallet.is_locked(Clock::get()?.unix_timestamp),
+            AccountLocked
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+//! Instruction handler for smallet:create_guardian_action
+
+use crate::*;
+
+// Define constants
+const GUARDIAN_ACTION_SEED: &[u8] = b"GuardianAction";
+
+pub fn handler(
+    ctx: Context<CreateGuardianAction>,
+    action_type: GuardianActionType,
+    guardians_count: u8,
+    addresses_count: u8,
+) -> Result<()> {
+    let global_state = &load_global_state_account(&ctx.accounts.global_state)?;
+    let smallet = &mut ctx.accounts.smallet;
+    let guardian_action: &mut Account<'_, GuardianAction> = &mut ctx.accounts.guardian_action;
+
+    invariant!(
+        smallet.guardians.len() == guardians_count as usize,
+        IncorrectGuardiansCount
+    );
+
+    // New addresses keep the order they are given in
+    let addresses: Vec<Pubkey> = ctx.remaining_accounts.iter().map(|info| *info.key).collect();
+    validate_addresses(&addresses)?;
+
+    invariant!(
+        addresses.len() == addresses_count as usize,
+        IncorrectAddressesCount
+    );
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    if action_type == GuardianActionType::InheritOwners {
+        invariant!(smallet.is_owner_inactive(now), OwnersStillActive);
+    }
+
+    // An action whose delay outlives its expiry could never be performed
+    let quorum = global_state.guardian_quorum(smallet, action_type);
+    invariant!(
+        quorum.delay <= global_state.guardian_policy(smallet).action_expires_time,
+        QuorumDelayExceedsExpiry
+    );
+
+    guardian_action.version = GUARDIAN_ACTION_VERSION;
+    guardian_action.smallet = smallet.key();
+    guardian_action.action_requested_time = now;
+    guardian_action.action_type = action_type;
+    // Set before performing, the global admin acts on the addresses at once
+    guardian_action.addresses = addresses;
+
+    if global_state.is_global_admin(&ctx.accounts.guardian.key()) {
+        perform_action(guardian_action, smallet, global_state)?;
+        return Ok(());
+    }
+
+    let idx = smallet.guardian_index_opt(ctx.accounts.guardian.key());
+    if let Some(guardian_idx) = idx {
+        guardian_action.clear_signs();
+        guardian_action.set_signed_with_index(guardian_idx);
+
+        if guardian_action.is_ready(&quorum, now) {
+            perform_action(guardian_action, smallet, global_state)?;
+        }
+
+        Ok(())
+    } else {
+        Err(crate::ErrorCode::InvalidGuardian.into())
+    }
+}
+
+// Accounts for [smallet::create_guardian_action].
+#[derive(Accounts)]
+#[instruction(guardians_count: u8, addresses_count: u8)]
+pub struct CreateGuardianAction<'info> {
+    // Global admin or any guardian of [smallet]
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+    // The [GlobalState].
+    /// CHECK: Loaded by [load_global_state_account] whatever its version.
+    pub global_state: UncheckedAccount<'info>,
+    // The [Smallet]
+    #[account(mut)]
+    pub smallet: Account<'info, Smallet>,
+    // Guardian action account for [smallet] change
+    #[account(
+        init,
+        seeds = [
+            GUARDIAN_ACTION_SEED,
+            smallet.key().to_bytes().as_ref(),
+            smallet.num_gudian_actions.to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = guardian,
+        space = GuardianAction::space(guardians_count, addresses_count),
+    )]
+    pub guardian_action: Account<'info, GuardianAction>,
+    // The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Validate<'info> for CreateGuardianAction<'info> {
+    fn validate(&self) -> Result<()> {
+        load_global_state_account(&self.global_state)?.check_domain(&self.smallet)?;
+        Ok(())
+    }
+}
+
+pub fn perform_action(
+    guardian_action: &mut Account<GuardianAction>,
+    smallet: &mut Account<Smallet>,
+    global_state: &GlobalState,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let policy = global_state.guardian_policy(smallet);
+
+    invariant!(
+        now <= guardian_action.action_requested_time + policy.action_expires_time,
+        ActionExpired
+    );
+    invariant!(!guardian_action.performed, ActionAlreadyPerformed);
+
+    match guardian_action.action_type {
+        GuardianActionType::UnlockSmallet => {
+            // Smallet should be unlock right after enough guardians signed as agree
+            smallet.frozen = false;
+            smallet.locked = false;
+        }
+        GuardianActionType::SetOwners => {
+            // Smallet owners should be changed right after enough guardians signed as agree
+            update_owners(smallet, guardian_action.addresses.clone())?;
+        }
+        GuardianActionType::InheritOwners => {
+            // Owners can only be taken over once they have been inactive long enough
+            invariant!(smallet.is_owner_inactive(now), OwnersStillActive);
+            update_owners(smallet, guardian_action.addresses.clone())?;
+        }
+        GuardianActionType::SetGuardians => {
+            // Smallet guardians should be changed after guardians_change_period is passed even if enough guardians are signed
+            invariant!(
+                now >= guardian_action.action_requested_time + policy.change_period,
+                NotEnoughChangePeriod
+            );
+            update_guardians(smallet, guardian_action.addresses.clone())?;
+        }
+        GuardianActionType::SetThreshold { threshold } => {
+            // Guardians can fix a threshold that became unreachable
+            validate_owner_set(&smallet.owners, &smallet.current_roles(), threshold)?;
+            smallet.threshold = threshold;
+
+            emit!(WalletChangeThresholdEvent {
+                smallet: smallet.key(),
+                threshold,
+                timestamp: now
+            });
+        }
+        GuardianActionType::ReplaceOwner { old, new } => {
+            // Replace a lost key, keeping its position and roles
+            let index = smallet.try_owner_index(old)?;
+            let mut owners = smallet.owners.clone();
+            owners[index] = new;
+            validate_owner_set(&owners, &smallet.current_roles(), smallet.threshold)?;
+            smallet.owners = owners;
+            // The new key must not inherit the session or approvals of the old one
+            if let Some(session) = smallet.owner_sessions.get_mut(index) {
+                *session = NO_ETA;
+            }
+            smallet.bump_owner_set_seqno()?;
+
+            emit!(WalletSetOwnersEvent {
+                smallet: smallet.key(),
+                owners: smallet.owners.clone(),
+                timestamp: now
+            });
+        }
+        _ => return Err(crate::ErrorCode::InvalidGuardian.into()),
+    }
+
+    smallet.num_gudian_actions += 1;
+    guardian_action.performed = true;
+
+    emit!(GuardianActionPerformEvent {
+        smallet: smallet.key(),
+        guardian_action: guardian_action.key(),
+        action_type: guardian_action.action_type,
+        timestamp: now
+    });
+
+    Ok(())
+}
@@ -12,13 +12,22 @@ pub fn handler(
     threshold: u64,
     minimum_delay: i64,
     guardians: Vec<Pubkey>,
+    guardian_policy: Option<GuardianPolicy>,
 ) -> Result<()> {
     invariant!(minimum_delay >= 0, "delay must be positive");
-    invariant!(minimum_delay < MAX_DELAY_SECONDS, DelayTooHigh);
+    invariant!(minimum_delay <= MAX_DELAY_SECONDS, DelayTooHigh);
     invariant!((max_owners as usize) >= owners.len(), "max_owners");
     invariant!((max_guardians as usize) >= guardians.len(), "max_guardians");
+    validate_owner_set(&owners, &vec![ROLE_ALL; owners.len()], threshold)?;
+    validate_addresses(&guardians)?;
+    if let Some(policy) = guardian_policy {
+        policy.validate()?;
+    }
 
+    let smallet_key = ctx.accounts.smallet.key();
     let smallet = &mut ctx.accounts.smallet;
+    smallet.version = SMALLET_VERSION;
+    smallet.domain = load_global_state_account(&ctx.accounts.global_state)?.domain;
     smallet.base = ctx.accounts.base.key();
     smallet.bump = *unwrap_int!(ctx.bumps.get("smallet"));
     smallet.threshold = threshold;
@@ -28,21 +37,34 @@ pub fn handler(
     smallet.num_transactions = 0;
     smallet.owners = owners;
     smallet.owner_sessions = vec![NO_ETA; smallet.owners.len()];
+    smallet.owner_roles = vec![ROLE_ALL; smallet.owners.len()];
     smallet.guardians = guardians;
+    smallet.guardian_policy = guardian_policy;
+    smallet.check_capacity(smallet.to_account_info().data_len())?;
+
+    let now = Clock::get()?.unix_timestamp;
+    smallet.record_owner_activity(now);
 
     emit!(WalletCreateEvent {
-        smallet: ctx.accounts.smallet.key(),
+        smallet: smallet_key,
         owners: smallet.owners.clone(),
         threshold,
         minimum_delay,
-        timestamp: Clock::get()?.unix_timestamp
+        timestamp: now
     });
     Ok(())
 }
 
 // Accounts for [smallet::create_smallet].
 #[derive(Accounts)]
-#[instruction(bump: u8, max_owners: u8, max_guardians: u8)]
+#[instruction(
+    bump: u8,
+    max_owners: u8,
+    owners: Vec<Pubkey>,
+    threshold: u64,
+    minimum_delay: i64,
+    max_guardians: u8
+)]
 pub struct CreateSmallet<'info> {
     // Base key of the Smallet.
     pub base: Signer<'info>,
@@ -58,6 +80,9 @@ pub struct CreateSmallet<'info> {
         space = Smallet::space(max_owners, max_guardians),
     )]
     pub smallet: Account<'info, Smallet>,
+    // The [GlobalState] of the domain the smallet joins.
+    /// CHECK: Loaded by [load_global_state_account] whatever its version.
+    pub global_state: UncheckedAccount<'info>,
     // Payer to create the smallet.
     #[account(mut)]
     pub payer: Signer<'info>,
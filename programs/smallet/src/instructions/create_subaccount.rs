@@ -14,7 +14,7 @@ pub fn handler(
     subaccount_type: SubaccountType,
 ) -> Result<()> {
     let prefix = match subaccount_type {
-        SubaccountType::Derived => b"SmalletDerived",
+        SubaccountType::Derived => b"SmalletDerived" as &[u8],
         SubaccountType::OwnerInvoker => b"SmalletOwnerInvoker",
     };
 
@@ -29,6 +29,7 @@ pub fn handler(
     info.smallet = smallet;
     info.subaccount_type = subaccount_type;
     info.index = index;
+    info.version = SUBACCOUNT_INFO_VERSION;
 
     Ok(())
 }
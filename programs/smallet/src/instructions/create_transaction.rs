@@ -9,60 +9,203 @@ pub fn handler(
     ctx: Context<CreateTransaction>,
     _bump: u8,
     instructions: Vec<TXInstruction>,
+    options: TransactionOptions,
     eta: i64,
 ) -> Result<()> {
-    let smallet = &ctx.accounts.smallet;
-    let owner_index = smallet.try_owner_index(ctx.accounts.proposer.key())?;
+    validate_options(&instructions, &options)?;
+    // The lookup tables must be given as remaining accounts
+    let lookup_addresses = Transaction::lookup_addresses(
+        &options.lookup_tables,
+        &options.lookup_accounts,
+        ctx.remaining_accounts,
+    )?;
+
+    let bump = *unwrap_int!(ctx.bumps.get("transaction"));
+    let accounts = ctx.accounts;
+    init_transaction(
+        &mut accounts.smallet,
+        &mut accounts.transaction,
+        accounts.proposer.key(),
+        bump,
+        instructions,
+        eta,
+        options,
+        lookup_addresses,
+    )
+}
+
+// Ensures the lookup accounts and assertions of `options` fit `instructions`.
+fn validate_options(instructions: &[TXInstruction], options: &TransactionOptions) -> Result<()> {
+    let lookup_tables = &options.lookup_tables;
+    for (i, table) in lookup_tables.iter().enumerate() {
+        invariant!(!lookup_tables[..i].contains(table), InvalidLookupTable);
+    }
+    // Accounts are inserted in order, so each position must already exist
+    // in the keys of its instruction once the previous ones are inserted
+    let lookup_accounts = &options.lookup_accounts;
+    let mut inserted = vec![0usize; instructions.len()];
+    for meta in lookup_accounts.iter() {
+        let keys = unwrap_opt!(
+            instructions.get(meta.instruction_index as usize),
+            InvalidLookupAccount
+        )
+        .keys
+        .len();
+        invariant!(
+            (meta.table_index as usize) < lookup_tables.len(),
+            InvalidLookupAccount
+        );
+        let count = &mut inserted[meta.instruction_index as usize];
+        invariant!(
+            meta.position as usize <= keys + *count,
+            InvalidLookupAccount
+        );
+        *count += 1;
+    }
+    invariant!(
+        lookup_accounts.windows(2).all(|pair| {
+            (pair[0].instruction_index, pair[0].position)
+                < (pair[1].instruction_index, pair[1].position)
+        }),
+        InvalidLookupAccount
+    );
+
+    invariant!(
+        options.pre_assertions.len() + options.post_assertions.len() <= MAX_ASSERTIONS,
+        TooManyAssertions
+    );
+    Ok(())
+}
+
+fn init_transaction(
+    smallet: &mut Account<Smallet>,
+    tx: &mut Account<Transaction>,
+    proposer: Pubkey,
+    bump: u8,
+    instructions: Vec<TXInstruction>,
+    eta: i64,
+    options: TransactionOptions,
+    lookup_addresses: Vec<Pubkey>,
+) -> Result<()> {
+    let owner_index = smallet.try_owner_index(proposer)?;
+
+    // Prerequisites must have been created before the transaction
+    let prerequisites = options.prerequisites;
+    invariant!(prerequisites.len() <= MAX_PREREQUISITES, TooManyPrerequisites);
+    invariant!(
+        prerequisites
+            .iter()
+            .all(|&index| index < smallet.num_transactions),
+        InvalidPrerequisite
+    );
 
     let clock = Clock::get()?;
     let current_ts = clock.unix_timestamp;
+    smallet.record_owner_activity(current_ts);
 
+    let minimum_delay = smallet.effective_minimum_delay(current_ts);
     if eta != NO_ETA {
         invariant!(eta >= 0, "ETA must be positive");
         let delay = eta - current_ts;
         invariant!(delay >= 0, "ETA must be in the future");
         invariant!(delay <= MAX_DELAY_SECONDS, "Delay is too high");
-        if smallet.minimum_delay != 0 {
-            invariant!(eta >= current_ts + smallet.minimum_delay as i64, "Invalid ETA");
+        if minimum_delay != 0 {
+            invariant!(eta >= current_ts + minimum_delay, InvalidETA);
         }
+    } else {
+        // Timelocked smallets cannot bypass their delay without an ETA
+        invariant!(minimum_delay == 0, InvalidETA);
     }
 
     // generate the signers boolean list
+    // the proposer only signs if it is allowed to approve
     let mut signers = vec![false; smallet.owners.len()];
-    signers[owner_index] = true;
+    signers[owner_index] = smallet.has_role(owner_index, ROLE_APPROVER);
 
     let index = smallet.num_transactions;
     smallet.num_transactions += 1;
+    smallet.pending_transactions = unwrap_int!(smallet.pending_transactions.checked_add(1));
 
     // init the TX
-    let tx = &mut ctx.accounts.transaction;
+    tx.version = TRANSACTION_VERSION;
     tx.smallet = smallet.key();
     tx.index = index;
-    tx.bump = *unwrap_int!(ctx.bumps.get("transaction"));
+    tx.bump = bump;
 
-    tx.proposer = ctx.accounts.proposer.key();
-    tx.instructions = instructions.clone();
+    tx.proposer = proposer;
+    if options.hash_committed {
+        // Only the hash is stored, the instructions are published in the event
+        tx.instructions_hash = Transaction::hash_instructions(&instructions)?;
+    } else {
+        tx.instructions = instructions.clone();
+    }
     tx.signers = signers;
     tx.owner_set_seqno = smallet.owner_set_seqno;
     tx.eta = eta;
+    // Transactions expire a grace period after they can first be executed
+    // unless the proposer sets their expiry
+    let executable_at = if eta == NO_ETA { current_ts } else { eta };
+    tx.expires_at = match options.expires_at {
+        Some(expires_at) => {
+            invariant!(expires_at > executable_at, InvalidExpiry);
+            invariant!(
+                expires_at - executable_at <= MAX_DELAY_SECONDS,
+                DelayTooHigh
+            );
+            expires_at
+        }
+        None => {
+            let grace_period = smallet.effective_grace_period(current_ts);
+            unwrap_int!(executable_at.checked_add(grace_period))
+        }
+    };
+    tx.lookup_tables = options.lookup_tables.clone();
+    tx.lookup_accounts = options.lookup_accounts.clone();
+    if !lookup_addresses.is_empty() {
+        tx.lookup_addresses_hash = Transaction::hash_lookup_addresses(&lookup_addresses);
+    }
+    tx.prerequisites = prerequisites.clone();
+    tx.pre_assertions = options.pre_assertions.clone();
+    tx.post_assertions = options.post_assertions.clone();
 
     tx.executor = Pubkey::default();
     tx.executed_at = -1;
 
     emit!(TransactionCreateEvent {
-        smallet: ctx.accounts.smallet.key(),
-        transaction: ctx.accounts.transaction.key(),
-        proposer: ctx.accounts.proposer.key(),
+        smallet: smallet.key(),
+        transaction: tx.key(),
+        proposer,
         instructions,
         eta,
-        timestamp: current_ts
+        timestamp: current_ts,
+        expires_at: tx.expires_at,
+        lookup_tables: options.lookup_tables,
+        lookup_accounts: options.lookup_accounts,
+        lookup_addresses,
+        prerequisites,
+        pre_assertions: options.pre_assertions,
+        post_assertions: options.post_assertions,
     });
     Ok(())
 }
 
+// Ensures `proposer` may create transactions on `smallet`.
+fn validate_proposer(smallet: &Smallet, proposer: Pubkey) -> Result<()> {
+    // Smallet is frozen
+    invariant!(!smallet.frozen, AccountFrozen);
+    // Smallet is locked
+    invariant!(
+        !smallet.is_locked(Clock::get()?.unix_timestamp),
+        AccountLocked
+    );
+    // Only proposers may create transactions
+    smallet.try_owner_index_with_role(proposer, ROLE_PROPOSER)?;
+    Ok(())
+}
+
 // Accounts for [smallet::create_transaction].
 #[derive(Accounts)]
-#[instruction(bump: u8, instructions: Vec<TXInstruction>)]
+#[instruction(bump: u8, instructions: Vec<TXInstruction>, options: TransactionOptions)]
 pub struct CreateTransaction<'info> {
     // The [Smallet]
     #[account(mut)]
@@ -71,16 +214,16 @@ pub struct CreateTransaction<'info> {
     #[account(
         init,
         seeds = [
-            b"Transaction".as_ref(),
+            TRANSACTION_SEED,
             smallet.key().to_bytes().as_ref(),
             smallet.num_transactions.to_le_bytes().as_ref()
         ],
         bump,
         payer = payer,
-        space = Transaction::space(instructions),
+        space = Transaction::space_with_options(instructions, &options),
     )]
     pub transaction: Account<'info, Transaction>,
-    // One of the owners. Checked in the handler via [Smallet::try_owner_index].
+    // One of the owners with [ROLE_PROPOSER]. Checked in the validator.
     pub proposer: Signer<'info>,
     // Payer to create the [Transaction].
     #[account(mut)]
@@ -91,9 +234,6 @@ pub struct CreateTransaction<'info> {
 
 impl<'info> Validate<'info> for CreateTransaction<'info> {
     fn validate(&self) -> Result<()> {
-        // Smallet is frozen
-        invariant!(!self.smallet.frozen, AccountFrozen);
-        // owner_index check happens later
-        Ok(())
+        validate_proposer(&self.smallet, self.proposer.key())
     }
 }
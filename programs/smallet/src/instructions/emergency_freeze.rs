@@ -0,0 +1,45 @@
+//! Instruction handler for smallet:emergency_freeze
+
+use crate::*;
+
+// Instruction handler for smallet::emergency_freeze
+pub fn handler(ctx: Context<EmergencyFreeze>) -> Result<()> {
+    ctx.accounts.smallet.frozen = true;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let freezer = ctx.accounts.freezer.key();
+    if ctx.accounts.smallet.owner_index_opt(freezer).is_some() {
+        ctx.accounts.smallet.record_owner_activity(timestamp);
+    }
+    emit!(OwnerSetFrozenEvent {
+        smallet: ctx.accounts.smallet.key(),
+        frozen: true,
+        timestamp
+    });
+    emit!(EmergencyFreezeEvent {
+        smallet: ctx.accounts.smallet.key(),
+        freezer,
+        timestamp
+    });
+    Ok(())
+}
+
+// Accounts for [smallet::emergency_freeze].
+#[derive(Accounts)]
+pub struct EmergencyFreeze<'info> {
+    // The [Smallet].
+    #[account(mut)]
+    pub smallet: Account<'info, Smallet>,
+    // An owner with [ROLE_FREEZER] or the emergency key of the [Smallet].
+    pub freezer: Signer<'info>,
+}
+
+impl<'info> Validate<'info> for EmergencyFreeze<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.smallet.is_freezer(&self.freezer.key()),
+            InvalidFreezer
+        );
+        Ok(())
+    }
+}
@@ -1,40 +1,89 @@
 //! Instruction handler for [smallet::execute_transaction] and [smallet::execute_transaction_derived].
 
 use crate::*;
+use anchor_spl::token::{self, spl_token, spl_token::native_mint, TokenAccount};
+use solana_program::program_pack::Pack;
+
+// System program instructions that move lamports.
+const SYSTEM_TRANSFER: u32 = 2;
+const SYSTEM_TRANSFER_WITH_SEED: u32 = 11;
+
+// SPL token instructions that move tokens.
+const TOKEN_TRANSFER: u8 = 3;
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
 
 // Instruction handler for smallet::execute_transaction
-pub fn execute_transaction(ctx: Context<ExecuteTransaction>) -> Result<()> {
-    let smallet = &ctx.accounts.smallet;
-    let wallet_seeds: &[&[&[u8]]] = &[&[
-        b"Smallet" as &[u8],
-        &smallet.base.to_bytes(),
-        &[smallet.bump],
-    ]];
-    do_execute_transaction(ctx, wallet_seeds)
+pub fn execute_transaction<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
+) -> Result<()> {
+    let smallet = ctx.accounts.load_smallet_account()?;
+    do_execute_transaction(ctx, smallet, None, None, None)
+}
+
+// Instruction handler for smallet::execute_hashed_transaction
+pub fn execute_hashed_transaction<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
+    instructions: Vec<TXInstruction>,
+) -> Result<()> {
+    let smallet = ctx.accounts.load_smallet_account()?;
+    do_execute_transaction(ctx, smallet, None, None, Some(instructions))
+}
+
+// Instruction handler for smallet::execute_transaction_step
+pub fn execute_transaction_step<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
+    step: u64,
+) -> Result<()> {
+    let smallet = ctx.accounts.load_smallet_account()?;
+    do_execute_transaction(ctx, smallet, None, Some(step), None)
+}
+
+// Instruction handler for smallet::execute_hashed_transaction_step
+pub fn execute_hashed_transaction_step<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
+    step: u64,
+    instructions: Vec<TXInstruction>,
+) -> Result<()> {
+    let smallet = ctx.accounts.load_smallet_account()?;
+    do_execute_transaction(ctx, smallet, None, Some(step), Some(instructions))
 }
 
 // Instruction handler for smallet::execute_transaction_derived
-pub fn execute_transaction_derived(
-    ctx: Context<ExecuteTransaction>,
+pub fn execute_transaction_derived<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
     index: u64,
     bump: u8,
 ) -> Result<()> {
-    let smallet = &ctx.accounts.smallet;
-    // Execute the transaction signed by the smallet.
-    let wallet_seeds: &[&[&[u8]]] = &[&[
-        b"SmalletDerived" as &[u8],
-        &smallet.key().to_bytes(),
-        &index.to_le_bytes(),
-        &[bump],
-    ]];
-    do_execute_transaction(ctx, wallet_seeds)
+    let smallet = ctx.accounts.load_smallet_account()?;
+    // Execute the transaction signed by the derived wallet.
+    do_execute_transaction(ctx, smallet, Some((index, bump)), None, None)
+}
+
+// Seeds of the wallet signing the executed instructions, either the smallet
+// or its derived wallet at `(index, bump)`.
+pub(crate) fn wallet_seeds(smallet: &Account<Smallet>, derived: Option<(u64, u8)>) -> Vec<Vec<u8>> {
+    match derived {
+        Some((index, bump)) => vec![
+            b"SmalletDerived".to_vec(),
+            smallet.key().to_bytes().to_vec(),
+            index.to_le_bytes().to_vec(),
+            vec![bump],
+        ],
+        None => vec![
+            b"Smallet".to_vec(),
+            smallet.base.to_bytes().to_vec(),
+            vec![smallet.bump],
+        ],
+    }
 }
 
 // Accounts for [smallet::execute_transaction].
 #[derive(Accounts)]
 pub struct ExecuteTransaction<'info> {
-    // The [Smallet].
-    pub smallet: Account<'info, Smallet>,
+    /// CHECK: The [Smallet], loaded by [ExecuteTransaction::load_smallet_account].
+    /// Its instructions may close it, so it is only written back while open.
+    #[account(mut)]
+    pub smallet: UncheckedAccount<'info>,
     // The [Transaction] to execute.
     #[account(mut)]
     pub transaction: Account<'info, Transaction>,
@@ -42,72 +91,421 @@ pub struct ExecuteTransaction<'info> {
     pub owner: Signer<'info>,
 }
 
-impl<'info> Validate<'info> for ExecuteTransaction<'info> {
-    fn validate(&self) -> Result<()> {
-        assert_keys_eq!(self.smallet, self.transaction.smallet, "smallet");
+impl<'info> ExecuteTransaction<'info> {
+    // Deserializes the [Smallet]. Changes are persisted with [AccountsExit::exit].
+    pub fn load_smallet_account(&self) -> Result<Account<'info, Smallet>> {
+        Account::try_from(&self.smallet.to_account_info())
+    }
+}
+
+// Ensures `transaction` can be executed by `smallet` at `current_ts`,
+// regardless of its approvals.
+pub(crate) fn validate_executable(
+    smallet: &Account<Smallet>,
+    transaction: &Transaction,
+    current_ts: i64,
+) -> Result<()> {
+    assert_keys_eq!(smallet.key(), transaction.smallet, "smallet");
+    invariant!(
+        smallet.owner_set_seqno == transaction.owner_set_seqno,
+        OwnerSetChanged
+    );
+    // Checking to see if this has been executed already
+    invariant!(transaction.executed_at == -1, AlreadyExecuted);
+    invariant!(transaction.cancelled_at == 0, TransactionCancelled);
+
+    let mut frozen_check = true;
+    // No point in approving/unapproving if the Smallet is frozen
+    // Except unfreezing Tx
+    if transaction.instructions.len() == 1 {
+        let instruction = &transaction.instructions[0];
+        if instruction.data.len() >= 8 && instruction.data[0..8] == SET_FROZEN_DISCRIMINATOR {
+            frozen_check = false;
+        }
+    }
+    invariant!(!frozen_check || !smallet.frozen, AccountFrozen);
+
+    let eta = transaction.eta;
+    // Only guardian recovery works while the Smallet is locked
+    invariant!(!smallet.is_locked(current_ts), AccountLocked);
+    msg!("current_ts: {}; eta: {}", current_ts, eta);
+    // Has transaction surpassed timelock?
+    invariant!(current_ts >= eta, TransactionNotReady);
+    if eta != NO_ETA {
+        // Has grace period passed?
         invariant!(
-            self.smallet.owner_set_seqno == self.transaction.owner_set_seqno,
-            OwnerSetChanged
+            current_ts <= unwrap_int!(eta.checked_add(smallet.effective_grace_period(current_ts))),
+            TransactionIsStale
         );
-        // Checking to see if this has been executed already
-        invariant!(self.transaction.executed_at == -1, AlreadyExecuted);
-
-        let mut frozen_check = true;
-        // No point in approving/unapproving if the Smallet is frozen
-        // Except unfreezing Tx
-        if self.transaction.instructions.len() == 1 {
-            let instruction = &self.transaction.instructions[0];
-            if instruction.data.len() >= 8 && instruction.data[0..8] == SET_FROZEN_DISCRIMINATOR {
-                frozen_check = false;
-            }
+    }
+    // Non-timelocked transactions do not stay executable forever either
+    invariant!(!transaction.is_expired(current_ts), TransactionExpired);
+    Ok(())
+}
+
+impl<'info> Validate<'info> for ExecuteTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        let current_ts = Clock::get()?.unix_timestamp;
+        let smallet = self.load_smallet_account()?;
+        validate_executable(&smallet, &self.transaction, current_ts)?;
+        // Do we have enough signers to execute the TX?
+        // Consider auto-signed owner as signer.
+        // Approvals are only counted when the execution starts, later steps
+        // carry on under the threshold stored then.
+        if self.transaction.executed_steps == 0 {
+            let sig_count = self.transaction.num_signers(&smallet, current_ts);
+            invariant!((sig_count as u64) >= smallet.threshold, NotEnoughSigners);
+        }
+        // ensure that the owner is a signer allowed to execute
+        // this prevents common frontrunning/flash loan attacks
+        smallet.try_owner_index_with_role(self.owner.key(), ROLE_EXECUTOR)?;
+
+        Ok(())
+    }
+}
+
+// Whether the account at `index` of the instruction is `key`.
+fn is_key_at(ix: &TXInstruction, index: usize, key: &Pubkey) -> bool {
+    ix.keys.get(index).map(|meta| meta.pubkey == *key) == Some(true)
+}
+
+// Returns the mint and amount moved out by a system, SPL token or Token-2022
+// transfer signed by `wallet`. Any other instruction is not considered an
+// outflow, and neither are transfers of accounts the wallet does not control.
+fn decode_outflow(
+    ix: &TXInstruction,
+    wallet: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<Option<(Pubkey, u64)>> {
+    if ix.program_id == solana_program::system_program::ID {
+        if ix.data.len() < 12 {
+            return Ok(None);
+        }
+        let tag = u32::from_le_bytes(ix.data[0..4].try_into().unwrap());
+        // The funding account signs a transfer, its base a transfer with seed
+        let signer_index = match tag {
+            SYSTEM_TRANSFER => 0,
+            SYSTEM_TRANSFER_WITH_SEED => 1,
+            _ => return Ok(None),
+        };
+        if !is_key_at(ix, signer_index, wallet) {
+            return Ok(None);
         }
-        invariant!(!frozen_check || !self.smallet.frozen, AccountFrozen);
-        
-        let eta = self.transaction.eta;
-        let clock = Clock::get()?;
-        let current_ts = clock.unix_timestamp;
-        msg!("current_ts: {}; eta: {}", current_ts, eta);
-        // Has transaction surpassed timelock?
-        invariant!(current_ts >= eta, TransactionNotReady);
-        if eta != NO_ETA {
-            // Has grace period passed?
+        let lamports = u64::from_le_bytes(ix.data[4..12].try_into().unwrap());
+        return Ok(Some((native_mint::ID, lamports)));
+    }
+
+    if ix.program_id == token::ID || ix.program_id == TOKEN_2022_PROGRAM_ID {
+        if ix.data.len() < 9 {
+            return Ok(None);
+        }
+        let authority_index = match ix.data[0] {
+            TOKEN_TRANSFER => 2,
+            TOKEN_TRANSFER_CHECKED => 3,
+            _ => return Ok(None),
+        };
+        if !is_key_at(ix, authority_index, wallet) {
+            return Ok(None);
+        }
+        let amount = u64::from_le_bytes(ix.data[1..9].try_into().unwrap());
+        let mint = if ix.data[0] == TOKEN_TRANSFER_CHECKED {
+            unwrap_opt!(ix.keys.get(1), MissingTransferAccount).pubkey
+        } else {
+            // The mint is only known from the source token account, whose
+            // layout starts like an SPL token account for both programs
+            let source = unwrap_opt!(ix.keys.first(), MissingTransferAccount).pubkey;
+            let info = unwrap_opt!(
+                accounts.iter().find(|info| *info.key == source),
+                MissingTransferAccount
+            );
+            assert_keys_eq!(*info.owner, ix.program_id, MissingTransferAccount);
+            let data = info.try_borrow_data()?;
             invariant!(
-                current_ts <= unwrap_int!(eta.checked_add(self.smallet.grace_period)),
-                TransactionIsStale
+                data.len() >= spl_token::state::Account::LEN,
+                MissingTransferAccount
             );
+            Pubkey::new(&data[0..32])
+        };
+        return Ok(Some((mint, amount)));
+    }
+
+    Ok(None)
+}
+
+// Number of approvals the transaction needs, taking the value tiers of the
+// outflows of `wallet` into account. Never less than [Smallet::threshold].
+pub(crate) fn required_threshold(
+    smallet: &Smallet,
+    wallet: &Pubkey,
+    instructions: &[TXInstruction],
+    accounts: &[AccountInfo],
+) -> Result<u64> {
+    if smallet.value_tiers.is_empty() {
+        return Ok(smallet.threshold);
+    }
+
+    // Sum outflows per mint
+    let mut outflows: Vec<(Pubkey, u64)> = vec![];
+    for ix in instructions.iter() {
+        if let Some((mint, amount)) = decode_outflow(ix, wallet, accounts)? {
+            match outflows.iter_mut().find(|(m, _)| *m == mint) {
+                Some((_, total)) => *total = total.saturating_add(amount),
+                None => outflows.push((mint, amount)),
+            }
         }
-        // Do we have enough signers to execute the TX?
-        // Consider auto-signed owner as signer
-        let sig_count = self
-            .transaction
-            .num_signers(self.smallet.owner_sessions.clone().as_ref(), current_ts);
+    }
+
+    Ok(outflows
+        .iter()
+        .filter_map(|(mint, amount)| smallet.tier_threshold(mint, *amount))
+        .fold(smallet.threshold, u64::max))
+}
+
+// Account an assertion applies to, either the smallet or one of `accounts`.
+pub(crate) fn find_assertion_account<'a, 'info>(
+    assertion: &TXAssertion,
+    smallet: &'a AccountInfo<'info>,
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<&'a AccountInfo<'info>> {
+    let key = assertion.account();
+    if *smallet.key == key {
+        return Ok(smallet);
+    }
+    Ok(unwrap_opt!(
+        accounts.iter().find(|info| *info.key == key),
+        MissingAssertionAccount
+    ))
+}
+
+// Ensures every assertion holds. `initial_lamports` are the lamports of the
+// accounts when the execution started.
+pub(crate) fn check_assertions<'info>(
+    assertions: &[TXAssertion],
+    smallet: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    initial_lamports: &[(Pubkey, u64)],
+) -> Result<()> {
+    if let Some(index) = failed_assertion(assertions, smallet, accounts, initial_lamports)? {
+        msg!("assertion {} does not hold", index);
+        return Err(crate::ErrorCode::AssertionFailed.into());
+    }
+    Ok(())
+}
+
+// Index of the first assertion that does not hold, if any.
+pub(crate) fn failed_assertion<'info>(
+    assertions: &[TXAssertion],
+    smallet: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    initial_lamports: &[(Pubkey, u64)],
+) -> Result<Option<usize>> {
+    for (index, assertion) in assertions.iter().enumerate() {
+        let info = find_assertion_account(assertion, smallet, accounts)?;
+        let holds = match assertion {
+            TXAssertion::MinTokenBalance { amount, .. } => {
+                *info.owner == token::ID
+                    && TokenAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?.amount
+                        >= *amount
+            }
+            TXAssertion::MinLamports { lamports, .. } => info.lamports() >= *lamports,
+            TXAssertion::MaxLamportsDecrease { lamports, .. } => {
+                let (_, initial) = unwrap_opt!(
+                    initial_lamports.iter().find(|(key, _)| key == info.key),
+                    MissingAssertionAccount
+                );
+                initial.saturating_sub(info.lamports()) <= *lamports
+            }
+            TXAssertion::DataEquals { offset, bytes, .. } => {
+                let start = *offset as usize;
+                info.try_borrow_data()?.get(start..start + bytes.len()) == Some(&bytes[..])
+            }
+        };
+        if !holds {
+            return Ok(Some(index));
+        }
+    }
+    Ok(None)
+}
+
+// Loads the [Transaction] of `smallet` at `index` from `accounts`.
+fn find_transaction(smallet: &Pubkey, index: u64, accounts: &[AccountInfo]) -> Result<Transaction> {
+    let (address, _) = Pubkey::find_program_address(
+        &[b"Transaction", smallet.as_ref(), &index.to_le_bytes()],
+        &crate::ID,
+    );
+    let info = unwrap_opt!(
+        accounts.iter().find(|info| *info.key == address),
+        MissingPrerequisite
+    );
+    assert_keys_eq!(*info.owner, crate::ID, MissingPrerequisite);
+    load_transaction(&info.try_borrow_data()?)
+}
+
+// Ensures the prerequisites of the transaction have been executed and, in
+// sequential mode, that the previous transaction is settled.
+pub(crate) fn check_execution_order(
+    smallet: &Account<Smallet>,
+    transaction: &Transaction,
+    accounts: &[AccountInfo],
+) -> Result<()> {
+    for &index in transaction.prerequisites.iter() {
+        let prerequisite = find_transaction(&smallet.key(), index, accounts)?;
+        invariant!(prerequisite.executed_at != -1, PrerequisiteNotExecuted);
+    }
+
+    if smallet.sequential && transaction.index > 0 {
+        let previous = find_transaction(&smallet.key(), transaction.index - 1, accounts)?;
         invariant!(
-            (sig_count as u64) >= self.smallet.threshold,
-            NotEnoughSigners
+            previous.is_settled(smallet, Clock::get()?.unix_timestamp),
+            PreviousTransactionPending
         );
-        // ensure that the owner is a signer
-        // this prevents common frontrunning/flash loan attacks
-        self.smallet.try_owner_index(self.owner.key())?;
-
-        Ok(())
     }
+    Ok(())
 }
 
-fn do_execute_transaction(ctx: Context<ExecuteTransaction>, seeds: &[&[&[u8]]]) -> Result<()> {
-    for ix in ctx.accounts.transaction.instructions.iter() {
+// Executes the remaining instructions of the transaction, or only the
+// instruction at `step` when executing step by step. Instructions of
+// hash-committed transactions are `supplied` by the executor.
+fn do_execute_transaction<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
+    mut smallet: Account<'info, Smallet>,
+    derived: Option<(u64, u8)>,
+    step: Option<u64>,
+    supplied: Option<Vec<TXInstruction>>,
+) -> Result<()> {
+    let wallet_seeds = wallet_seeds(&smallet, derived);
+    let signer = wallet_seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    let seeds: &[&[&[u8]]] = &[signer.as_slice()];
+
+    // Accounts referenced through lookup tables are verified against the tables
+    let transaction = &ctx.accounts.transaction;
+    let instructions = transaction.instructions_to_execute(supplied)?;
+    let instructions = transaction.resolve_lookups(instructions, ctx.remaining_accounts)?;
+
+    // Steps are executed strictly in order
+    let first = ctx.accounts.transaction.executed_steps as usize;
+
+    // Value tiers may require more approvals than the smallet threshold.
+    // Only the wallet signing this execution can move funds out. The
+    // requirement is computed once for every instruction when the execution
+    // starts, as later steps may change the smallet.
+    let required = if first == 0 {
+        let wallet =
+            Pubkey::create_program_address(seeds[0], &crate::ID).map_err(ProgramError::from)?;
+        let required =
+            required_threshold(&smallet, &wallet, &instructions, ctx.remaining_accounts)?;
+        let sig_count = ctx
+            .accounts
+            .transaction
+            .num_signers(&smallet, Clock::get()?.unix_timestamp);
+        invariant!((sig_count as u64) >= required, NotEnoughSigners);
+        required
+    } else {
+        ctx.accounts.transaction.required_threshold
+    };
+    let last = match step {
+        Some(step) => {
+            invariant!(step as usize == first, InvalidExecutionStep);
+            invariant!(first < instructions.len(), InvalidExecutionStep);
+            first + 1
+        }
+        None => instructions.len(),
+    };
+    let completed = last == instructions.len();
+
+    // Assertions compare the state before and after the whole execution
+    let has_assertions = ctx.accounts.transaction.has_assertions();
+    if has_assertions {
+        invariant!(step.is_none(), AssertionsRequireFullExecution);
+    }
+
+    // Ordering constraints are checked when the execution starts
+    if first == 0 {
+        check_execution_order(&smallet, &ctx.accounts.transaction, ctx.remaining_accounts)?;
+    }
+
+    if completed {
+        // The transaction is no longer pending. Persist it before the instructions
+        // run, as they may read the smallet through the Auth path.
+        smallet.pending_transactions = smallet.pending_transactions.saturating_sub(1);
+        smallet.exit(&crate::ID)?;
+    }
+
+    let smallet_info = smallet.to_account_info();
+    let mut initial_lamports = vec![];
+    if has_assertions {
+        let tx = &ctx.accounts.transaction;
+        for assertion in tx.pre_assertions.iter().chain(tx.post_assertions.iter()) {
+            let info = find_assertion_account(assertion, &smallet_info, ctx.remaining_accounts)?;
+            initial_lamports.push((*info.key, info.lamports()));
+        }
+        check_assertions(
+            &tx.pre_assertions,
+            &smallet_info,
+            ctx.remaining_accounts,
+            &initial_lamports,
+        )?;
+    }
+
+    // Keep the last return data of each instruction for the execute event
+    let mut return_data = Vec::with_capacity(last - first);
+    for ix in instructions[first..last].iter() {
         solana_program::program::invoke_signed(&(ix).into(), ctx.remaining_accounts, seeds)?;
+        return_data.push(
+            solana_program::program::get_return_data()
+                .map(|(program_id, data)| TXReturnData { program_id, data }),
+        );
+    }
+
+    // The referenced accounts are read again after the instructions ran
+    if has_assertions {
+        check_assertions(
+            &ctx.accounts.transaction.post_assertions,
+            &smallet_info,
+            ctx.remaining_accounts,
+            &initial_lamports,
+        )?;
     }
 
-    // Burn the transaction to ensure one time use.
+    let now = Clock::get()?.unix_timestamp;
+
     let tx = &mut ctx.accounts.transaction;
-    tx.executor = ctx.accounts.owner.key();
-    tx.executed_at = Clock::get()?.unix_timestamp;
+    tx.executed_steps = last as u64;
+    tx.required_threshold = required;
+    if completed {
+        // Burn the transaction to ensure one time use.
+        tx.executor = ctx.accounts.owner.key();
+        tx.executed_at = now;
+    }
+
+    // The instructions may have changed the smallet through the Auth path,
+    // so reload it before recording the activity. Once they closed it, it
+    // must not be written again, which would revive it.
+    if smallet_info.lamports() > 0 {
+        smallet.reload()?;
+        smallet.record_owner_activity(now);
+        smallet.exit(&crate::ID)?;
+    }
+
+    if !completed {
+        emit!(TransactionStepExecuteEvent {
+            smallet: smallet.key(),
+            transaction: ctx.accounts.transaction.key(),
+            executor: ctx.accounts.owner.key(),
+            step: first as u64,
+            timestamp: now,
+            return_data: return_data.pop().flatten(),
+        });
+        return Ok(());
+    }
 
     emit!(TransactionExecuteEvent {
-        smallet: ctx.accounts.smallet.key(),
+        smallet: smallet.key(),
         transaction: ctx.accounts.transaction.key(),
         executor: ctx.accounts.owner.key(),
-        timestamp: Clock::get()?.unix_timestamp
+        timestamp: now,
+        first_step: first as u64,
+        return_data,
     });
     Ok(())
 }
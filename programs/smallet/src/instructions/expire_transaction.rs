@@ -0,0 +1,49 @@
+//! Instruction handler for smallet:expire_transaction
+
+use crate::*;
+
+// Instruction handler for smallet::expire_transaction
+pub fn handler(ctx: Context<ExpireTransaction>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let smallet = &mut ctx.accounts.smallet;
+    let tx = &mut ctx.accounts.transaction;
+    tx.expired_at = now;
+    smallet.pending_transactions = smallet.pending_transactions.saturating_sub(1);
+
+    emit!(TransactionExpireEvent {
+        smallet: ctx.accounts.smallet.key(),
+        transaction: ctx.accounts.transaction.key(),
+        expires_at: ctx.accounts.transaction.expires_at,
+        timestamp: now
+    });
+    Ok(())
+}
+
+impl<'info> Validate<'info> for ExpireTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        // Transactions of a previous owner set are no longer counted as pending
+        invariant!(
+            self.smallet.owner_set_seqno == self.transaction.owner_set_seqno,
+            OwnerSetChanged
+        );
+        invariant!(self.transaction.executed_at == -1, AlreadyExecuted);
+        invariant!(self.transaction.cancelled_at == 0, TransactionCancelled);
+        invariant!(self.transaction.expired_at == 0, TransactionExpired);
+        invariant!(
+            self.transaction.is_expired(Clock::get()?.unix_timestamp),
+            TransactionNotExpired
+        );
+        Ok(())
+    }
+}
+
+// Accounts for [smallet::expire_transaction].
+#[derive(Accounts)]
+pub struct ExpireTransaction<'info> {
+    // The [Smallet].
+    #[account(mut)]
+    pub smallet: Account<'info, Smallet>,
+    // The expired [Transaction].
+    #[account(mut, has_one = smallet)]
+    pub transaction: Account<'info, Transaction>,
+}
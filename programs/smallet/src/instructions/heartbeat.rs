@@ -0,0 +1,38 @@
+//! Instruction handler for smallet:heartbeat
+
+use crate::*;
+
+// Instruction handler for smallet::heartbeat
+pub fn handler(ctx: Context<Heartbeat>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.smallet.record_owner_activity(now);
+
+    emit!(OwnerHeartbeatEvent {
+        smallet: ctx.accounts.smallet.key(),
+        owner: ctx.accounts.owner.key(),
+        timestamp: now
+    });
+    Ok(())
+}
+
+// Accounts for [smallet::heartbeat].
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    // The [Smallet].
+    #[account(mut)]
+    pub smallet: Account<'info, Smallet>,
+    // One of the smallet owners.
+    pub owner: Signer<'info>,
+}
+
+impl<'info> Validate<'info> for Heartbeat<'info> {
+    fn validate(&self) -> Result<()> {
+        self.smallet.try_owner_index(self.owner.key())?;
+        // Owners cannot reset the inactivity clock while guardians hold the lock
+        invariant!(
+            !self.smallet.is_locked(Clock::get()?.unix_timestamp),
+            AccountLocked
+        );
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::*;
 
 #[derive(Accounts)]
+#[instruction(domain: u64)]
 pub struct InitializeGlobalState<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -11,6 +12,7 @@ pub struct InitializeGlobalState<'info> {
       payer = admin,
       seeds = [
           b"Smallet".as_ref(),
+          GlobalState::domain_seed(domain).as_ref(),
       ],
       bump,
   )]
@@ -19,23 +21,43 @@ pub struct InitializeGlobalState<'info> {
     // system accounts
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
-    // TODO: should uncomment these accounts for real network version
-    // ProgramData PDA is not created on localnetwork
-    // So commented program deployer validation to pass unit-tests
-    //   #[account(
-    //     constraint = program.programdata_address()? == Some(program_data.key()) @ crate::ErrorCode::InvalidProgramDataAccount,
-    //     constraint = program.key() == crate::ID,
-    // )]
-    //   pub program: Program<'info, crate::program::Smallet>,
-    //   #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ crate::ErrorCode::InvalidDeployer)]
-    //   pub program_data: Account<'info, ProgramData>,
+    // The smallet program, whose upgrade authority must be the admin.
+    pub program: Program<'info, crate::program::Smallet>,
+    /// CHECK: ProgramData of the smallet program. Checked in the validator.
+    pub program_data: UncheckedAccount<'info>,
 }
 
-pub fn handle(ctx: Context<InitializeGlobalState>) -> Result<()> {
+impl<'info> Validate<'info> for InitializeGlobalState<'info> {
+    // ProgramData is not created on localnet, so the deployer is only
+    // validated on real networks.
+    #[cfg(not(feature = "localnet"))]
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.program.programdata_address()? == Some(self.program_data.key()),
+            InvalidProgramDataAccount
+        );
+        let program_data: Account<ProgramData> =
+            Account::try_from(&self.program_data.to_account_info())?;
+        invariant!(
+            program_data.upgrade_authority_address == Some(self.admin.key()),
+            InvalidDeployer
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "localnet")]
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+pub fn handle(ctx: Context<InitializeGlobalState>, domain: u64) -> Result<()> {
     let global_state = &mut ctx.accounts.global_state;
+    global_state.domain = domain;
 
     // save global admin
     global_state.global_admin = ctx.accounts.admin.key();
+    global_state.version = GLOBAL_STATE_VERSION;
 
     Ok(())
 }
@@ -5,16 +5,21 @@ use crate::*;
 // Instruction handler for smallet::lock_smallet creation
 pub fn handler(ctx: Context<LockSmallet>) -> Result<()> {
     let smallet = &mut ctx.accounts.smallet;
-    let global_state = &ctx.accounts.global_state;
+    let global_state = load_global_state_account(&ctx.accounts.global_state)?;
 
     if !global_state.is_global_admin(&ctx.accounts.guardian.key()) {
         invariant!(
             smallet.is_guardian(&ctx.accounts.guardian.key()),
-            Invalidguardian
+            InvalidGuardian
         );
     }
 
-    // Lock smallet immediately if signer is guardian or global admin
+    // Lock smallet immediately if signer is guardian or global admin.
+    // Locking again must not extend a lock that is still in effect.
+    let now = Clock::get()?.unix_timestamp;
+    if !smallet.is_locked(now) {
+        smallet.locked_at = now;
+    }
     smallet.locked = true;
 
     Ok(())
@@ -27,7 +32,8 @@ pub struct LockSmallet<'info> {
     #[account(mut)]
     pub guardian: Signer<'info>,
     // The [GlobalState].
-    pub global_state: Account<'info, GlobalState>,
+    /// CHECK: Loaded by [load_global_state_account] whatever its version.
+    pub global_state: UncheckedAccount<'info>,
     // The [Smallet]
     #[account(mut)]
     pub smallet: Account<'info, Smallet>,
@@ -35,6 +41,7 @@ pub struct LockSmallet<'info> {
 
 impl<'info> Validate<'info> for LockSmallet<'info> {
     fn validate(&self) -> Result<()> {
+        load_global_state_account(&self.global_state)?.check_domain(&self.smallet)?;
         Ok(())
     }
 }
@@ -0,0 +1,143 @@
+//! Instruction handlers for smallet:migrate_*
+
+use crate::*;
+
+// Instruction handler for smallet::migrate_global_state
+pub fn migrate_global_state(ctx: Context<MigrateAccount>) -> Result<()> {
+    let global_state = load_global_state(&ctx.accounts.account.try_borrow_data()?)?;
+    let space = 8 + global_state.try_to_vec()?.len();
+    write_account_of(&ctx, &global_state, space)
+}
+
+// Instruction handler for smallet::migrate_smallet
+pub fn migrate_smallet(ctx: Context<MigrateAccount>) -> Result<()> {
+    let (smallet, space) = {
+        let data = ctx.accounts.account.try_borrow_data()?;
+        let smallet = load_smallet(&data)?;
+        let space = migrated_smallet_space(&data, &smallet)?;
+        (smallet, space)
+    };
+    write_account_of(&ctx, &smallet, space)
+}
+
+// Instruction handler for smallet::migrate_transaction
+pub fn migrate_transaction(ctx: Context<MigrateTransaction>) -> Result<()> {
+    let mut transaction = load_transaction(&ctx.accounts.account.try_borrow_data()?)?;
+    assert_keys_eq!(ctx.accounts.smallet, transaction.smallet, NotSmalletAccount);
+    if transaction.expires_at == 0 {
+        // Legacy transactions do not record when they were created, so they
+        // expire a grace period after their ETA or after the migration
+        let smallet = load_smallet(&ctx.accounts.smallet.try_borrow_data()?)?;
+        let now = Clock::get()?.unix_timestamp;
+        let executable_at = if transaction.eta == NO_ETA {
+            now
+        } else {
+            transaction.eta
+        };
+        transaction.expires_at =
+            unwrap_int!(executable_at.checked_add(smallet.effective_grace_period(now)));
+    }
+    let space = 8 + transaction.try_to_vec()?.len();
+    write_account(
+        &ctx.accounts.account,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        &transaction,
+        space,
+    )
+}
+
+// Instruction handler for smallet::migrate_guardian_action
+pub fn migrate_guardian_action(ctx: Context<MigrateAccount>) -> Result<()> {
+    let guardian_action = load_guardian_action(&ctx.accounts.account.try_borrow_data()?)?;
+    let space = 8 + guardian_action.try_to_vec()?.len();
+    write_account_of(&ctx, &guardian_action, space)
+}
+
+// Instruction handler for smallet::migrate_subaccount_info
+pub fn migrate_subaccount_info(ctx: Context<MigrateAccount>) -> Result<()> {
+    let subaccount_info = load_subaccount_info(&ctx.accounts.account.try_borrow_data()?)?;
+    write_account_of(&ctx, &subaccount_info, 8 + SubaccountInfo::LEN)
+}
+
+// Rewrites the account of a [MigrateAccount] in the current layout.
+fn write_account_of<T: AccountSerialize>(
+    ctx: &Context<MigrateAccount>,
+    migrated: &T,
+    space: usize,
+) -> Result<()> {
+    write_account(
+        &ctx.accounts.account,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        migrated,
+        space,
+    )
+}
+
+// Rewrites the account in the current layout, growing it if needed.
+fn write_account<'info, T: AccountSerialize>(
+    account: &UncheckedAccount<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    migrated: &T,
+    space: usize,
+) -> Result<()> {
+    let account = account.to_account_info();
+    // Accounts are never shrunk, so previously allocated capacity is kept
+    let space = space.max(account.data_len());
+    realloc_account(
+        &account,
+        &payer.to_account_info(),
+        &system_program.to_account_info(),
+        space,
+    )?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    migrated.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
+
+// Accounts for [smallet::migrate_smallet] and the other migrations.
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: Decoded by the handler according to its version.
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+    // Payer of the additional rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    // The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Validate<'info> for MigrateAccount<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(*self.account.owner, crate::ID, "account.owner");
+        Ok(())
+    }
+}
+
+// Accounts for [smallet::migrate_transaction].
+#[derive(Accounts)]
+pub struct MigrateTransaction<'info> {
+    /// CHECK: Decoded by the handler according to its version.
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+    /// CHECK: The [Smallet] of the transaction, decoded whatever its version.
+    pub smallet: UncheckedAccount<'info>,
+    // Payer of the additional rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    // The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Validate<'info> for MigrateTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(*self.account.owner, crate::ID, "account.owner");
+        assert_keys_eq!(*self.smallet.owner, crate::ID, "smallet.owner");
+        Ok(())
+    }
+}
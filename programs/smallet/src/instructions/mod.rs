@@ -1,37 +1,127 @@
+pub mod activate_global_thresholds;
 pub mod approve;
+pub mod cancel_transaction;
 pub mod change_threshold;
+pub mod close_smallet;
 pub mod create_guardian_action;
 pub mod create_smallet;
 pub mod create_subaccount;
 pub mod create_transaction;
+pub mod emergency_freeze;
 pub mod execute_transaction;
+pub mod expire_transaction;
+pub mod heartbeat;
 pub mod initialize_global_state;
 pub mod lock_smallet;
+pub mod migrate;
 pub mod owner_invoke;
+pub mod resize_smallet;
+pub mod set_emergency_key;
 pub mod set_frozen;
 pub mod set_frozen_admin;
 pub mod set_global_thresholds;
+pub mod set_grace_period;
+pub mod set_guardian_policy;
+pub mod set_guardian_quorums;
+pub mod set_inactivity_period;
+pub mod set_lock_duration;
+pub mod set_minimum_delay;
+pub mod set_owner_roles;
 pub mod set_owners;
+pub mod set_sequential;
 pub mod set_session;
+pub mod set_value_tiers;
+pub mod simulate_transaction;
+pub mod sweep_smallet_account;
 pub mod transfer_global_admin;
 pub mod try_action_with_sign;
 pub mod unapprove;
 
-pub use approve::*;
-pub use change_threshold::*;
-pub use create_guardian_action::*;
-pub use create_smallet::*;
-pub use create_subaccount::*;
-pub use create_transaction::*;
-pub use execute_transaction::*;
-pub use initialize_global_state::*;
-pub use lock_smallet::*;
-pub use owner_invoke::*;
-pub use set_frozen::*;
-pub use set_frozen_admin::*;
-pub use set_global_thresholds::*;
-pub use set_owners::*;
-pub use set_session::*;
-pub use transfer_global_admin::*;
-pub use try_action_with_sign::*;
-pub use unapprove::*;
+pub use activate_global_thresholds::ActivateGlobalThresholds;
+pub use approve::Approve;
+pub use cancel_transaction::CancelTransaction;
+pub use close_smallet::CloseSmallet;
+pub use create_guardian_action::{perform_action, CreateGuardianAction};
+pub use create_smallet::CreateSmallet;
+pub use create_subaccount::CreateSubaccountInfo;
+pub use create_transaction::CreateTransaction;
+pub use emergency_freeze::EmergencyFreeze;
+pub use execute_transaction::ExecuteTransaction;
+pub use expire_transaction::ExpireTransaction;
+pub use heartbeat::Heartbeat;
+pub use initialize_global_state::InitializeGlobalState;
+pub use lock_smallet::LockSmallet;
+pub use migrate::{MigrateAccount, MigrateTransaction};
+pub use owner_invoke::OwnerInvokeInstruction;
+pub use resize_smallet::{realloc_account, ResizeSmallet};
+pub use set_frozen::SetFrozen;
+pub use set_frozen_admin::SetFrozenAdmin;
+pub use set_global_thresholds::SetGlobalThresholds;
+pub use set_owners::{update_guardians, update_owners, Auth};
+pub use set_session::SetSession;
+pub use simulate_transaction::{AccountDelta, SimulateTransaction, SimulationReport};
+pub use sweep_smallet_account::SweepSmalletAccount;
+pub use transfer_global_admin::TransferGlobalAdmin;
+pub use try_action_with_sign::PerformGuardianAction;
+
+// Helpers shared between instruction handlers.
+pub(crate) use execute_transaction::{
+    check_execution_order, failed_assertion, validate_executable, wallet_seeds,
+};
+
+// Client account modules generated by `#[derive(Accounts)]`, which `#[program]`
+// expects at the crate root.
+pub(crate) use {
+    activate_global_thresholds::__client_accounts_activate_global_thresholds,
+    approve::__client_accounts_approve, cancel_transaction::__client_accounts_cancel_transaction,
+    close_smallet::__client_accounts_close_smallet,
+    create_guardian_action::__client_accounts_create_guardian_action,
+    create_smallet::__client_accounts_create_smallet,
+    create_subaccount::__client_accounts_create_subaccount_info,
+    create_transaction::__client_accounts_create_transaction,
+    emergency_freeze::__client_accounts_emergency_freeze,
+    execute_transaction::__client_accounts_execute_transaction,
+    expire_transaction::__client_accounts_expire_transaction,
+    heartbeat::__client_accounts_heartbeat,
+    initialize_global_state::__client_accounts_initialize_global_state,
+    lock_smallet::__client_accounts_lock_smallet, migrate::__client_accounts_migrate_account,
+    migrate::__client_accounts_migrate_transaction,
+    owner_invoke::__client_accounts_owner_invoke_instruction,
+    resize_smallet::__client_accounts_resize_smallet, set_frozen::__client_accounts_set_frozen,
+    set_frozen_admin::__client_accounts_set_frozen_admin,
+    set_global_thresholds::__client_accounts_set_global_thresholds,
+    set_owners::__client_accounts_auth, set_session::__client_accounts_set_session,
+    simulate_transaction::__client_accounts_simulate_transaction,
+    sweep_smallet_account::__client_accounts_sweep_smallet_account,
+    transfer_global_admin::__client_accounts_transfer_global_admin,
+    try_action_with_sign::__client_accounts_perform_guardian_action,
+};
+#[cfg(feature = "cpi")]
+pub(crate) use {
+    activate_global_thresholds::__cpi_client_accounts_activate_global_thresholds,
+    approve::__cpi_client_accounts_approve,
+    cancel_transaction::__cpi_client_accounts_cancel_transaction,
+    close_smallet::__cpi_client_accounts_close_smallet,
+    create_guardian_action::__cpi_client_accounts_create_guardian_action,
+    create_smallet::__cpi_client_accounts_create_smallet,
+    create_subaccount::__cpi_client_accounts_create_subaccount_info,
+    create_transaction::__cpi_client_accounts_create_transaction,
+    emergency_freeze::__cpi_client_accounts_emergency_freeze,
+    execute_transaction::__cpi_client_accounts_execute_transaction,
+    expire_transaction::__cpi_client_accounts_expire_transaction,
+    heartbeat::__cpi_client_accounts_heartbeat,
+    initialize_global_state::__cpi_client_accounts_initialize_global_state,
+    lock_smallet::__cpi_client_accounts_lock_smallet,
+    migrate::__cpi_client_accounts_migrate_account,
+    migrate::__cpi_client_accounts_migrate_transaction,
+    owner_invoke::__cpi_client_accounts_owner_invoke_instruction,
+    resize_smallet::__cpi_client_accounts_resize_smallet,
+    set_frozen::__cpi_client_accounts_set_frozen,
+    set_frozen_admin::__cpi_client_accounts_set_frozen_admin,
+    set_global_thresholds::__cpi_client_accounts_set_global_thresholds,
+    set_owners::__cpi_client_accounts_auth, set_session::__cpi_client_accounts_set_session,
+    simulate_transaction::__cpi_client_accounts_simulate_transaction,
+    sweep_smallet_account::__cpi_client_accounts_sweep_smallet_account,
+    transfer_global_admin::__cpi_client_accounts_transfer_global_admin,
+    try_action_with_sign::__cpi_client_accounts_perform_guardian_action,
+};
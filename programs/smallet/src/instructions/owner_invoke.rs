@@ -19,7 +19,7 @@ pub fn owner_invoke_instruction(
 
     solana_program::program::invoke_signed(&(&ix).into(), ctx.remaining_accounts, invoker_seeds)?;
 
-    Ok(())
+    record_invoker_activity(ctx)
 }
 
 // Instruction handler for smallet::owner_invoke_instruction_v2
@@ -55,6 +55,16 @@ pub fn owner_invoke_instruction_v2(
     };
 
     solana_program::program::invoke_signed(ix, ctx.remaining_accounts, invoker_seeds)?;
+    record_invoker_activity(ctx)
+}
+
+// Records the owner activity once the invoked instruction has run.
+fn record_invoker_activity(ctx: Context<OwnerInvokeInstruction>) -> Result<()> {
+    // The invoked instruction may have written to the smallet
+    ctx.accounts.smallet.reload()?;
+    ctx.accounts
+        .smallet
+        .record_owner_activity(Clock::get()?.unix_timestamp);
     Ok(())
 }
 
@@ -62,6 +72,7 @@ pub fn owner_invoke_instruction_v2(
 #[derive(Accounts)]
 pub struct OwnerInvokeInstruction<'info> {
     // The [Smallet]
+    #[account(mut)]
     pub smallet: Account<'info, Smallet>,
     // An owner of the [Smallet].
     pub owner: Signer<'info>,
@@ -71,8 +82,14 @@ impl<'info> Validate<'info> for OwnerInvokeInstruction<'info> {
     fn validate(&self) -> Result<()> {
         // Smallet is frozen
         invariant!(!self.smallet.frozen, AccountFrozen);
+        // Smallet is locked
+        invariant!(
+            !self.smallet.is_locked(Clock::get()?.unix_timestamp),
+            AccountLocked
+        );
 
-        self.smallet.try_owner_index(self.owner.key())?;
+        self.smallet
+            .try_owner_index_with_role(self.owner.key(), ROLE_INVOKER)?;
         Ok(())
     }
 }
@@ -0,0 +1,75 @@
+//! Instruction handler for smallet:resize_smallet
+
+use crate::*;
+
+// Instruction handler for smallet::resize_smallet
+pub fn handler(ctx: Context<ResizeSmallet>, max_owners: u8, max_guardians: u8) -> Result<()> {
+    let smallet_info = ctx.accounts.smallet.to_account_info();
+    let space = Smallet::space(max_owners, max_guardians);
+    // Smallets can only grow, as shrinking would leave rent without a recipient
+    invariant!(space >= smallet_info.data_len(), "smallet can only grow");
+
+    realloc_account(
+        &smallet_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        space,
+    )?;
+
+    emit!(WalletResizeEvent {
+        smallet: ctx.accounts.smallet.key(),
+        space: space as u64,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
+// Reallocs `account` to `space` bytes, the payer topping up rent for the new size.
+pub fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let missing_lamports = required_lamports.saturating_sub(account.lamports());
+    if missing_lamports > 0 {
+        solana_program::program::invoke(
+            &solana_program::system_instruction::transfer(payer.key, account.key, missing_lamports),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+
+    account.realloc(space, false)?;
+    Ok(())
+}
+
+// Accounts for [smallet::resize_smallet].
+#[derive(Accounts)]
+pub struct ResizeSmallet<'info> {
+    // The [Smallet]
+    #[account(mut, signer)]
+    pub smallet: Account<'info, Smallet>,
+    // Payer of the additional rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    // The [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Validate<'info> for ResizeSmallet<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.smallet.to_account_info().is_signer,
+            "smallet.is_signer"
+        );
+        // Smallet is frozen
+        invariant!(!self.smallet.frozen, AccountFrozen);
+        // Smallet is locked
+        invariant!(
+            !self.smallet.is_locked(Clock::get()?.unix_timestamp),
+            AccountLocked
+        );
+        Ok(())
+    }
+}
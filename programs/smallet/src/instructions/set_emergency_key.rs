@@ -0,0 +1,16 @@
+//! Instruction handler for smallet:set_emergency_key
+
+use crate::*;
+
+// Instruction handler for smallet::set_emergency_key
+pub fn handler(ctx: Context<Auth>, emergency_key: Option<Pubkey>) -> Result<()> {
+    let smallet = &mut ctx.accounts.smallet;
+    smallet.emergency_key = emergency_key.unwrap_or_default();
+
+    emit!(WalletSetEmergencyKeyEvent {
+        smallet: ctx.accounts.smallet.key(),
+        emergency_key,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
@@ -16,11 +16,13 @@ pub fn handler(ctx: Context<SetFrozenAdmin>, frozen: bool) -> Result<()> {
 
 impl<'info> Validate<'info> for SetFrozenAdmin<'info> {
     fn validate(&self) -> Result<()> {
+        let global_state = load_global_state_account(&self.global_state)?;
         // Check if admin is the current global admin
         invariant!(
-            self.global_state.is_global_admin(&self.admin.key()),
+            global_state.is_global_admin(&self.admin.key()),
             InvalidGlobalAdmin
         );
+        global_state.check_domain(&self.smallet)?;
         Ok(())
     }
 }
@@ -32,7 +34,8 @@ pub struct SetFrozenAdmin<'info> {
     #[account(mut)]
     pub smallet: Account<'info, Smallet>,
     // The [GlobalState].
-    pub global_state: Account<'info, GlobalState>,
+    /// CHECK: Loaded by [load_global_state_account] whatever its version.
+    pub global_state: UncheckedAccount<'info>,
     // Current global admin
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -8,18 +8,32 @@ pub fn handler(
     action_expires: Option<i64>,
     min_agree_permyriad: Option<u16>,
 ) -> Result<()> {
-    if let Some(period) = change_period {
-        ctx.accounts.global_state.guardians_change_period = period;
-    }
-
-    if let Some(expires) = action_expires {
-        ctx.accounts.global_state.guardians_action_expires_time = expires;
-    }
+    let global_state = &mut ctx.accounts.global_state;
+    // Omitted values keep the scheduled ones, if any, so partial updates compose
+    let current = if global_state.thresholds_activate_at != 0 {
+        global_state.pending_thresholds
+    } else {
+        global_state.thresholds()
+    };
+    let thresholds = GuardianPolicy {
+        change_period: change_period.unwrap_or(current.change_period),
+        action_expires_time: action_expires.unwrap_or(current.action_expires_time),
+        min_agree_permyriad: min_agree_permyriad.unwrap_or(current.min_agree_permyriad),
+    };
+    thresholds.validate()?;
 
-    if let Some(permyriad) = min_agree_permyriad {
-        ctx.accounts.global_state.min_agree_permyriad = permyriad;
-    }
+    // Changes only apply after the notice period, replacing any earlier schedule
+    let now = Clock::get()?.unix_timestamp;
+    let activate_at = unwrap_int!(now.checked_add(GLOBAL_THRESHOLDS_NOTICE_PERIOD));
+    global_state.pending_thresholds = thresholds;
+    global_state.thresholds_activate_at = activate_at;
 
+    emit!(GlobalThresholdsScheduledEvent {
+        global_state: ctx.accounts.global_state.key(),
+        thresholds,
+        activate_at,
+        timestamp: now
+    });
     Ok(())
 }
 
@@ -0,0 +1,38 @@
+//! Instruction handler for smallet:set_grace_period
+
+use crate::*;
+
+// Instruction handler for smallet::set_grace_period
+pub fn handler(ctx: Context<Auth>, grace_period: i64) -> Result<()> {
+    invariant!(grace_period >= 0, "grace period must be positive");
+    invariant!(grace_period <= MAX_DELAY_SECONDS, DelayTooHigh);
+
+    let now = Clock::get()?.unix_timestamp;
+    let smallet = &mut ctx.accounts.smallet;
+    smallet.apply_pending_delays(now);
+
+    // Changes only take effect once the current minimum delay has passed:
+    // reductions would expire approved transactions, increases would revive
+    // stale approvals
+    let effective_at = if grace_period != smallet.grace_period {
+        unwrap_int!(now.checked_add(smallet.minimum_delay))
+    } else {
+        now
+    };
+    if effective_at == now {
+        smallet.grace_period = grace_period;
+        smallet.pending_grace_period = 0;
+        smallet.grace_period_change_at = 0;
+    } else {
+        smallet.pending_grace_period = grace_period;
+        smallet.grace_period_change_at = effective_at;
+    }
+
+    emit!(WalletSetGracePeriodEvent {
+        smallet: ctx.accounts.smallet.key(),
+        grace_period,
+        effective_at,
+        timestamp: now
+    });
+    Ok(())
+}
@@ -0,0 +1,20 @@
+//! Instruction handler for smallet:set_guardian_policy
+
+use crate::*;
+
+// Instruction handler for smallet::set_guardian_policy
+pub fn handler(ctx: Context<Auth>, guardian_policy: Option<GuardianPolicy>) -> Result<()> {
+    if let Some(policy) = guardian_policy {
+        policy.validate()?;
+    }
+
+    let smallet = &mut ctx.accounts.smallet;
+    smallet.guardian_policy = guardian_policy;
+
+    emit!(WalletSetGuardianPolicyEvent {
+        smallet: ctx.accounts.smallet.key(),
+        guardian_policy,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
@@ -0,0 +1,40 @@
+//! Instruction handler for smallet:set_guardian_quorums
+
+use crate::*;
+
+// Instruction handler for smallet::set_guardian_quorums
+pub fn handler(ctx: Context<Auth>, guardian_quorums: Vec<GuardianQuorum>) -> Result<()> {
+    invariant!(
+        guardian_quorums.len() <= MAX_GUARDIAN_QUORUMS,
+        InvalidGuardianQuorums
+    );
+
+    for (i, quorum) in guardian_quorums.iter().enumerate() {
+        invariant!(
+            quorum.action_kind != GuardianActionKind::NoAction,
+            InvalidGuardianQuorums
+        );
+        invariant!(quorum.min_agree_permyriad <= 10000, InvalidGuardianQuorums);
+        invariant!(
+            quorum.delay >= 0 && quorum.delay <= MAX_DELAY_SECONDS,
+            InvalidGuardianQuorums
+        );
+        // One quorum per action type
+        invariant!(
+            guardian_quorums[..i]
+                .iter()
+                .all(|other| other.action_kind != quorum.action_kind),
+            InvalidGuardianQuorums
+        );
+    }
+
+    let smallet = &mut ctx.accounts.smallet;
+    smallet.guardian_quorums = guardian_quorums.clone();
+
+    emit!(WalletSetGuardianQuorumsEvent {
+        smallet: ctx.accounts.smallet.key(),
+        guardian_quorums,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
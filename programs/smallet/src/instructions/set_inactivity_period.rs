@@ -0,0 +1,18 @@
+//! Instruction handler for smallet:set_inactivity_period
+
+use crate::*;
+
+// Instruction handler for smallet::set_inactivity_period
+pub fn handler(ctx: Context<Auth>, inactivity_period: i64) -> Result<()> {
+    invariant!(inactivity_period >= 0, "inactivity period must be positive");
+
+    let smallet = &mut ctx.accounts.smallet;
+    smallet.inactivity_period = inactivity_period;
+
+    emit!(WalletSetInactivityPeriodEvent {
+        smallet: ctx.accounts.smallet.key(),
+        inactivity_period,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//! Instruction handler for smallet:set_lock_duration
+
+use crate::*;
+
+// Instruction handler for smallet::set_lock_duration
+pub fn handler(ctx: Context<Auth>, lock_duration: i64) -> Result<()> {
+    invariant!(lock_duration >= 0, "lock duration must be positive");
+    invariant!(lock_duration <= MAX_DELAY_SECONDS, DelayTooHigh);
+
+    let smallet = &mut ctx.accounts.smallet;
+    smallet.lock_duration = lock_duration;
+
+    emit!(WalletSetLockDurationEvent {
+        smallet: ctx.accounts.smallet.key(),
+        lock_duration,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
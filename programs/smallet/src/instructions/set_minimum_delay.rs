@@ -0,0 +1,37 @@
+//! Instruction handler for smallet:set_minimum_delay
+
+use crate::*;
+
+// Instruction handler for smallet::set_minimum_delay
+pub fn handler(ctx: Context<Auth>, minimum_delay: i64) -> Result<()> {
+    invariant!(minimum_delay >= 0, "delay must be positive");
+    invariant!(minimum_delay <= MAX_DELAY_SECONDS, DelayTooHigh);
+
+    let now = Clock::get()?.unix_timestamp;
+    let smallet = &mut ctx.accounts.smallet;
+    smallet.apply_pending_delays(now);
+
+    // Reductions only take effect once the current delay has passed,
+    // so the timelock cannot be shortened and bypassed at once
+    let effective_at = if minimum_delay < smallet.minimum_delay {
+        unwrap_int!(now.checked_add(smallet.minimum_delay))
+    } else {
+        now
+    };
+    if effective_at == now {
+        smallet.minimum_delay = minimum_delay;
+        smallet.pending_minimum_delay = 0;
+        smallet.minimum_delay_change_at = 0;
+    } else {
+        smallet.pending_minimum_delay = minimum_delay;
+        smallet.minimum_delay_change_at = effective_at;
+    }
+
+    emit!(WalletSetMinimumDelayEvent {
+        smallet: ctx.accounts.smallet.key(),
+        minimum_delay,
+        effective_at,
+        timestamp: now
+    });
+    Ok(())
+}
@@ -0,0 +1,25 @@
+//! Instruction handler for smallet:set_owner_roles
+
+use crate::*;
+
+// Instruction handler for smallet::set_owner_roles
+pub fn handler(ctx: Context<Auth>, roles: Vec<u8>) -> Result<()> {
+    let smallet = &mut ctx.accounts.smallet;
+    // The threshold must stay reachable by approvers
+    validate_owner_set(&smallet.owners, &roles, smallet.threshold)?;
+
+    // Revoke sessions of owners that can no longer approve
+    for (session, role) in smallet.owner_sessions.iter_mut().zip(roles.iter()) {
+        if role & ROLE_APPROVER == 0 {
+            *session = NO_ETA;
+        }
+    }
+    smallet.owner_roles = roles.clone();
+
+    emit!(WalletSetOwnerRolesEvent {
+        smallet: ctx.accounts.smallet.key(),
+        roles,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
@@ -4,24 +4,49 @@ use crate::*;
 
 // Instruction handler for smallet::set_owners
 pub fn handler(ctx: Context<Auth>, owners: Vec<Pubkey>) -> Result<()> {
-    let smallet = &mut ctx.accounts.smallet;
-    if (owners.len() as u64) < smallet.threshold {
-        smallet.threshold = owners.len() as u64;
-    }
+    // Owners must explicitly lower the threshold before removing owners
+    let roles = ctx.accounts.smallet.roles_for_owners(&owners);
+    validate_owner_set(&owners, &roles, ctx.accounts.smallet.threshold)?;
+    update_owners(&mut ctx.accounts.smallet, owners)
+}
+
+// Replaces the owners of the [Smallet].
+// Shared by [smallet::set_owners] and guardian actions.
+// The threshold is lowered if the approvers of the new owner set cannot reach it.
+pub fn update_owners(smallet: &mut Account<Smallet>, owners: Vec<Pubkey>) -> Result<()> {
+    let roles = smallet.roles_for_owners(&owners);
+    smallet.threshold = smallet.threshold.min(count_approvers(&roles));
+    validate_owner_set(&owners, &roles, smallet.threshold)?;
 
+    smallet.owner_roles = roles;
     smallet.owners = owners.clone();
-    smallet.owner_set_seqno = unwrap_int!(smallet.owner_set_seqno.checked_add(1));
+    smallet.bump_owner_set_seqno()?;
     // Revoke all sessions when change a set of owners
     smallet.owner_sessions = vec![NO_ETA; owners.len()];
+    smallet.check_capacity(smallet.to_account_info().data_len())?;
 
     emit!(WalletSetOwnersEvent {
-        smallet: ctx.accounts.smallet.key(),
+        smallet: smallet.key(),
         owners,
         timestamp: Clock::get()?.unix_timestamp
     });
     Ok(())
 }
 
+// Replaces the guardians of the [Smallet].
+pub fn update_guardians(smallet: &mut Account<Smallet>, guardians: Vec<Pubkey>) -> Result<()> {
+    validate_addresses(&guardians)?;
+    smallet.guardians = guardians.clone();
+    smallet.check_capacity(smallet.to_account_info().data_len())?;
+
+    emit!(WalletSetGuardiansEvent {
+        smallet: smallet.key(),
+        guardians,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
+
 // Accounts for [smallet::set_owners] and [smallet::change_threshold].
 #[derive(Accounts)]
 pub struct Auth<'info> {
@@ -38,6 +63,11 @@ impl<'info> Validate<'info> for Auth<'info> {
         );
         // Smallet is frozen
         invariant!(!self.smallet.frozen, AccountFrozen);
+        // Smallet is locked
+        invariant!(
+            !self.smallet.is_locked(Clock::get()?.unix_timestamp),
+            AccountLocked
+        );
         Ok(())
     }
 }
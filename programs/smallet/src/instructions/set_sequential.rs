@@ -0,0 +1,16 @@
+//! Instruction handler for smallet:set_sequential
+
+use crate::*;
+
+// Instruction handler for smallet::set_sequential
+pub fn handler(ctx: Context<Auth>, sequential: bool) -> Result<()> {
+    let smallet = &mut ctx.accounts.smallet;
+    smallet.sequential = sequential;
+
+    emit!(WalletSetSequentialEvent {
+        smallet: ctx.accounts.smallet.key(),
+        sequential,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
@@ -21,6 +21,7 @@ pub fn handler(ctx: Context<SetSession>, expires_at: Option<i64>) -> Result<()>
     }
 
     ctx.accounts.smallet.owner_sessions[owner_index] = new_ts;
+    ctx.accounts.smallet.record_owner_activity(current_ts);
 
     emit!(OwnerSetSessionEvent {
         smallet: ctx.accounts.smallet.key(),
@@ -39,3 +40,17 @@ pub struct SetSession<'info> {
     // One of the smallet owners.
     pub owner: Signer<'info>,
 }
+
+impl<'info> Validate<'info> for SetSession<'info> {
+    fn validate(&self) -> Result<()> {
+        // Smallet is locked
+        invariant!(
+            !self.smallet.is_locked(Clock::get()?.unix_timestamp),
+            AccountLocked
+        );
+        // A session auto-signs transactions, so only approvers may set one
+        self.smallet
+            .try_owner_index_with_role(self.owner.key(), ROLE_APPROVER)?;
+        Ok(())
+    }
+}
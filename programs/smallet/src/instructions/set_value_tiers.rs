@@ -0,0 +1,30 @@
+//! Instruction handler for smallet:set_value_tiers
+
+use crate::*;
+
+// Instruction handler for smallet::set_value_tiers
+pub fn handler(ctx: Context<Auth>, value_tiers: Vec<ValueTier>) -> Result<()> {
+    invariant!(value_tiers.len() <= MAX_VALUE_TIERS, TooManyValueTiers);
+
+    let smallet = &mut ctx.accounts.smallet;
+    let roles = smallet.current_roles();
+    for (i, tier) in value_tiers.iter().enumerate() {
+        invariant!(tier.threshold > 0, InvalidValueTiers);
+        // Every tier must be reachable by the approvers
+        validate_owner_set(&smallet.owners, &roles, tier.threshold)?;
+        // Tiers of the same mint must be listed in increasing amount order
+        let previous = value_tiers[..i].iter().rev().find(|t| t.mint == tier.mint);
+        if let Some(previous) = previous {
+            invariant!(previous.max_amount < tier.max_amount, InvalidValueTiers);
+        }
+    }
+
+    smallet.value_tiers = value_tiers.clone();
+
+    emit!(WalletSetValueTiersEvent {
+        smallet: ctx.accounts.smallet.key(),
+        value_tiers,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
@@ -0,0 +1,176 @@
+//! Instruction handler for smallet:simulate_transaction
+
+use crate::*;
+use anchor_spl::token::{self, TokenAccount};
+
+// Outcome of a simulated [Transaction].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct SimulationReport {
+    // Number of instructions that executed successfully.
+    pub executed: u64,
+    // Index of the pre-assertion that did not hold, in which case no
+    // instruction was executed.
+    pub failed_pre_assertion: Option<u64>,
+    // Index of the post-assertion that did not hold after the instructions.
+    pub failed_post_assertion: Option<u64>,
+    // Lamport changes of the accounts whose balance changed.
+    pub lamport_deltas: Vec<AccountDelta>,
+    // Token changes of the token accounts whose balance changed.
+    pub token_deltas: Vec<AccountDelta>,
+}
+
+// Balance change of an account during a simulation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct AccountDelta {
+    pub account: Pubkey,
+    pub delta: i128,
+}
+
+// Lamport and token balances of an account.
+struct Balances {
+    account: Pubkey,
+    lamports: u64,
+    tokens: Option<u64>,
+}
+
+fn read_balances(info: &AccountInfo) -> Result<Balances> {
+    let tokens = if *info.owner == token::ID {
+        TokenAccount::try_deserialize(&mut &info.try_borrow_data()?[..])
+            .ok()
+            .map(|account| account.amount)
+    } else {
+        None
+    };
+    Ok(Balances {
+        account: *info.key,
+        lamports: info.lamports(),
+        tokens,
+    })
+}
+
+// Instruction handler for smallet::simulate_transaction
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SimulateTransaction<'info>>,
+    instructions: Option<Vec<TXInstruction>>,
+    index: Option<u64>,
+    bump: Option<u8>,
+) -> Result<()> {
+    let smallet = &ctx.accounts.smallet;
+    let transaction = &ctx.accounts.transaction;
+    let instructions = transaction.instructions_to_execute(instructions)?;
+    let instructions = transaction.resolve_lookups(instructions, ctx.remaining_accounts)?;
+
+    // The instructions are signed by the same wallet as on execution
+    let derived = match (index, bump) {
+        (Some(index), Some(bump)) => Some((index, bump)),
+        (None, None) => None,
+        _ => return Err(crate::ErrorCode::InvalidDerivedWallet.into()),
+    };
+    let wallet_seeds = wallet_seeds(smallet, derived);
+    let signer = wallet_seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    let seeds: &[&[&[u8]]] = &[signer.as_slice()];
+
+    // Same ordering constraints as execution
+    let first = transaction.executed_steps as usize;
+    if first == 0 {
+        check_execution_order(smallet, transaction, ctx.remaining_accounts)?;
+    }
+
+    let smallet_info = smallet.to_account_info();
+    let mut infos: Vec<&AccountInfo> = vec![&smallet_info];
+    for info in ctx.remaining_accounts.iter() {
+        if !infos.iter().any(|known| known.key == info.key) {
+            infos.push(info);
+        }
+    }
+    let before = infos
+        .iter()
+        .map(|info| read_balances(info))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Same assertions as execution, reported instead of failing the simulation
+    let initial_lamports = before
+        .iter()
+        .map(|balances| (balances.account, balances.lamports))
+        .collect::<Vec<_>>();
+    let mut report = SimulationReport {
+        failed_pre_assertion: failed_assertion(
+            &transaction.pre_assertions,
+            &smallet_info,
+            ctx.remaining_accounts,
+            &initial_lamports,
+        )?
+        .map(|index| index as u64),
+        ..Default::default()
+    };
+    if report.failed_pre_assertion.is_some() {
+        return complete_simulation(&report);
+    }
+
+    // A failing instruction aborts the simulation without a report, the last
+    // logged step is the one that failed
+    for (step, ix) in instructions.iter().enumerate().skip(first) {
+        msg!("simulate: step {}", step);
+        solana_program::program::invoke_signed(&(ix).into(), ctx.remaining_accounts, seeds)?;
+    }
+    report.executed = (instructions.len() - first) as u64;
+
+    report.failed_post_assertion = failed_assertion(
+        &transaction.post_assertions,
+        &smallet_info,
+        ctx.remaining_accounts,
+        &initial_lamports,
+    )?
+    .map(|index| index as u64);
+    for (info, before) in infos.iter().zip(before.iter()) {
+        let after = read_balances(info)?;
+        if after.lamports != before.lamports {
+            report.lamport_deltas.push(AccountDelta {
+                account: before.account,
+                delta: after.lamports as i128 - before.lamports as i128,
+            });
+        }
+        if after.tokens != before.tokens {
+            report.token_deltas.push(AccountDelta {
+                account: before.account,
+                delta: after.tokens.unwrap_or(0) as i128 - before.tokens.unwrap_or(0) as i128,
+            });
+        }
+    }
+
+    complete_simulation(&report)
+}
+
+// Returns the report and reverts everything the instructions did.
+fn complete_simulation(report: &SimulationReport) -> Result<()> {
+    msg!("simulate: {:?}", report);
+    solana_program::program::set_return_data(&report.try_to_vec()?);
+    Err(crate::ErrorCode::SimulationComplete.into())
+}
+
+// Accounts for [smallet::simulate_transaction].
+#[derive(Accounts)]
+pub struct SimulateTransaction<'info> {
+    // The [Smallet].
+    #[account(mut)]
+    pub smallet: Account<'info, Smallet>,
+    // The [Transaction] to simulate.
+    pub transaction: Account<'info, Transaction>,
+    // An owner of the [Smallet].
+    pub owner: Signer<'info>,
+}
+
+impl<'info> Validate<'info> for SimulateTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        // Same checks as execution, except for the approvals
+        validate_executable(
+            &self.smallet,
+            &self.transaction,
+            Clock::get()?.unix_timestamp,
+        )?;
+        // Only owners allowed to execute may simulate, as execution would
+        self.smallet
+            .try_owner_index_with_role(self.owner.key(), ROLE_EXECUTOR)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+//! Instruction handler for smallet:sweep_smallet_account
+
+use crate::*;
+
+// Instruction handler for smallet::sweep_smallet_account
+pub fn handler(ctx: Context<SweepSmalletAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+    let recipient = ctx.accounts.recipient.to_account_info();
+
+    let lamports = account.lamports();
+    **recipient.try_borrow_mut_lamports()? = unwrap_int!(recipient.lamports().checked_add(lamports));
+    **account.try_borrow_mut_lamports()? = 0;
+    account.try_borrow_mut_data()?.fill(0);
+
+    Ok(())
+}
+
+// Smallet a [Transaction], [GuardianAction] or [SubaccountInfo] belongs to.
+// Accounts of any layout version are accepted.
+fn owning_smallet(account: &AccountInfo) -> Result<Pubkey> {
+    assert_keys_eq!(*account.owner, crate::ID, NotSmalletAccount);
+    let data = account.try_borrow_data()?;
+    if let Ok(transaction) = load_transaction(&data) {
+        return Ok(transaction.smallet);
+    }
+    if let Ok(guardian_action) = load_guardian_action(&data) {
+        return Ok(guardian_action.smallet);
+    }
+    if let Ok(subaccount_info) = load_subaccount_info(&data) {
+        return Ok(subaccount_info.smallet);
+    }
+    Err(crate::ErrorCode::NotSmalletAccount.into())
+}
+
+// Accounts for [smallet::sweep_smallet_account].
+#[derive(Accounts)]
+pub struct SweepSmalletAccount<'info> {
+    /// CHECK: Must have been closed. Checked in the validator.
+    pub smallet: UncheckedAccount<'info>,
+    /// CHECK: A [Transaction], [GuardianAction] or [SubaccountInfo] of the smallet.
+    /// Checked in the validator.
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+    /// CHECK: Any account may receive the rent.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+impl<'info> Validate<'info> for SweepSmalletAccount<'info> {
+    fn validate(&self) -> Result<()> {
+        // A closed smallet no longer belongs to the program
+        let smallet = self.smallet.to_account_info();
+        invariant!(
+            *smallet.owner != crate::ID || smallet.data_is_empty(),
+            SmalletNotClosed
+        );
+
+        let owner = owning_smallet(&self.account.to_account_info())?;
+        assert_keys_eq!(owner, self.smallet.key(), NotSmalletAccount);
+        Ok(())
+    }
+}
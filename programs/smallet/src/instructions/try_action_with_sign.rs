@@ -4,7 +4,7 @@ use crate::*;
 
 // Instruction handler for smallet::try_action_with_sign
 pub fn handler(ctx: Context<PerformGuardianAction>, _index: u64) -> Result<()> {
-    let global_state = &ctx.accounts.global_state;
+    let global_state = &load_global_state_account(&ctx.accounts.global_state)?;
     let smallet = &mut ctx.accounts.smallet;
     let guardian_action: &mut Account<'_, GuardianAction> = &mut ctx.accounts.guardian_action;
 
@@ -15,10 +15,10 @@ pub fn handler(ctx: Context<PerformGuardianAction>, _index: u64) -> Result<()> {
         // set this guardian as signed
         guardian_action.set_signed_with_index(guardian_idx);
 
-        // check if signs are enough for action
-        if guardian_action.check_enough_sign(global_state.min_agree_permyriad) {
+        // check if signs are enough and the delay has passed for action
+        let quorum = global_state.guardian_quorum(smallet, guardian_action.action_type);
+        if guardian_action.is_ready(&quorum, Clock::get()?.unix_timestamp) {
             perform_action(guardian_action, smallet, global_state)?;
-            // TODO: Should emit event for guardian action
         }
 
         Ok(())
@@ -35,7 +35,8 @@ pub struct PerformGuardianAction<'info> {
     #[account(mut)]
     pub guardian: Signer<'info>,
     // The [GlobalState].
-    pub global_state: Account<'info, GlobalState>,
+    /// CHECK: Loaded by [load_global_state_account] whatever its version.
+    pub global_state: UncheckedAccount<'info>,
     // The [Smallet]
     #[account(mut)]
     pub smallet: Account<'info, Smallet>,
@@ -58,6 +59,7 @@ impl<'info> Validate<'info> for PerformGuardianAction<'info> {
             self.guardian_action.smallet.eq(&self.smallet.key()),
             InvalidGuardianAction
         );
+        load_global_state_account(&self.global_state)?.check_domain(&self.smallet)?;
         Ok(())
     }
 }
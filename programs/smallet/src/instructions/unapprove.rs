@@ -9,11 +9,14 @@ pub fn handler(ctx: Context<Approve>) -> Result<()> {
         .try_owner_index(ctx.accounts.owner.key())?;
     ctx.accounts.transaction.signers[owner_index] = false;
 
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.smallet.record_owner_activity(now);
+
     emit!(TransactionUnapproveEvent {
         smallet: ctx.accounts.smallet.key(),
         transaction: ctx.accounts.transaction.key(),
         owner: ctx.accounts.owner.key(),
-        timestamp: Clock::get()?.unix_timestamp
+        timestamp: now
     });
     Ok(())
 }
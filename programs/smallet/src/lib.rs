@@ -20,6 +20,9 @@
 #![allow(rustdoc::all)]
 #![allow(rustdoc::missing_doc_code_examples)]
 #![allow(clippy::unwrap_used)]
+// Handlers return Anchor's error type and take one argument per instruction argument
+#![allow(clippy::result_large_err)]
+#![allow(clippy::too_many_arguments)]
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
@@ -29,11 +32,15 @@ mod constants;
 mod events;
 mod instructions;
 mod state;
+mod validators;
+mod versions;
 
 pub use constants::*;
 pub use events::*;
 pub use instructions::*;
 pub use state::*;
+pub use validators::*;
+pub use versions::*;
 
 declare_id!("7iFugUof2fQaHojbxskcELz5nCfNfKJx5vd8cY7qPAYU");
 
@@ -43,14 +50,18 @@ pub mod smallet {
     use super::*;
 
     /**
-     * Initializes a global state account.
+     * Initializes the global state account of a domain.
      * Deployer becomes the initial admin of global state.
      */
-    pub fn initialize_global_state(ctx: Context<InitializeGlobalState>) -> Result<()> {
-        instructions::initialize_global_state::handle(ctx)
+    #[access_control(ctx.accounts.validate())]
+    pub fn initialize_global_state(
+        ctx: Context<InitializeGlobalState>,
+        domain: u64,
+    ) -> Result<()> {
+        instructions::initialize_global_state::handle(ctx, domain)
     }
 
-    // Transfer global admin for the [Smallet]
+    // Transfer global admin for the domain of the [GlobalState]
     #[access_control(ctx.accounts.validate())]
     pub fn transfer_global_admin(
         ctx: Context<TransferGlobalAdmin>,
@@ -59,7 +70,8 @@ pub mod smallet {
         instructions::transfer_global_admin::handler(ctx, new_admin)
     }
 
-    // Set global thresholds for the [Smallet]
+    // Schedules new global thresholds for the domain of the [GlobalState],
+    // which can be activated once the notice period has passed
     #[access_control(ctx.accounts.validate())]
     pub fn set_global_thresholds(
         ctx: Context<SetGlobalThresholds>,
@@ -75,7 +87,15 @@ pub mod smallet {
         )
     }
 
-    // Initializes a new [Smallet] account with a set of owners and a threshold
+    // Applies the scheduled global thresholds once their notice period has
+    // passed. Anyone may call it.
+    #[access_control(ctx.accounts.validate())]
+    pub fn activate_global_thresholds(ctx: Context<ActivateGlobalThresholds>) -> Result<()> {
+        instructions::activate_global_thresholds::handler(ctx)
+    }
+
+    // Initializes a new [Smallet] account with a set of owners and a threshold,
+    // joining the domain of the given [GlobalState]
     #[access_control(ctx.accounts.validate())]
     pub fn create_smallet(
         ctx: Context<CreateSmallet>,
@@ -86,16 +106,18 @@ pub mod smallet {
         minimum_delay: i64,
         gudians_count: u8,
         gudians: Vec<Pubkey>,
+        guardian_policy: Option<GuardianPolicy>,
     ) -> Result<()> {
         instructions::create_smallet::handler(
             ctx,
             bump,
-            gudians_count,
             max_owners,
+            gudians_count,
             owners,
             threshold,
             minimum_delay,
             gudians,
+            guardian_policy,
         )
     }
 
@@ -114,14 +136,94 @@ pub mod smallet {
         instructions::change_threshold::handler(ctx, threshold)
     }
 
+    // Sets the approval tiers applied to token and SOL outflows. The only way
+    // this can be invoked is via a recursive call from execute_transaction ->
+    // set_value_tiers.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_value_tiers(ctx: Context<Auth>, value_tiers: Vec<ValueTier>) -> Result<()> {
+        instructions::set_value_tiers::handler(ctx, value_tiers)
+    }
+
+    // Sets the role bitmask of every owner. The only way this can be invoked
+    // is via a recursive call from execute_transaction -> set_owner_roles.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_owner_roles(ctx: Context<Auth>, roles: Vec<u8>) -> Result<()> {
+        instructions::set_owner_roles::handler(ctx, roles)
+    }
+
+    // Grows the [Smallet] account to hold up to the given number of owners and
+    // guardians, the payer covering the additional rent. The only way this can
+    // be invoked is via a recursive call from execute_transaction -> resize_smallet.
+    #[access_control(ctx.accounts.validate())]
+    pub fn resize_smallet(
+        ctx: Context<ResizeSmallet>,
+        max_owners: u8,
+        max_guardians: u8,
+    ) -> Result<()> {
+        instructions::resize_smallet::handler(ctx, max_owners, max_guardians)
+    }
+
+    // Rewrites a [GlobalState] of an older layout version in the current one.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_global_state(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate::migrate_global_state(ctx)
+    }
+
+    // Rewrites a [Smallet] of an older layout version in the current one,
+    // the payer covering any additional rent.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_smallet(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate::migrate_smallet(ctx)
+    }
+
+    // Rewrites a [Transaction] of an older layout version in the current one.
+    // Legacy transactions get an expiry from the grace period of the [Smallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_transaction(ctx: Context<MigrateTransaction>) -> Result<()> {
+        instructions::migrate::migrate_transaction(ctx)
+    }
+
+    // Rewrites a [GuardianAction] of an older layout version in the current one.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_guardian_action(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate::migrate_guardian_action(ctx)
+    }
+
+    // Rewrites a [SubaccountInfo] of an older layout version in the current one.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_subaccount_info(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate::migrate_subaccount_info(ctx)
+    }
+
+    // Closes the [Smallet], sending its rent to the recipient. Refuses while
+    // transactions are pending or any given derived subaccount is not empty.
+    // The only way this can be invoked is via a recursive call from
+    // execute_transaction -> close_smallet.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_smallet<'info>(ctx: Context<'_, '_, '_, 'info, CloseSmallet<'info>>) -> Result<()> {
+        instructions::close_smallet::handler(ctx)
+    }
+
+    // Closes a [Transaction], [GuardianAction] or [SubaccountInfo] of a closed
+    // [Smallet], sending its rent to the recipient. Anyone may call it.
+    #[access_control(ctx.accounts.validate())]
+    pub fn sweep_smallet_account(ctx: Context<SweepSmalletAccount>) -> Result<()> {
+        instructions::sweep_smallet_account::handler(ctx)
+    }
+
     // Creates a new [Transaction] account, automatically signed by the creator,
-    // which must be one of the owners of the smallet.
+    // which must be one of the owners of the smallet with the proposer role.
+    // The options add prerequisites, lookup tables, assertions and an expiry
+    // to the transaction, or store only the hash of its instructions, which are
+    // published in [TransactionCreateEvent] and supplied again on execution.
+    #[access_control(ctx.accounts.validate())]
     pub fn create_transaction(
         ctx: Context<CreateTransaction>,
         bump: u8,
         instructions: Vec<TXInstruction>,
+        options: TransactionOptions,
     ) -> Result<()> {
-        instructions::create_transaction::handler(ctx, bump, instructions, NO_ETA)
+        instructions::create_transaction::handler(ctx, bump, instructions, options, NO_ETA)
     }
 
     // Creates a new [Transaction] account with time delay.
@@ -130,9 +232,24 @@ pub mod smallet {
         ctx: Context<CreateTransaction>,
         bump: u8,
         instructions: Vec<TXInstruction>,
+        options: TransactionOptions,
         eta: i64,
     ) -> Result<()> {
-        instructions::create_transaction::handler(ctx, bump, instructions, eta)
+        instructions::create_transaction::handler(ctx, bump, instructions, options, eta)
+    }
+
+    // Cancels a transaction on behalf of its proposer, settling it for the
+    // sequential mode of the [Smallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_transaction(ctx: Context<CancelTransaction>) -> Result<()> {
+        instructions::cancel_transaction::handler(ctx)
+    }
+
+    // Records the expiry of a pending transaction, which no longer counts as
+    // pending for the [Smallet]. Anyone can call it.
+    #[access_control(ctx.accounts.validate())]
+    pub fn expire_transaction(ctx: Context<ExpireTransaction>) -> Result<()> {
+        instructions::expire_transaction::handler(ctx)
     }
 
     // Approves a transaction on behalf of an owner of the [Smallet]
@@ -149,20 +266,70 @@ pub mod smallet {
 
     // Executes the given transaction if threshold owners have signed it.
     #[access_control(ctx.accounts.validate())]
-    pub fn execute_transaction(ctx: Context<ExecuteTransaction>) -> Result<()> {
-        instructions::execute_transaction(ctx)
+    pub fn execute_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
+    ) -> Result<()> {
+        instructions::execute_transaction::execute_transaction(ctx)
+    }
+
+    // Executes the given hash-committed transaction if threshold owners have
+    // signed it. The instructions must match the committed hash.
+    #[access_control(ctx.accounts.validate())]
+    pub fn execute_hashed_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
+        instructions: Vec<TXInstruction>,
+    ) -> Result<()> {
+        instructions::execute_transaction::execute_hashed_transaction(ctx, instructions)
+    }
+
+    // Executes the next instruction of the given transaction if threshold owners
+    // have signed it. The transaction is marked executed after its last step.
+    // Proposals too large for a single Solana transaction are executed this way.
+    #[access_control(ctx.accounts.validate())]
+    pub fn execute_transaction_step<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
+        step: u64,
+    ) -> Result<()> {
+        instructions::execute_transaction::execute_transaction_step(ctx, step)
+    }
+
+    // Executes the next instruction of the given hash-committed transaction if
+    // threshold owners have signed it. Every instruction must be supplied so
+    // that they match the committed hash.
+    #[access_control(ctx.accounts.validate())]
+    pub fn execute_hashed_transaction_step<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
+        step: u64,
+        instructions: Vec<TXInstruction>,
+    ) -> Result<()> {
+        instructions::execute_transaction::execute_hashed_transaction_step(ctx, step, instructions)
+    }
+
+    // Runs the instructions of the given transaction regardless of its
+    // approvals, then always fails so that nothing commits. The balance
+    // changes and the assertions that did not hold are reported through the
+    // logs and the return data. The derived wallet at `index` with `bump`
+    // signs, if given, as on execution.
+    #[access_control(ctx.accounts.validate())]
+    pub fn simulate_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, SimulateTransaction<'info>>,
+        instructions: Option<Vec<TXInstruction>>,
+        index: Option<u64>,
+        bump: Option<u8>,
+    ) -> Result<()> {
+        instructions::simulate_transaction::handler(ctx, instructions, index, bump)
     }
 
     // Executes the given transaction signed by the given derived address,
     // if threshold owners have signed it.
     // This allows a Smallet to receive SOL.
     #[access_control(ctx.accounts.validate())]
-    pub fn execute_transaction_derived(
-        ctx: Context<ExecuteTransaction>,
+    pub fn execute_transaction_derived<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
         index: u64,
         bump: u8,
     ) -> Result<()> {
-        instructions::execute_transaction_derived(ctx, index, bump)
+        instructions::execute_transaction::execute_transaction_derived(ctx, index, bump)
     }
 
     // Invokes an arbitrary instruction as a PDA derived from the owner,
@@ -176,7 +343,7 @@ pub mod smallet {
         bump: u8,
         ix: TXInstruction,
     ) -> Result<()> {
-        instructions::owner_invoke_instruction(ctx, index, bump, ix)
+        instructions::owner_invoke::owner_invoke_instruction(ctx, index, bump, ix)
     }
 
     // Invokes an arbitrary instruction as a PDA derived from the owner,
@@ -198,7 +365,7 @@ pub mod smallet {
         invoker: Pubkey,
         data: Vec<u8>,
     ) -> Result<()> {
-        instructions::owner_invoke_instruction_v2(ctx, index, bump, invoker, data)
+        instructions::owner_invoke::owner_invoke_instruction_v2(ctx, index, bump, invoker, data)
     }
 
     // Creates a struct containing a reverse mapping of a subaccount to a
@@ -228,18 +395,105 @@ pub mod smallet {
         instructions::set_session::handler(ctx, expires_at)
     }
 
+    // Records owner activity for the [Smallet] without doing anything else,
+    // postponing the inactivity takeover by guardians.
+    #[access_control(ctx.accounts.validate())]
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        instructions::heartbeat::handler(ctx)
+    }
+
+    // Sets the owner inactivity period after which guardians may take over the
+    // owner set. The only way this can be invoked is via a recursive call from
+    // execute_transaction -> set_inactivity_period.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_inactivity_period(ctx: Context<Auth>, inactivity_period: i64) -> Result<()> {
+        instructions::set_inactivity_period::handler(ctx, inactivity_period)
+    }
+
+    // Sets the guardian policy of the [Smallet], or falls back to the global
+    // one if None. The only way this can be invoked is via a recursive call
+    // from execute_transaction -> set_guardian_policy.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_guardian_policy(
+        ctx: Context<Auth>,
+        guardian_policy: Option<GuardianPolicy>,
+    ) -> Result<()> {
+        instructions::set_guardian_policy::handler(ctx, guardian_policy)
+    }
+
+    // Sets the guardian quorum and delay per guardian action type of the
+    // [Smallet]. The only way this can be invoked is via a recursive call
+    // from execute_transaction -> set_guardian_quorums.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_guardian_quorums(
+        ctx: Context<Auth>,
+        guardian_quorums: Vec<GuardianQuorum>,
+    ) -> Result<()> {
+        instructions::set_guardian_quorums::handler(ctx, guardian_quorums)
+    }
+
     // Set frozen for the [Smallet]
     #[access_control(ctx.accounts.validate())]
     pub fn set_frozen(ctx: Context<SetFrozen>, frozen: bool) -> Result<()> {
         instructions::set_frozen::handler(ctx, frozen)
     }
 
+    // Freeze the [Smallet] immediately as a single owner with the freezer role
+    // or as the emergency key. Unfreezing still requires [smallet::set_frozen].
+    #[access_control(ctx.accounts.validate())]
+    pub fn emergency_freeze(ctx: Context<EmergencyFreeze>) -> Result<()> {
+        instructions::emergency_freeze::handler(ctx)
+    }
+
+    // Sets the emergency key allowed to freeze the [Smallet]. The only way
+    // this can be invoked is via a recursive call from execute_transaction ->
+    // set_emergency_key.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_emergency_key(ctx: Context<Auth>, emergency_key: Option<Pubkey>) -> Result<()> {
+        instructions::set_emergency_key::handler(ctx, emergency_key)
+    }
+
     // Set frozen by admin for the [Smallet]
     #[access_control(ctx.accounts.validate())]
     pub fn set_frozen_admin(ctx: Context<SetFrozenAdmin>, frozen: bool) -> Result<()> {
         instructions::set_frozen_admin::handler(ctx, frozen)
     }
 
+    // Sets whether transactions of the [Smallet] can only execute after the
+    // previous one has been executed or cancelled.
+    // The only way this can be invoked is via a recursive call from
+    // execute_transaction -> set_sequential.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_sequential(ctx: Context<Auth>, sequential: bool) -> Result<()> {
+        instructions::set_sequential::handler(ctx, sequential)
+    }
+
+    // Sets the minimum timelock delay of the [Smallet]. A reduction only takes
+    // effect once the current minimum delay has passed.
+    // The only way this can be invoked is via a recursive call from
+    // execute_transaction -> set_minimum_delay.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_minimum_delay(ctx: Context<Auth>, minimum_delay: i64) -> Result<()> {
+        instructions::set_minimum_delay::handler(ctx, minimum_delay)
+    }
+
+    // Sets the number of seconds a transaction stays executable after its ETA.
+    // A change only takes effect once the current minimum delay has passed.
+    // The only way this can be invoked is via a recursive call from
+    // execute_transaction -> set_grace_period.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_grace_period(ctx: Context<Auth>, grace_period: i64) -> Result<()> {
+        instructions::set_grace_period::handler(ctx, grace_period)
+    }
+
+    // Sets the number of seconds after which a lock of the [Smallet] expires.
+    // The only way this can be invoked is via a recursive call from
+    // execute_transaction -> set_lock_duration.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_lock_duration(ctx: Context<Auth>, lock_duration: i64) -> Result<()> {
+        instructions::set_lock_duration::handler(ctx, lock_duration)
+    }
+
     // Set locked by admin or guardians for the [Smallet]
     #[access_control(ctx.accounts.validate())]
     pub fn lock_smallet(ctx: Context<LockSmallet>) -> Result<()> {
@@ -324,4 +578,104 @@ pub enum ErrorCode {
     InvalidGuardianAction,
     #[msg("Guardian action is already performed.")]
     ActionAlreadyPerformed,
+    #[msg("Too many value tiers.")]
+    TooManyValueTiers,
+    #[msg("Value tiers must require a signer and increase in amount per mint.")]
+    InvalidValueTiers,
+    #[msg("Token account of a transfer was not provided.")]
+    MissingTransferAccount,
+    #[msg("The given owner does not have the role required for this action.")]
+    MissingRole,
+    #[msg("Owner roles must be given for every owner and leave enough approvers.")]
+    InvalidOwnerRoles,
+    #[msg("The given address is not allowed to freeze the smallet.")]
+    InvalidFreezer,
+    #[msg("The Smallet account is locked.")]
+    AccountLocked,
+    #[msg("Owners have not been inactive for the inactivity period.")]
+    OwnersStillActive,
+    #[msg("Guardian policy periods must be positive and permyriad at most 10000.")]
+    InvalidGuardianPolicy,
+    #[msg("Guardian quorums must be unique per action type and within bounds.")]
+    InvalidGuardianQuorums,
+    #[msg("The Smallet account does not have enough space for the change.")]
+    SmalletCapacityExceeded,
+    #[msg("Owners and guardians must be unique.")]
+    DuplicateAddresses,
+    #[msg("The default pubkey cannot be an owner or guardian.")]
+    DefaultAddress,
+    #[msg("Threshold must be greater than zero.")]
+    ZeroThreshold,
+    #[msg("The Smallet still has pending transactions.")]
+    PendingTransactions,
+    #[msg("A derived subaccount of the Smallet is not empty.")]
+    SubaccountNotEmpty,
+    #[msg("The Smallet has not been closed.")]
+    SmalletNotClosed,
+    #[msg("The account does not belong to the given Smallet.")]
+    NotSmalletAccount,
+    #[msg("The account layout version is not supported.")]
+    UnsupportedAccountVersion,
+    #[msg("The GlobalState does not govern the domain of the Smallet.")]
+    InvalidDomain,
+    #[msg("Guardians change period is out of bounds.")]
+    InvalidChangePeriod,
+    #[msg("Guardian action expiry is out of bounds.")]
+    InvalidActionExpiresTime,
+    #[msg("Agree permyriad cannot exceed 10000.")]
+    InvalidAgreePermyriad,
+    #[msg("Guardian actions cannot expire before the guardians change period.")]
+    ActionExpiresBeforeChangePeriod,
+    #[msg("No global thresholds are scheduled.")]
+    NoPendingThresholds,
+    #[msg("The notice period of the global thresholds has not passed.")]
+    ThresholdsNoticePeriodNotPassed,
+    #[msg("A lookup table of the transaction was not provided.")]
+    MissingLookupTable,
+    #[msg("Invalid address lookup table.")]
+    InvalidLookupTable,
+    #[msg("Invalid lookup table account reference.")]
+    InvalidLookupAccount,
+    #[msg("Transaction steps must be executed in order.")]
+    InvalidExecutionStep,
+    #[msg("The transaction does not commit to a hash of its instructions.")]
+    NotHashCommitted,
+    #[msg("The instructions do not match the committed hash.")]
+    InstructionsHashMismatch,
+    #[msg("The instructions of a hash-committed transaction must be supplied.")]
+    MissingInstructions,
+    #[msg("Too many prerequisite transactions.")]
+    TooManyPrerequisites,
+    #[msg("Prerequisite transactions must have been created before.")]
+    InvalidPrerequisite,
+    #[msg("A prerequisite transaction was not provided.")]
+    MissingPrerequisite,
+    #[msg("A prerequisite transaction has not been executed.")]
+    PrerequisiteNotExecuted,
+    #[msg("The previous transaction has not been executed or cancelled.")]
+    PreviousTransactionPending,
+    #[msg("The transaction has been cancelled.")]
+    TransactionCancelled,
+    #[msg("Too many assertions.")]
+    TooManyAssertions,
+    #[msg("An account of an assertion was not provided.")]
+    MissingAssertionAccount,
+    #[msg("An assertion of the transaction does not hold.")]
+    AssertionFailed,
+    #[msg("Transactions with assertions must be executed at once.")]
+    AssertionsRequireFullExecution,
+    #[msg("Simulation complete, every instruction succeeded.")]
+    SimulationComplete,
+    #[msg("Transaction has expired.")]
+    TransactionExpired,
+    #[msg("The quorum delay of the action exceeds the action expiry.")]
+    QuorumDelayExceedsExpiry,
+    #[msg("The index and bump of a derived wallet must be given together.")]
+    InvalidDerivedWallet,
+    #[msg("The transaction has not expired.")]
+    TransactionNotExpired,
+    #[msg("The lookup tables no longer resolve to the accounts of the proposal.")]
+    LookupAddressesChanged,
+    #[msg("The expiry must be after the transaction can be executed.")]
+    InvalidExpiry,
 }
@@ -5,10 +5,6 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
 use vipers::prelude::*;
 
-// Constants
-const NO_ETA: i64 = -1;
-const MAX_DELAY_SECONDS: i64 = 3600 * 36; // 36 hours
-
 // Global state for the program.
 #[account]
 #[derive(Default, Debug)]
@@ -17,14 +13,139 @@ pub struct GlobalState {
     pub guardians_change_period: i64,
     pub guardians_action_expires_time: i64,
     pub min_agree_permyriad: u16,
+    // Layout version of the account.
+    pub version: u8,
+    // Domain governed by this global state.
+    pub domain: u64,
+    // Thresholds scheduled to replace the current ones.
+    pub pending_thresholds: GuardianPolicy,
+    // Time from which the pending thresholds can be activated, or 0 if none.
+    pub thresholds_activate_at: i64,
+    pub reserved: [u8; 30],
 }
 
 impl GlobalState {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 2;
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 2 + 1 + 64;
 
     pub fn is_global_admin(&self, address: &Pubkey) -> bool {
         self.global_admin.eq(address)
     }
+
+    // Seed of the global state of `domain`.
+    // The default domain has an empty seed, keeping the address of the
+    // original singleton global state.
+    pub fn domain_seed(domain: u64) -> Vec<u8> {
+        if domain == crate::DEFAULT_DOMAIN {
+            vec![]
+        } else {
+            domain.to_le_bytes().to_vec()
+        }
+    }
+
+    // Thresholds currently in effect.
+    pub fn thresholds(&self) -> GuardianPolicy {
+        GuardianPolicy {
+            change_period: self.guardians_change_period,
+            action_expires_time: self.guardians_action_expires_time,
+            min_agree_permyriad: self.min_agree_permyriad,
+        }
+    }
+
+    // Ensures this global state governs the domain of `smallet`.
+    pub fn check_domain(&self, smallet: &Smallet) -> Result<()> {
+        invariant!(self.domain == smallet.domain, InvalidDomain);
+        Ok(())
+    }
+
+    // Guardian policy applying to `smallet`.
+    // Per-smallet values are used when present but never go below the global ones.
+    pub fn guardian_policy(&self, smallet: &Smallet) -> GuardianPolicy {
+        let floors = self.thresholds();
+        match smallet.guardian_policy {
+            Some(policy) => GuardianPolicy {
+                change_period: policy.change_period.max(floors.change_period),
+                action_expires_time: policy.action_expires_time.max(floors.action_expires_time),
+                min_agree_permyriad: policy.min_agree_permyriad.max(floors.min_agree_permyriad),
+            },
+            None => floors,
+        }
+    }
+
+    // Quorum required to perform `action_type` on `smallet`.
+    // Defaults to the guardian policy quorum without delay, which is also the floor
+    // of per-action quorums.
+    pub fn guardian_quorum(
+        &self,
+        smallet: &Smallet,
+        action_type: GuardianActionType,
+    ) -> GuardianQuorum {
+        let action_kind = action_type.kind();
+        let policy = self.guardian_policy(smallet);
+        match smallet
+            .guardian_quorums
+            .iter()
+            .find(|quorum| quorum.action_kind == action_kind)
+        {
+            Some(quorum) => GuardianQuorum {
+                action_kind,
+                min_agree_permyriad: quorum.min_agree_permyriad.max(policy.min_agree_permyriad),
+                delay: quorum.delay,
+            },
+            None => GuardianQuorum {
+                action_kind,
+                min_agree_permyriad: policy.min_agree_permyriad,
+                delay: 0,
+            },
+        }
+    }
+}
+
+// Guardian recovery parameters of a [Smallet].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct GuardianPolicy {
+    // Seconds that must pass before guardians can be replaced.
+    pub change_period: i64,
+    // Seconds after which a guardian action can no longer be performed.
+    pub action_expires_time: i64,
+    // Share of guardians, in permyriad, that must agree on an action.
+    pub min_agree_permyriad: u16,
+}
+
+// Quorum and delay for a single kind of [GuardianActionType].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct GuardianQuorum {
+    // Kind of action the quorum applies to.
+    pub action_kind: GuardianActionKind,
+    // Share of guardians, in permyriad, that must agree on the action.
+    pub min_agree_permyriad: u16,
+    // Seconds after the request before the action can be performed.
+    pub delay: i64,
+}
+
+impl GuardianQuorum {
+    pub const LEN: usize = 1 + 2 + 8;
+}
+
+impl GuardianPolicy {
+    pub const LEN: usize = 8 + 8 + 2;
+
+    pub fn validate(&self) -> Result<()> {
+        invariant!(
+            (0..=crate::MAX_GUARDIANS_PERIOD).contains(&self.change_period),
+            InvalidChangePeriod
+        );
+        invariant!(
+            (0..=crate::MAX_GUARDIANS_PERIOD).contains(&self.action_expires_time),
+            InvalidActionExpiresTime
+        );
+        invariant!(self.min_agree_permyriad <= 10000, InvalidAgreePermyriad);
+        // Guardians could never be replaced otherwise
+        invariant!(
+            self.action_expires_time >= self.change_period,
+            ActionExpiresBeforeChangePeriod
+        );
+        Ok(())
+    }
 }
 
 // Main account representing a Smallet.
@@ -44,14 +165,125 @@ pub struct Smallet {
     pub num_gudian_actions: u64,
     pub frozen: bool,
     pub locked: bool,
-    pub reserved: [u64; 16],
+    pub value_tiers: Vec<ValueTier>,
+    // `owner_roles[index]` is the role bitmask of `owners[index]`.
+    pub owner_roles: Vec<u8>,
+    // Key allowed to freeze the smallet without being an owner.
+    // [Pubkey::default] if there is none.
+    pub emergency_key: Pubkey,
+    // When the smallet was last locked.
+    pub locked_at: i64,
+    // Seconds after which a lock expires on its own. 0 if locks never expire.
+    pub lock_duration: i64,
+    // Last time an owner proposed, approved, executed or sent a heartbeat.
+    pub last_owner_activity: i64,
+    // Seconds of owner inactivity after which guardians may take over
+    // the owner set. 0 if disabled.
+    pub inactivity_period: i64,
+    // Guardian policy of the smallet. Falls back to the [GlobalState] one if None.
+    pub guardian_policy: Option<GuardianPolicy>,
+    // Per-action guardian quorums. Action types without one use the policy quorum.
+    pub guardian_quorums: Vec<GuardianQuorum>,
+    // Number of transactions created for the current owner set and not executed yet.
+    pub pending_transactions: u64,
+    // Layout version of the account.
+    pub version: u8,
+    // Domain of the [GlobalState] governing the smallet.
+    pub domain: u64,
+    // Whether transactions can only execute after the previous one is settled.
+    pub sequential: bool,
+    // Reduced minimum delay taking effect at [Smallet::minimum_delay_change_at].
+    pub pending_minimum_delay: i64,
+    // When the pending minimum delay takes effect. 0 if none.
+    pub minimum_delay_change_at: i64,
+    // New grace period taking effect at [Smallet::grace_period_change_at].
+    pub pending_grace_period: i64,
+    // When the pending grace period takes effect. 0 if none.
+    pub grace_period_change_at: i64,
+    pub reserved: [u64; 10],
 }
 
 impl Smallet {
     pub fn space(max_owners: u8, max_guardians: u8) -> usize {
-        8 + 207 + 4 + std::mem::size_of::<Pubkey>() * (max_owners as usize)
-            + 4 + std::mem::size_of::<i64>() * (max_owners as usize)
-            + 4 + std::mem::size_of::<Pubkey>() * (max_guardians as usize)
+        let max_owners = max_owners as usize;
+        let max_guardians = max_guardians as usize;
+        8 // discriminator
+            + std::mem::size_of::<Pubkey>() // base
+            + std::mem::size_of::<u8>() // bump
+            + std::mem::size_of::<u64>() // threshold
+            + std::mem::size_of::<i64>() // minimum_delay
+            + std::mem::size_of::<i64>() // grace_period
+            + std::mem::size_of::<u32>() // owner_set_seqno
+            + std::mem::size_of::<u64>() // num_transactions
+            + 4 + std::mem::size_of::<Pubkey>() * max_owners // owners
+            + 4 + std::mem::size_of::<i64>() * max_owners // owner_sessions
+            + 4 + std::mem::size_of::<Pubkey>() * max_guardians // guardians
+            + std::mem::size_of::<u64>() // num_gudian_actions
+            + std::mem::size_of::<bool>() // frozen
+            + std::mem::size_of::<bool>() // locked
+            + 4 + ValueTier::LEN * crate::MAX_VALUE_TIERS // value_tiers
+            + 4 + std::mem::size_of::<u8>() * max_owners // owner_roles
+            + std::mem::size_of::<Pubkey>() // emergency_key
+            + std::mem::size_of::<i64>() // locked_at
+            + std::mem::size_of::<i64>() // lock_duration
+            + std::mem::size_of::<i64>() // last_owner_activity
+            + std::mem::size_of::<i64>() // inactivity_period
+            + 1 + GuardianPolicy::LEN // guardian_policy
+            + 4 + GuardianQuorum::LEN * crate::MAX_GUARDIAN_QUORUMS // guardian_quorums
+            + std::mem::size_of::<u64>() // pending_transactions
+            + std::mem::size_of::<u8>() // version
+            + std::mem::size_of::<u64>() // domain
+            + std::mem::size_of::<bool>() // sequential
+            + std::mem::size_of::<i64>() // pending_minimum_delay
+            + std::mem::size_of::<i64>() // minimum_delay_change_at
+            + std::mem::size_of::<i64>() // pending_grace_period
+            + std::mem::size_of::<i64>() // grace_period_change_at
+            + std::mem::size_of::<[u64; 10]>() // reserved
+    }
+
+    // Minimum delay in effect at `now`, including a pending reduction.
+    pub fn effective_minimum_delay(&self, now: i64) -> i64 {
+        if self.minimum_delay_change_at != 0 && now >= self.minimum_delay_change_at {
+            self.pending_minimum_delay
+        } else {
+            self.minimum_delay
+        }
+    }
+
+    // Grace period in effect at `now`, including a pending change.
+    pub fn effective_grace_period(&self, now: i64) -> i64 {
+        if self.grace_period_change_at != 0 && now >= self.grace_period_change_at {
+            self.pending_grace_period
+        } else {
+            self.grace_period
+        }
+    }
+
+    // Stores the delays in effect at `now`, clearing changes that took effect.
+    pub fn apply_pending_delays(&mut self, now: i64) {
+        self.minimum_delay = self.effective_minimum_delay(now);
+        if self.minimum_delay_change_at != 0 && now >= self.minimum_delay_change_at {
+            self.minimum_delay_change_at = 0;
+            self.pending_minimum_delay = 0;
+        }
+        self.grace_period = self.effective_grace_period(now);
+        if self.grace_period_change_at != 0 && now >= self.grace_period_change_at {
+            self.grace_period_change_at = 0;
+            self.pending_grace_period = 0;
+        }
+    }
+
+    // Invalidates every transaction created for the current owner set.
+    pub fn bump_owner_set_seqno(&mut self) -> Result<()> {
+        self.owner_set_seqno = unwrap_int!(self.owner_set_seqno.checked_add(1));
+        self.pending_transactions = 0;
+        Ok(())
+    }
+
+    // Ensures the serialized smallet fits in `data_len` bytes of account data.
+    pub fn check_capacity(&self, data_len: usize) -> Result<()> {
+        invariant!(8 + self.try_to_vec()?.len() <= data_len, SmalletCapacityExceeded);
+        Ok(())
     }
 
     pub fn owner_index_opt(&self, key: Pubkey) -> Option<usize> {
@@ -62,6 +294,66 @@ impl Smallet {
         Ok(unwrap_opt!(self.owner_index_opt(key), InvalidOwner))
     }
 
+    // Role bitmask of the owner at `index`.
+    // Owners without an explicit entry hold every role.
+    pub fn roles_of(&self, index: usize) -> u8 {
+        self.owner_roles.get(index).copied().unwrap_or(crate::ROLE_ALL)
+    }
+
+    pub fn has_role(&self, index: usize, role: u8) -> bool {
+        self.roles_of(index) & role == role
+    }
+
+    pub fn try_owner_index_with_role(&self, key: Pubkey, role: u8) -> Result<usize> {
+        let index = self.try_owner_index(key)?;
+        invariant!(self.has_role(index, role), MissingRole);
+        Ok(index)
+    }
+
+    // Roles of the current owners, defaulting missing entries.
+    pub fn current_roles(&self) -> Vec<u8> {
+        self.roles_for_owners(&self.owners)
+    }
+
+    // Roles for a new owner set. Owners that stay keep their roles,
+    // new owners get every role.
+    pub fn roles_for_owners(&self, owners: &[Pubkey]) -> Vec<u8> {
+        owners
+            .iter()
+            .map(|owner| {
+                self.owner_index_opt(*owner)
+                    .map_or(crate::ROLE_ALL, |index| self.roles_of(index))
+            })
+            .collect()
+    }
+
+    // Whether the smallet is locked at `now`, taking lock expiry into account.
+    pub fn is_locked(&self, now: i64) -> bool {
+        if !self.locked {
+            return false;
+        }
+        self.lock_duration == 0 || now < self.locked_at.saturating_add(self.lock_duration)
+    }
+
+    pub fn record_owner_activity(&mut self, now: i64) {
+        self.last_owner_activity = now;
+    }
+
+    // Whether the owners have been inactive for longer than the inactivity period.
+    pub fn is_owner_inactive(&self, now: i64) -> bool {
+        self.inactivity_period != 0
+            && now >= self.last_owner_activity.saturating_add(self.inactivity_period)
+    }
+
+    // Whether `key` may freeze the smallet on its own.
+    pub fn is_freezer(&self, key: &Pubkey) -> bool {
+        if *key != Pubkey::default() && self.emergency_key == *key {
+            return true;
+        }
+        self.owner_index_opt(*key)
+            .map_or(false, |index| self.has_role(index, crate::ROLE_FREEZER))
+    }
+
     pub fn is_guardian(&self, guardian: &Pubkey) -> bool {
         self.guardians.contains(guardian)
     }
@@ -69,6 +361,36 @@ impl Smallet {
     pub fn guardian_index_opt(&self, key: Pubkey) -> Option<usize> {
         self.guardians.iter().position(|a| *a == key)
     }
+
+    // Number of approvals required to move `amount` of `mint` out of the smallet.
+    // Returns None if no tier is configured for the mint.
+    pub fn tier_threshold(&self, mint: &Pubkey, amount: u64) -> Option<u64> {
+        let mut tiers = self.value_tiers.iter().filter(|t| t.mint == *mint).peekable();
+        tiers.peek()?;
+
+        let everyone = crate::count_approvers(&self.current_roles());
+        Some(
+            tiers
+                .find(|t| amount < t.max_amount)
+                .map_or(everyone, |t| t.threshold.min(everyone)),
+        )
+    }
+}
+
+// Approval tier for outflows of a single mint.
+// Lamports are tracked under the native mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ValueTier {
+    // Mint the tier applies to.
+    pub mint: Pubkey,
+    // Outflows strictly below this amount fall into the tier.
+    pub max_amount: u64,
+    // Number of approvals required for outflows in the tier.
+    pub threshold: u64,
+}
+
+impl ValueTier {
+    pub const LEN: usize = 32 + 8 + 8;
 }
 
 #[derive(Clone, Copy, Default, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
@@ -78,6 +400,42 @@ pub enum GuardianActionType {
     UnlockSmallet,
     SetOwners,
     SetGuardians,
+    // Replace the owners after the owners have been inactive for the inactivity period.
+    InheritOwners,
+    // Change the threshold of the smallet.
+    SetThreshold { threshold: u64 },
+    // Replace a single owner, keeping its roles.
+    ReplaceOwner { old: Pubkey, new: Pubkey },
+}
+
+impl GuardianActionType {
+    // Size of the largest action type.
+    pub const LEN: usize = 1 + 32 + 32;
+
+    pub fn kind(&self) -> GuardianActionKind {
+        match self {
+            GuardianActionType::NoAction => GuardianActionKind::NoAction,
+            GuardianActionType::UnlockSmallet => GuardianActionKind::UnlockSmallet,
+            GuardianActionType::SetOwners => GuardianActionKind::SetOwners,
+            GuardianActionType::SetGuardians => GuardianActionKind::SetGuardians,
+            GuardianActionType::InheritOwners => GuardianActionKind::InheritOwners,
+            GuardianActionType::SetThreshold { .. } => GuardianActionKind::SetThreshold,
+            GuardianActionType::ReplaceOwner { .. } => GuardianActionKind::ReplaceOwner,
+        }
+    }
+}
+
+// Kind of a [GuardianActionType], without its parameters.
+#[derive(Clone, Copy, Default, Debug, PartialEq, AnchorDeserialize, AnchorSerialize)]
+pub enum GuardianActionKind {
+    #[default]
+    NoAction,
+    UnlockSmallet,
+    SetOwners,
+    SetGuardians,
+    InheritOwners,
+    SetThreshold,
+    ReplaceOwner,
 }
 
 #[account]
@@ -95,17 +453,21 @@ pub struct GuardianAction {
     pub agreed_signs: Vec<bool>,
     // New addresses of owners or guardians
     pub addresses: Vec<Pubkey>,
+    // Layout version of the account.
+    pub version: u8,
 }
 
 impl GuardianAction {
     // Computes the space a [Smallet] uses.
     pub fn space(gudians_count: u8, addresses_count: u8) -> usize {
         8 // Anchor discriminator
-            + 42
+            + 41
+            + GuardianActionType::LEN
             + 4 // 4 = the Vec discriminator
             + std::mem::size_of::<Pubkey>() * (addresses_count as usize)
             + 4 // 4 = the Vec discriminator
             + std::mem::size_of::<bool>() * (gudians_count as usize)
+            + 1 // version
     }
 
     // Clear all guardians sign
@@ -130,7 +492,13 @@ impl GuardianAction {
 
         let signed_count = self.agreed_signs.iter().filter(|&signed| *signed).count();
 
-        return min_required_signs <= signed_count;
+        min_required_signs <= signed_count
+    }
+
+    // Whether enough guardians signed and the quorum delay has passed.
+    pub fn is_ready(&self, quorum: &GuardianQuorum, now: i64) -> bool {
+        self.check_enough_sign(quorum.min_agree_permyriad)
+            && now >= self.action_requested_time.saturating_add(quorum.delay)
     }
 }
 
@@ -157,13 +525,46 @@ pub struct Transaction {
     // Owner set sequence number.
     pub owner_set_seqno: u32,
     // Estimated time the [Transaction] will be executed.
-    // - If set to [crate::NO_ETA], the transaction may be executed at any time.
+    // - If set to [crate::NO_ETA], the transaction may be executed at any time. Only
+    //   smallets without a minimum delay accept it.
     // - Otherwise, the [Transaction] may be executed at any point after the ETA has elapsed.
     pub eta: i64,
     // The account that executed the [Transaction].
     pub executor: Pubkey,
     // When the transaction was executed. -1 if not executed.
     pub executed_at: i64,
+    // Layout version of the account.
+    pub version: u8,
+    // Number of instructions already executed step by step.
+    pub executed_steps: u64,
+    // Hash of the instructions of a hash-committed transaction, which does not
+    // store its instructions. All zeros otherwise.
+    pub instructions_hash: [u8; 32],
+    // When the transaction was cancelled. 0 if not cancelled.
+    pub cancelled_at: i64,
+    // Time after which the transaction can no longer be approved or executed.
+    // Legacy transactions get one when they are migrated.
+    pub expires_at: i64,
+    // Approvals required to execute the transaction, including value tiers.
+    // Computed when its execution starts, 0 until then.
+    pub required_threshold: u64,
+    // When the expiry of the transaction was recorded. 0 if not recorded.
+    pub expired_at: i64,
+    // Address lookup tables the instructions reference accounts from.
+    pub lookup_tables: Vec<Pubkey>,
+    // Accounts of the instructions resolved from [Transaction::lookup_tables]
+    // at execution, inserted in the keys of their instruction in order.
+    pub lookup_accounts: Vec<TXLookupAccountMeta>,
+    // Indices of the transactions that must be executed before this one.
+    pub prerequisites: Vec<u64>,
+    // Assertions that must hold before the instructions are executed.
+    pub pre_assertions: Vec<TXAssertion>,
+    // Assertions that must hold after the instructions are executed.
+    pub post_assertions: Vec<TXAssertion>,
+    // Hash of the accounts resolved from [Transaction::lookup_tables] when the
+    // transaction was created. All zeros without lookup accounts or for
+    // migrated transactions.
+    pub lookup_addresses_hash: [u8; 32],
 }
 
 impl Transaction {
@@ -174,12 +575,179 @@ impl Transaction {
             + 4 // Vec discriminator
             + (instructions.iter().map(|ix| ix.space()).sum::<usize>())
     }
+
+    // Computes the space a [Transaction] created with `options` uses.
+    pub fn space_with_options(
+        instructions: Vec<TXInstruction>,
+        options: &TransactionOptions,
+    ) -> usize {
+        // Hash-committed transactions do not store their instructions
+        let stored = if options.hash_committed {
+            vec![]
+        } else {
+            instructions
+        };
+        Transaction::space(stored)
+            + std::mem::size_of_val(&options.prerequisites[..])
+            + std::mem::size_of_val(&options.lookup_tables[..])
+            + TXLookupAccountMeta::LEN * options.lookup_accounts.len()
+            + options
+                .pre_assertions
+                .iter()
+                .chain(options.post_assertions.iter())
+                .map(|assertion| assertion.space())
+                .sum::<usize>()
+    }
+
+    // Whether the transaction has expired at `now`.
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now > self.expires_at
+    }
+
+    // Whether the transaction has assertions to check around its execution.
+    pub fn has_assertions(&self) -> bool {
+        !self.pre_assertions.is_empty() || !self.post_assertions.is_empty()
+    }
+
+    // Whether the transaction can no longer be executed at `now`, either
+    // because it was executed, cancelled, expired or invalidated by a change
+    // of the owner set.
+    pub fn is_settled(&self, smallet: &Smallet, now: i64) -> bool {
+        self.executed_at != -1
+            || self.cancelled_at != 0
+            || self.expired_at != 0
+            || self.is_expired(now)
+            || self.owner_set_seqno != smallet.owner_set_seqno
+    }
+
+    // Hash committing to `instructions`.
+    pub fn hash_instructions(instructions: &[TXInstruction]) -> Result<[u8; 32]> {
+        Ok(solana_program::hash::hash(&instructions.try_to_vec()?).to_bytes())
+    }
+
+    // Whether the transaction only stores the hash of its instructions.
+    pub fn is_hash_committed(&self) -> bool {
+        self.instructions_hash != [0; 32]
+    }
+
+    // Instructions to execute. Hash-committed transactions must be given
+    // instructions matching their hash, other transactions use their own.
+    pub fn instructions_to_execute(
+        &self,
+        supplied: Option<Vec<TXInstruction>>,
+    ) -> Result<Vec<TXInstruction>> {
+        match supplied {
+            Some(instructions) => {
+                invariant!(self.is_hash_committed(), NotHashCommitted);
+                invariant!(
+                    Transaction::hash_instructions(&instructions)? == self.instructions_hash,
+                    InstructionsHashMismatch
+                );
+                Ok(instructions)
+            }
+            None => {
+                invariant!(!self.is_hash_committed(), MissingInstructions);
+                Ok(self.instructions.clone())
+            }
+        }
+    }
+
+    // Accounts referenced by `lookup_accounts`, read from the `lookup_tables`
+    // found in `accounts`.
+    pub fn lookup_addresses(
+        lookup_tables: &[Pubkey],
+        lookup_accounts: &[TXLookupAccountMeta],
+        accounts: &[AccountInfo],
+    ) -> Result<Vec<Pubkey>> {
+        if lookup_accounts.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut tables = Vec::with_capacity(lookup_tables.len());
+        for table in lookup_tables.iter() {
+            let info = unwrap_opt!(
+                accounts.iter().find(|info| info.key == table),
+                MissingLookupTable
+            );
+            assert_keys_eq!(
+                *info.owner,
+                crate::ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+                InvalidLookupTable
+            );
+            tables.push(info.try_borrow_data()?);
+        }
+
+        let mut addresses = Vec::with_capacity(lookup_accounts.len());
+        for meta in lookup_accounts.iter() {
+            let data = unwrap_opt!(tables.get(meta.table_index as usize), InvalidLookupAccount);
+            let start = crate::LOOKUP_TABLE_META_SIZE
+                + std::mem::size_of::<Pubkey>() * meta.address_index as usize;
+            let address = unwrap_opt!(
+                data.get(start..start + std::mem::size_of::<Pubkey>()),
+                InvalidLookupAccount
+            );
+            addresses.push(Pubkey::new(address));
+        }
+        Ok(addresses)
+    }
+
+    // Hash of the accounts resolved from the lookup tables.
+    pub fn hash_lookup_addresses(addresses: &[Pubkey]) -> [u8; 32] {
+        let bytes = addresses.iter().map(Pubkey::as_ref).collect::<Vec<_>>();
+        solana_program::hash::hashv(&bytes).to_bytes()
+    }
+
+    // `instructions` with the accounts referenced through lookup tables
+    // resolved against the lookup table `accounts`.
+    pub fn resolve_lookups(
+        &self,
+        mut instructions: Vec<TXInstruction>,
+        accounts: &[AccountInfo],
+    ) -> Result<Vec<TXInstruction>> {
+        if self.lookup_accounts.is_empty() {
+            return Ok(instructions);
+        }
+
+        let addresses =
+            Self::lookup_addresses(&self.lookup_tables, &self.lookup_accounts, accounts)?;
+        // The tables may have been extended or closed and recreated since
+        invariant!(
+            self.lookup_addresses_hash == [0; 32]
+                || self.lookup_addresses_hash == Self::hash_lookup_addresses(&addresses),
+            LookupAddressesChanged
+        );
+
+        for (meta, address) in self.lookup_accounts.iter().zip(addresses) {
+            let ix = unwrap_opt!(
+                instructions.get_mut(meta.instruction_index as usize),
+                InvalidLookupAccount
+            );
+            invariant!(
+                meta.position as usize <= ix.keys.len(),
+                InvalidLookupAccount
+            );
+            // Accounts from lookup tables can never sign
+            ix.keys.insert(
+                meta.position as usize,
+                TXAccountMeta {
+                    pubkey: address,
+                    is_signer: false,
+                    is_writable: meta.is_writable,
+                },
+            );
+        }
+        Ok(instructions)
+    }
     // Number of signers.
-    pub fn num_signers(&self, auto_signed_owners: &[i64], now: i64) -> usize {
+    // Only owners currently holding [crate::ROLE_APPROVER] are counted.
+    pub fn num_signers(&self, smallet: &Smallet, now: i64) -> usize {
         self.signers
             .iter()
-            .zip(auto_signed_owners.iter())
-            .filter(|(&signed, &auto_signed)| signed || auto_signed >= now)
+            .zip(smallet.owner_sessions.iter())
+            .enumerate()
+            .filter(|(index, (&signed, &auto_signed))| {
+                (signed || auto_signed >= now) && smallet.has_role(*index, crate::ROLE_APPROVER)
+            })
             .count()
     }
 }
@@ -199,11 +767,99 @@ impl TXInstruction {
     // Space that a [TXInstruction] takes up.
     pub fn space(&self) -> usize {
         std::mem::size_of::<Pubkey>()
-            + (self.keys.len() as usize) * std::mem::size_of::<TXAccountMeta>()
-            + (self.data.len() as usize)
+            + self.keys.len() * std::mem::size_of::<TXAccountMeta>()
+            + self.data.len()
     }
 }
 
+// Data returned by a program invoked by a [TXInstruction]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct TXReturnData {
+    // Program that set the return data
+    pub program_id: Pubkey,
+    // The returned data
+    pub data: Vec<u8>,
+}
+
+// Condition on the state of an account checked around the execution of a [Transaction]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum TXAssertion {
+    // The token account holds at least `amount` tokens.
+    MinTokenBalance { account: Pubkey, amount: u64 },
+    // The account holds at least `lamports`.
+    MinLamports { account: Pubkey, lamports: u64 },
+    // The lamports of the account dropped by at most `lamports` since the
+    // execution started.
+    MaxLamportsDecrease { account: Pubkey, lamports: u64 },
+    // The account data at `offset` equals `bytes`.
+    DataEquals {
+        account: Pubkey,
+        offset: u32,
+        bytes: Vec<u8>,
+    },
+}
+
+impl TXAssertion {
+    // Account the assertion applies to.
+    pub fn account(&self) -> Pubkey {
+        match self {
+            TXAssertion::MinTokenBalance { account, .. }
+            | TXAssertion::MinLamports { account, .. }
+            | TXAssertion::MaxLamportsDecrease { account, .. }
+            | TXAssertion::DataEquals { account, .. } => *account,
+        }
+    }
+
+    // Space that a [TXAssertion] takes up.
+    pub fn space(&self) -> usize {
+        1 + std::mem::size_of::<Pubkey>()
+            + match self {
+                TXAssertion::DataEquals { bytes, .. } => 4 + 4 + bytes.len(),
+                _ => 8,
+            }
+    }
+}
+
+// Optional parts of a proposed [Transaction].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct TransactionOptions {
+    // Indices of the transactions that must be executed before this one.
+    pub prerequisites: Vec<u64>,
+    // Address lookup tables the instructions reference accounts from.
+    pub lookup_tables: Vec<Pubkey>,
+    // Accounts of the instructions resolved from the lookup tables.
+    pub lookup_accounts: Vec<TXLookupAccountMeta>,
+    // Assertions that must hold before the instructions are executed.
+    pub pre_assertions: Vec<TXAssertion>,
+    // Assertions that must hold after the instructions are executed.
+    pub post_assertions: Vec<TXAssertion>,
+    // Whether to store only the hash of the instructions, which are published
+    // in [crate::TransactionCreateEvent] and supplied again on execution.
+    pub hash_committed: bool,
+    // Time after which the transaction can no longer be approved or executed.
+    // Defaults to a grace period after it can first be executed.
+    pub expires_at: Option<i64>,
+}
+
+// Account of a [TXInstruction] referenced through an address lookup table
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Copy, Clone)]
+pub struct TXLookupAccountMeta {
+    // Index of the instruction in [Transaction::instructions]
+    pub instruction_index: u8,
+    // Index of the table in [Transaction::lookup_tables]
+    pub table_index: u8,
+    // Index of the account in the lookup table
+    pub address_index: u8,
+    // True if the account can be loaded as a read-write account.
+    pub is_writable: bool,
+    // Index of the account in the keys of its instruction once resolved
+    pub position: u8,
+}
+
+impl TXLookupAccountMeta {
+    pub const LEN: usize = 1 + 1 + 1 + 1 + 1;
+}
+
 // Account metadata used to define [TXInstruction]s
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Copy, Clone)]
 pub struct TXAccountMeta {
@@ -242,22 +898,26 @@ impl From<TXAccountMeta> for solana_program::instruction::AccountMeta {
 }
 // Type of Subaccount.
 #[derive(
-    AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord,
+    AnchorSerialize,
+    AnchorDeserialize,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    PartialOrd,
+    Ord,
 )]
 #[repr(u8)]
 pub enum SubaccountType {
     // Requires the normal multisig approval process.
+    #[default]
     Derived = 0,
     // Any owner may sign an instruction  as this address.
     OwnerInvoker = 1,
 }
 
-impl Default for SubaccountType {
-    fn default() -> Self {
-        SubaccountType::Derived
-    }
-}
-
 // Mapping of a Subaccount to its [Smallet].
 #[account]
 #[derive(Copy, Default, Debug, PartialEq, Eq)]
@@ -268,9 +928,11 @@ pub struct SubaccountInfo {
     pub subaccount_type: SubaccountType,
     // Index of the sub-account.
     pub index: u64,
+    // Layout version of the account.
+    pub version: u8,
 }
 
 impl SubaccountInfo {
     // Number of bytes that a [SubaccountInfo] uses.
-    pub const LEN: usize = 32 + 1 + 8;
+    pub const LEN: usize = 32 + 1 + 8 + 1;
 }
@@ -0,0 +1,35 @@
+//! Validation of owner and guardian sets and of global thresholds
+
+use crate::*;
+
+// Ensures `addresses` are unique and none of them is the default pubkey.
+pub fn validate_addresses(addresses: &[Pubkey]) -> Result<()> {
+    for (i, address) in addresses.iter().enumerate() {
+        invariant!(*address != Pubkey::default(), DefaultAddress);
+        invariant!(!addresses[..i].contains(address), DuplicateAddresses);
+    }
+    Ok(())
+}
+
+// Ensures `owners` holding `roles` is a valid owner set for `threshold`.
+// The threshold must be reachable by the owners holding [ROLE_APPROVER].
+pub fn validate_owner_set(owners: &[Pubkey], roles: &[u8], threshold: u64) -> Result<()> {
+    validate_addresses(owners)?;
+    invariant!(threshold > 0, ZeroThreshold);
+    invariant!(threshold <= owners.len() as u64, InvalidThreshold);
+    invariant!(roles.len() == owners.len(), InvalidOwnerRoles);
+    invariant!(
+        roles.iter().all(|role| role & !ROLE_ALL == 0),
+        InvalidOwnerRoles
+    );
+    invariant!(count_approvers(roles) >= threshold, InvalidOwnerRoles);
+    Ok(())
+}
+
+// Number of owners holding [ROLE_APPROVER] in `roles`.
+pub fn count_approvers(roles: &[u8]) -> u64 {
+    roles
+        .iter()
+        .filter(|&role| role & ROLE_APPROVER != 0)
+        .count() as u64
+}
@@ -0,0 +1,471 @@
+//! Legacy account layouts and decoders accepting every supported version
+
+#![allow(missing_docs)]
+
+use crate::*;
+use anchor_lang::Discriminator;
+
+// Layout of a [GlobalState] before versioning.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GlobalStateV0 {
+    pub global_admin: Pubkey,
+    pub guardians_change_period: i64,
+    pub guardians_action_expires_time: i64,
+    pub min_agree_permyriad: u16,
+}
+
+impl From<GlobalStateV0> for GlobalState {
+    fn from(v0: GlobalStateV0) -> Self {
+        GlobalState {
+            global_admin: v0.global_admin,
+            guardians_change_period: v0.guardians_change_period,
+            guardians_action_expires_time: v0.guardians_action_expires_time,
+            min_agree_permyriad: v0.min_agree_permyriad,
+            version: GLOBAL_STATE_VERSION,
+            domain: DEFAULT_DOMAIN,
+            pending_thresholds: GuardianPolicy::default(),
+            thresholds_activate_at: 0,
+            reserved: [0; 30],
+        }
+    }
+}
+
+// Layout of a [Smallet] before versioning.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SmalletV0 {
+    pub base: Pubkey,
+    pub bump: u8,
+    pub threshold: u64,
+    pub minimum_delay: i64,
+    pub grace_period: i64,
+    pub owner_set_seqno: u32,
+    pub num_transactions: u64,
+    pub owners: Vec<Pubkey>,
+    pub owner_sessions: Vec<i64>,
+    pub guardians: Vec<Pubkey>,
+    pub num_gudian_actions: u64,
+    pub frozen: bool,
+    pub locked: bool,
+    pub reserved: [u64; 16],
+}
+
+impl SmalletV0 {
+    // Space allocated for a [Smallet] in this layout.
+    pub fn space(max_owners: u8, max_guardians: u8) -> usize {
+        let max_owners = max_owners as usize;
+        let max_guardians = max_guardians as usize;
+        8 // discriminator
+            + 207 // fixed-size fields
+            + 4 + std::mem::size_of::<Pubkey>() * max_owners // owners
+            + 4 + std::mem::size_of::<i64>() * max_owners // owner_sessions
+            + 4 + std::mem::size_of::<Pubkey>() * max_guardians // guardians
+    }
+}
+
+impl From<SmalletV0> for Smallet {
+    fn from(v0: SmalletV0) -> Self {
+        Smallet {
+            base: v0.base,
+            bump: v0.bump,
+            threshold: v0.threshold,
+            minimum_delay: v0.minimum_delay,
+            grace_period: v0.grace_period,
+            owner_set_seqno: v0.owner_set_seqno,
+            num_transactions: v0.num_transactions,
+            // Every owner could do everything before roles
+            owner_roles: vec![ROLE_ALL; v0.owners.len()],
+            owners: v0.owners,
+            owner_sessions: v0.owner_sessions,
+            guardians: v0.guardians,
+            num_gudian_actions: v0.num_gudian_actions,
+            frozen: v0.frozen,
+            locked: v0.locked,
+            version: SMALLET_VERSION,
+            domain: DEFAULT_DOMAIN,
+            reserved: [0; 10],
+            ..Default::default()
+        }
+    }
+}
+
+// Layout of a [Smallet] at version 1, up to its reserved space.
+// Its version takes the place of the first reserved bytes of [SmalletV0],
+// which are always zero.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SmalletV1 {
+    pub base: Pubkey,
+    pub bump: u8,
+    pub threshold: u64,
+    pub minimum_delay: i64,
+    pub grace_period: i64,
+    pub owner_set_seqno: u32,
+    pub num_transactions: u64,
+    pub owners: Vec<Pubkey>,
+    pub owner_sessions: Vec<i64>,
+    pub guardians: Vec<Pubkey>,
+    pub num_gudian_actions: u64,
+    pub frozen: bool,
+    pub locked: bool,
+    pub value_tiers: Vec<ValueTier>,
+    pub owner_roles: Vec<u8>,
+    pub emergency_key: Pubkey,
+    pub locked_at: i64,
+    pub lock_duration: i64,
+    pub last_owner_activity: i64,
+    pub inactivity_period: i64,
+    pub guardian_policy: Option<GuardianPolicy>,
+    pub guardian_quorums: Vec<GuardianQuorum>,
+    pub pending_transactions: u64,
+    pub version: u8,
+}
+
+impl SmalletV1 {
+    // Space allocated for a [Smallet] in this layout.
+    pub fn space(max_owners: u8, max_guardians: u8) -> usize {
+        let max_owners = max_owners as usize;
+        let max_guardians = max_guardians as usize;
+        8 // discriminator
+            + 191 // fixed-size fields
+            + 4 + std::mem::size_of::<Pubkey>() * max_owners // owners
+            + 4 + std::mem::size_of::<i64>() * max_owners // owner_sessions
+            + 4 + std::mem::size_of::<Pubkey>() * max_guardians // guardians
+            + 4 + ValueTier::LEN * crate::MAX_VALUE_TIERS // value_tiers
+            + 4 + std::mem::size_of::<u8>() * max_owners // owner_roles
+            + 4 + GuardianQuorum::LEN * crate::MAX_GUARDIAN_QUORUMS // guardian_quorums
+    }
+}
+
+impl From<SmalletV1> for Smallet {
+    fn from(v1: SmalletV1) -> Self {
+        Smallet {
+            base: v1.base,
+            bump: v1.bump,
+            threshold: v1.threshold,
+            minimum_delay: v1.minimum_delay,
+            grace_period: v1.grace_period,
+            owner_set_seqno: v1.owner_set_seqno,
+            num_transactions: v1.num_transactions,
+            owners: v1.owners,
+            owner_sessions: v1.owner_sessions,
+            guardians: v1.guardians,
+            num_gudian_actions: v1.num_gudian_actions,
+            frozen: v1.frozen,
+            locked: v1.locked,
+            value_tiers: v1.value_tiers,
+            owner_roles: v1.owner_roles,
+            emergency_key: v1.emergency_key,
+            locked_at: v1.locked_at,
+            lock_duration: v1.lock_duration,
+            last_owner_activity: v1.last_owner_activity,
+            inactivity_period: v1.inactivity_period,
+            guardian_policy: v1.guardian_policy,
+            guardian_quorums: v1.guardian_quorums,
+            pending_transactions: v1.pending_transactions,
+            version: SMALLET_VERSION,
+            domain: DEFAULT_DOMAIN,
+            sequential: false,
+            pending_minimum_delay: 0,
+            minimum_delay_change_at: 0,
+            pending_grace_period: 0,
+            grace_period_change_at: 0,
+            reserved: [0; 10],
+        }
+    }
+}
+
+// Layout of a [Transaction] before versioning.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransactionV0 {
+    pub smallet: Pubkey,
+    pub index: u64,
+    pub bump: u8,
+    pub proposer: Pubkey,
+    pub instructions: Vec<TXInstruction>,
+    pub signers: Vec<bool>,
+    pub owner_set_seqno: u32,
+    pub eta: i64,
+    pub executor: Pubkey,
+    pub executed_at: i64,
+}
+
+impl From<TransactionV0> for Transaction {
+    fn from(v0: TransactionV0) -> Self {
+        Transaction {
+            smallet: v0.smallet,
+            index: v0.index,
+            bump: v0.bump,
+            proposer: v0.proposer,
+            instructions: v0.instructions,
+            signers: v0.signers,
+            owner_set_seqno: v0.owner_set_seqno,
+            eta: v0.eta,
+            executor: v0.executor,
+            executed_at: v0.executed_at,
+            version: TRANSACTION_VERSION,
+            executed_steps: 0,
+            instructions_hash: [0; 32],
+            cancelled_at: 0,
+            // Set by [smallet::migrate_transaction] from the grace period of the smallet
+            expires_at: 0,
+            required_threshold: 0,
+            expired_at: 0,
+            lookup_tables: vec![],
+            lookup_accounts: vec![],
+            prerequisites: vec![],
+            pre_assertions: vec![],
+            post_assertions: vec![],
+            lookup_addresses_hash: [0; 32],
+        }
+    }
+}
+
+// Layout of a [Transaction] before address lookup tables.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransactionV1 {
+    pub v0: TransactionV0,
+    pub version: u8,
+    pub reserved: [u64; 8],
+}
+
+impl From<TransactionV1> for Transaction {
+    fn from(v1: TransactionV1) -> Self {
+        v1.v0.into()
+    }
+}
+
+// Layout of a [TXLookupAccountMeta] before positions.
+// Resolved accounts were appended to the keys of their instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct TXLookupAccountMetaV0 {
+    pub instruction_index: u8,
+    pub table_index: u8,
+    pub address_index: u8,
+    pub is_writable: bool,
+}
+
+// Layout of a [Transaction] with address lookup tables, before step
+// execution, hash commitments, prerequisites, assertions and expiry.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransactionV2 {
+    pub v1: TransactionV1,
+    pub lookup_tables: Vec<Pubkey>,
+    pub lookup_accounts: Vec<TXLookupAccountMetaV0>,
+}
+
+impl From<TransactionV2> for Transaction {
+    fn from(v2: TransactionV2) -> Self {
+        let transaction: Transaction = v2.v1.into();
+        // Appending is inserting after the keys and the accounts resolved before
+        let mut keys: Vec<usize> = transaction
+            .instructions
+            .iter()
+            .map(|ix| ix.keys.len())
+            .collect();
+        let lookup_accounts = v2
+            .lookup_accounts
+            .iter()
+            .map(|meta| {
+                let position = match keys.get_mut(meta.instruction_index as usize) {
+                    Some(len) => {
+                        *len += 1;
+                        *len - 1
+                    }
+                    None => 0,
+                };
+                TXLookupAccountMeta {
+                    instruction_index: meta.instruction_index,
+                    table_index: meta.table_index,
+                    address_index: meta.address_index,
+                    is_writable: meta.is_writable,
+                    position: position as u8,
+                }
+            })
+            .collect();
+        Transaction {
+            lookup_tables: v2.lookup_tables,
+            lookup_accounts,
+            ..transaction
+        }
+    }
+}
+
+// Layout of a [Transaction] before the accounts resolved from its lookup
+// tables were recorded.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransactionV3 {
+    pub v0: TransactionV0,
+    pub version: u8,
+    pub executed_steps: u64,
+    pub instructions_hash: [u8; 32],
+    pub cancelled_at: i64,
+    pub expires_at: i64,
+    pub required_threshold: u64,
+    pub expired_at: i64,
+    pub lookup_tables: Vec<Pubkey>,
+    pub lookup_accounts: Vec<TXLookupAccountMeta>,
+    pub prerequisites: Vec<u64>,
+    pub pre_assertions: Vec<TXAssertion>,
+    pub post_assertions: Vec<TXAssertion>,
+}
+
+impl From<TransactionV3> for Transaction {
+    fn from(v3: TransactionV3) -> Self {
+        Transaction {
+            executed_steps: v3.executed_steps,
+            instructions_hash: v3.instructions_hash,
+            cancelled_at: v3.cancelled_at,
+            expires_at: v3.expires_at,
+            required_threshold: v3.required_threshold,
+            expired_at: v3.expired_at,
+            lookup_tables: v3.lookup_tables,
+            lookup_accounts: v3.lookup_accounts,
+            prerequisites: v3.prerequisites,
+            pre_assertions: v3.pre_assertions,
+            post_assertions: v3.post_assertions,
+            ..v3.v0.into()
+        }
+    }
+}
+
+// Layout of a [GuardianAction] before versioning.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GuardianActionV0 {
+    pub smallet: Pubkey,
+    pub action_requested_time: i64,
+    pub action_type: GuardianActionType,
+    pub performed: bool,
+    pub agreed_signs: Vec<bool>,
+    pub addresses: Vec<Pubkey>,
+}
+
+impl From<GuardianActionV0> for GuardianAction {
+    fn from(v0: GuardianActionV0) -> Self {
+        GuardianAction {
+            smallet: v0.smallet,
+            action_requested_time: v0.action_requested_time,
+            action_type: v0.action_type,
+            performed: v0.performed,
+            agreed_signs: v0.agreed_signs,
+            addresses: v0.addresses,
+            version: GUARDIAN_ACTION_VERSION,
+        }
+    }
+}
+
+// Layout of a [SubaccountInfo] before versioning.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SubaccountInfoV0 {
+    pub smallet: Pubkey,
+    pub subaccount_type: SubaccountType,
+    pub index: u64,
+}
+
+impl From<SubaccountInfoV0> for SubaccountInfo {
+    fn from(v0: SubaccountInfoV0) -> Self {
+        SubaccountInfo {
+            smallet: v0.smallet,
+            subaccount_type: v0.subaccount_type,
+            index: v0.index,
+            version: SUBACCOUNT_INFO_VERSION,
+        }
+    }
+}
+
+// Returns the account data following the discriminator of `T`.
+fn account_body<T: Discriminator>(data: &[u8]) -> Result<&[u8]> {
+    if data.len() < 8 || data[..8] != T::discriminator() {
+        return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    Ok(&data[8..])
+}
+
+// Decodes a [GlobalState] of any supported version.
+pub fn load_global_state(data: &[u8]) -> Result<GlobalState> {
+    let mut body = account_body::<GlobalState>(data)?;
+    let v0 = GlobalStateV0::deserialize(&mut body)?;
+    // Versioned layouts append the version to the legacy one
+    match body.first().copied().unwrap_or(0) {
+        0 => Ok(v0.into()),
+        GLOBAL_STATE_VERSION => GlobalState::try_deserialize(&mut &data[..]),
+        _ => Err(crate::ErrorCode::UnsupportedAccountVersion.into()),
+    }
+}
+
+// Loads a read-only [GlobalState] of any supported version.
+pub fn load_global_state_account(info: &AccountInfo) -> Result<GlobalState> {
+    if *info.owner != crate::ID {
+        return Err(anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+    load_global_state(&info.try_borrow_data()?)
+}
+
+// Decodes a [Smallet] of any supported version.
+pub fn load_smallet(data: &[u8]) -> Result<Smallet> {
+    // The zeroed reserved space of the legacy layout reads as version 0
+    let v1 = SmalletV1::deserialize(&mut account_body::<Smallet>(data)?)?;
+    match v1.version {
+        0 => Ok(SmalletV0::deserialize(&mut account_body::<Smallet>(data)?)?.into()),
+        1 => Ok(v1.into()),
+        SMALLET_VERSION => Smallet::try_deserialize(&mut &data[..]),
+        _ => Err(crate::ErrorCode::UnsupportedAccountVersion.into()),
+    }
+}
+
+// Space of the [Smallet] decoded from `data` in the current layout, keeping the
+// room its account had for more owners or guardians.
+pub fn migrated_smallet_space(data: &[u8], smallet: &Smallet) -> Result<usize> {
+    let version = SmalletV1::deserialize(&mut account_body::<Smallet>(data)?)?.version;
+    let layout_space: fn(u8, u8) -> usize = match version {
+        0 => SmalletV0::space,
+        1 => SmalletV1::space,
+        SMALLET_VERSION => Smallet::space,
+        _ => return Err(crate::ErrorCode::UnsupportedAccountVersion.into()),
+    };
+    let owners = smallet.owners.len() as u8;
+    let guardians = smallet.guardians.len() as u8;
+    let spare = data.len().saturating_sub(layout_space(owners, guardians));
+
+    // Spare owner slots also make room for the owner fields added since
+    let owner_len = layout_space(1, 0) - layout_space(0, 0);
+    let owner_growth = Smallet::space(1, 0) - Smallet::space(0, 0) - owner_len;
+    Ok(Smallet::space(owners, guardians) + spare + spare / owner_len * owner_growth)
+}
+
+// Decodes a [Transaction] of any supported version.
+pub fn load_transaction(data: &[u8]) -> Result<Transaction> {
+    let mut body = account_body::<Transaction>(data)?;
+    let v0 = TransactionV0::deserialize(&mut body)?;
+    // Versioned layouts append the version to the legacy one
+    match body.first().copied().unwrap_or(0) {
+        0 => Ok(v0.into()),
+        1 => Ok(TransactionV1::deserialize(&mut account_body::<Transaction>(data)?)?.into()),
+        2 => Ok(TransactionV2::deserialize(&mut account_body::<Transaction>(data)?)?.into()),
+        3 => Ok(TransactionV3::deserialize(&mut account_body::<Transaction>(data)?)?.into()),
+        TRANSACTION_VERSION => Transaction::try_deserialize(&mut &data[..]),
+        _ => Err(crate::ErrorCode::UnsupportedAccountVersion.into()),
+    }
+}
+
+// Decodes a [GuardianAction] of any supported version.
+pub fn load_guardian_action(data: &[u8]) -> Result<GuardianAction> {
+    let mut body = account_body::<GuardianAction>(data)?;
+    let v0 = GuardianActionV0::deserialize(&mut body)?;
+    // Versioned layouts append the version to the legacy one
+    match body.first().copied().unwrap_or(0) {
+        0 => Ok(v0.into()),
+        GUARDIAN_ACTION_VERSION => GuardianAction::try_deserialize(&mut &data[..]),
+        _ => Err(crate::ErrorCode::UnsupportedAccountVersion.into()),
+    }
+}
+
+// Decodes a [SubaccountInfo] of any supported version.
+pub fn load_subaccount_info(data: &[u8]) -> Result<SubaccountInfo> {
+    let mut body = account_body::<SubaccountInfo>(data)?;
+    let v0 = SubaccountInfoV0::deserialize(&mut body)?;
+    // Versioned layouts append the version to the legacy one
+    match body.first().copied().unwrap_or(0) {
+        0 => Ok(v0.into()),
+        SUBACCOUNT_INFO_VERSION => SubaccountInfo::try_deserialize(&mut &data[..]),
+        _ => Err(crate::ErrorCode::UnsupportedAccountVersion.into()),
+    }
+}
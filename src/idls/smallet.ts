@@ -16,15 +16,6 @@ export type SmalletIDL = {
           name: "globalState";
           isMut: true;
           isSigner: false;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                type: "string";
-                value: "Smallet";
-              }
-            ];
-          };
         },
         {
           name: "systemProgram";
@@ -35,9 +26,24 @@ export type SmalletIDL = {
           name: "rent";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "programData";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "domain";
+          type: "u64";
         }
       ];
-      args: [];
     },
     {
       name: "transferGlobalAdmin";
@@ -95,6 +101,17 @@ export type SmalletIDL = {
         }
       ];
     },
+    {
+      name: "activateGlobalThresholds";
+      accounts: [
+        {
+          name: "globalState";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
     {
       name: "createSmallet";
       accounts: [
@@ -122,6 +139,11 @@ export type SmalletIDL = {
             ];
           };
         },
+        {
+          name: "globalState";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "payer";
           isMut: true;
@@ -165,6 +187,14 @@ export type SmalletIDL = {
           type: {
             vec: "publicKey";
           };
+        },
+        {
+          name: "guardianPolicy";
+          type: {
+            option: {
+              defined: "GuardianPolicy";
+            };
+          };
         }
       ];
     },
@@ -203,42 +233,47 @@ export type SmalletIDL = {
       ];
     },
     {
-      name: "createTransaction";
+      name: "setValueTiers";
       accounts: [
         {
           name: "smallet";
           isMut: true;
-          isSigner: false;
-        },
+          isSigner: true;
+        }
+      ];
+      args: [
         {
-          name: "transaction";
-          isMut: true;
-          isSigner: false;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                type: "string";
-                value: "Transaction";
-              },
-              {
-                kind: "account";
-                type: "publicKey";
-                account: "Smallet";
-                path: "smallet";
-              },
-              {
-                kind: "account";
-                type: "u64";
-                account: "Smallet";
-                path: "smallet.num_transactions";
-              }
-            ];
+          name: "valueTiers";
+          type: {
+            vec: {
+              defined: "ValueTier";
+            };
           };
-        },
+        }
+      ];
+    },
+    {
+      name: "setOwnerRoles";
+      accounts: [
         {
-          name: "proposer";
-          isMut: false;
+          name: "smallet";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "roles";
+          type: "bytes";
+        }
+      ];
+    },
+    {
+      name: "resizeSmallet";
+      accounts: [
+        {
+          name: "smallet";
+          isMut: true;
           isSigner: true;
         },
         {
@@ -254,57 +289,43 @@ export type SmalletIDL = {
       ];
       args: [
         {
-          name: "bump";
+          name: "maxOwners";
           type: "u8";
         },
         {
-          name: "instructions";
-          type: {
-            vec: {
-              defined: "TXInstruction";
-            };
-          };
+          name: "maxGuardians";
+          type: "u8";
         }
       ];
     },
     {
-      name: "createTransactionWithTimelock";
+      name: "migrateGlobalState";
       accounts: [
         {
-          name: "smallet";
+          name: "account";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "transaction";
+          name: "payer";
           isMut: true;
-          isSigner: false;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                type: "string";
-                value: "Transaction";
-              },
-              {
-                kind: "account";
-                type: "publicKey";
-                account: "Smallet";
-                path: "smallet";
-              },
-              {
-                kind: "account";
-                type: "u64";
-                account: "Smallet";
-                path: "smallet.num_transactions";
-              }
-            ];
-          };
+          isSigner: true;
         },
         {
-          name: "proposer";
+          name: "systemProgram";
           isMut: false;
-          isSigner: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateSmallet";
+      accounts: [
+        {
+          name: "account";
+          isMut: true;
+          isSigner: false;
         },
         {
           name: "payer";
@@ -317,69 +338,94 @@ export type SmalletIDL = {
           isSigner: false;
         }
       ];
-      args: [
+      args: [];
+    },
+    {
+      name: "migrateTransaction";
+      accounts: [
         {
-          name: "bump";
-          type: "u8";
+          name: "account";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "instructions";
-          type: {
-            vec: {
-              defined: "TXInstruction";
-            };
-          };
+          name: "smallet";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "eta";
-          type: "i64";
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "approve";
+      name: "migrateGuardianAction";
       accounts: [
         {
-          name: "smallet";
-          isMut: false;
+          name: "account";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "transaction";
+          name: "payer";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "owner";
+          name: "systemProgram";
           isMut: false;
-          isSigner: true;
+          isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "unapprove";
+      name: "migrateSubaccountInfo";
       accounts: [
         {
-          name: "smallet";
-          isMut: false;
+          name: "account";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "transaction";
+          name: "payer";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "owner";
+          name: "systemProgram";
           isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeSmallet";
+      accounts: [
+        {
+          name: "smallet";
+          isMut: true;
           isSigner: true;
+        },
+        {
+          name: "recipient";
+          isMut: true;
+          isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "executeTransaction";
+      name: "sweepSmalletAccount";
       accounts: [
         {
           name: "smallet";
@@ -387,24 +433,24 @@ export type SmalletIDL = {
           isSigner: false;
         },
         {
-          name: "transaction";
+          name: "account";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "owner";
-          isMut: false;
-          isSigner: true;
+          name: "recipient";
+          isMut: true;
+          isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "executeTransactionDerived";
+      name: "createTransaction";
       accounts: [
         {
           name: "smallet";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
@@ -413,185 +459,228 @@ export type SmalletIDL = {
           isSigner: false;
         },
         {
-          name: "owner";
+          name: "proposer";
           isMut: false;
           isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
-        {
-          name: "index";
-          type: "u64";
-        },
         {
           name: "bump";
           type: "u8";
+        },
+        {
+          name: "instructions";
+          type: {
+            vec: {
+              defined: "TXInstruction";
+            };
+          };
+        },
+        {
+          name: "options";
+          type: {
+            defined: "TransactionOptions";
+          };
         }
       ];
     },
     {
-      name: "ownerInvokeInstruction";
+      name: "createTransactionWithTimelock";
       accounts: [
         {
           name: "smallet";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "owner";
-          isMut: false;
-          isSigner: true;
+          name: "transaction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "proposer";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
-        {
-          name: "index";
-          type: "u64";
-        },
         {
           name: "bump";
           type: "u8";
         },
         {
-          name: "ix";
+          name: "instructions";
           type: {
-            defined: "TXInstruction";
+            vec: {
+              defined: "TXInstruction";
+            };
+          };
+        },
+        {
+          name: "options";
+          type: {
+            defined: "TransactionOptions";
           };
+        },
+        {
+          name: "eta";
+          type: "i64";
         }
       ];
     },
     {
-      name: "ownerInvokeInstructionV2";
+      name: "cancelTransaction";
       accounts: [
         {
           name: "smallet";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "owner";
+          name: "transaction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "proposer";
           isMut: false;
           isSigner: true;
         }
       ];
-      args: [
-        {
-          name: "index";
-          type: "u64";
-        },
-        {
-          name: "bump";
-          type: "u8";
-        },
+      args: [];
+    },
+    {
+      name: "expireTransaction";
+      accounts: [
         {
-          name: "invoker";
-          type: "publicKey";
+          name: "smallet";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "data";
-          type: "bytes";
+          name: "transaction";
+          isMut: true;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "createSubaccountInfo";
+      name: "approve";
       accounts: [
         {
-          name: "subaccountInfo";
+          name: "smallet";
           isMut: true;
           isSigner: false;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                type: "string";
-                value: "SubaccountInfo";
-              },
-              {
-                kind: "arg";
-                type: "publicKey";
-                path: "subaccount";
-              }
-            ];
-          };
         },
         {
-          name: "payer";
+          name: "transaction";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "owner";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         }
       ];
-      args: [
-        {
-          name: "bump";
-          type: "u8";
-        },
-        {
-          name: "subaccount";
-          type: "publicKey";
-        },
+      args: [];
+    },
+    {
+      name: "unapprove";
+      accounts: [
         {
           name: "smallet";
-          type: "publicKey";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "index";
-          type: "u64";
+          name: "transaction";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "subaccountType";
-          type: {
-            defined: "SubaccountType";
-          };
+          name: "owner";
+          isMut: false;
+          isSigner: true;
         }
       ];
+      args: [];
     },
     {
-      name: "setSession";
+      name: "executeTransaction";
       accounts: [
         {
           name: "smallet";
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "transaction";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "owner";
           isMut: false;
           isSigner: true;
         }
       ];
-      args: [
-        {
-          name: "expiresAt";
-          type: {
-            option: "i64";
-          };
-        }
-      ];
+      args: [];
     },
     {
-      name: "setFrozen";
+      name: "executeHashedTransaction";
       accounts: [
         {
           name: "smallet";
           isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transaction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "owner";
+          isMut: false;
           isSigner: true;
         }
       ];
       args: [
         {
-          name: "frozen";
-          type: "bool";
+          name: "instructions";
+          type: {
+            vec: {
+              defined: "TXInstruction";
+            };
+          };
         }
       ];
     },
     {
-      name: "setFrozenAdmin";
+      name: "executeTransactionStep";
       accounts: [
         {
           name: "smallet";
@@ -599,131 +688,200 @@ export type SmalletIDL = {
           isSigner: false;
         },
         {
-          name: "globalState";
-          isMut: false;
+          name: "transaction";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "admin";
-          isMut: true;
+          name: "owner";
+          isMut: false;
           isSigner: true;
         }
       ];
       args: [
         {
-          name: "frozen";
-          type: "bool";
+          name: "step";
+          type: "u64";
         }
       ];
     },
     {
-      name: "lockSmallet";
+      name: "executeHashedTransactionStep";
       accounts: [
         {
-          name: "guadian";
+          name: "smallet";
           isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "globalState";
-          isMut: false;
           isSigner: false;
         },
         {
-          name: "smallet";
+          name: "transaction";
           isMut: true;
           isSigner: false;
+        },
+        {
+          name: "owner";
+          isMut: false;
+          isSigner: true;
         }
       ];
-      args: [];
-    },
-    {
-      name: "createGuadianAction";
-      accounts: [
+      args: [
         {
-          name: "guadian";
-          isMut: true;
-          isSigner: true;
+          name: "step";
+          type: "u64";
         },
         {
-          name: "globalState";
-          isMut: false;
-          isSigner: false;
-        },
+          name: "instructions";
+          type: {
+            vec: {
+              defined: "TXInstruction";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "simulateTransaction";
+      accounts: [
         {
           name: "smallet";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "guadianAction";
-          isMut: true;
+          name: "transaction";
+          isMut: false;
           isSigner: false;
-          pda: {
-            seeds: [
-              {
-                kind: "const";
-                type: "string";
-                value: "GuadianAction";
-              },
-              {
-                kind: "account";
-                type: "publicKey";
-                account: "Smallet";
-                path: "smallet";
-              },
-              {
-                kind: "account";
-                type: "u64";
-                account: "Smallet";
-                path: "smallet.num_gudian_actions";
-              }
-            ];
-          };
         },
         {
-          name: "systemProgram";
+          name: "owner";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         }
       ];
       args: [
         {
-          name: "actionType";
+          name: "instructions";
           type: {
-            defined: "GuadianActionType";
+            option: {
+              vec: {
+                defined: "TXInstruction";
+              };
+            };
           };
         },
         {
-          name: "guadiansCount";
-          type: "u8";
+          name: "index";
+          type: {
+            option: "u64";
+          };
         },
         {
-          name: "addressesCount";
-          type: "u8";
+          name: "bump";
+          type: {
+            option: "u8";
+          };
         }
       ];
     },
     {
-      name: "tryActionWithSign";
+      name: "executeTransactionDerived";
       accounts: [
         {
-          name: "guadian";
+          name: "smallet";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "globalState";
+          name: "transaction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "owner";
           isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u64";
+        },
+        {
+          name: "bump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "ownerInvokeInstruction";
+      accounts: [
+        {
+          name: "smallet";
+          isMut: true;
           isSigner: false;
         },
+        {
+          name: "owner";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u64";
+        },
+        {
+          name: "bump";
+          type: "u8";
+        },
+        {
+          name: "ix";
+          type: {
+            defined: "TXInstruction";
+          };
+        }
+      ];
+    },
+    {
+      name: "ownerInvokeInstructionV2";
+      accounts: [
         {
           name: "smallet";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "guadianAction";
+          name: "owner";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u64";
+        },
+        {
+          name: "bump";
+          type: "u8";
+        },
+        {
+          name: "invoker";
+          type: "publicKey";
+        },
+        {
+          name: "data";
+          type: "bytes";
+        }
+      ];
+    },
+    {
+      name: "createSubaccountInfo";
+      accounts: [
+        {
+          name: "subaccountInfo";
           isMut: true;
           isSigner: false;
           pda: {
@@ -731,1541 +889,5253 @@ export type SmalletIDL = {
               {
                 kind: "const";
                 type: "string";
-                value: "GuadianAction";
-              },
-              {
-                kind: "account";
-                type: "publicKey";
-                account: "Smallet";
-                path: "smallet";
+                value: "SubaccountInfo";
               },
               {
                 kind: "arg";
-                type: "u64";
-                path: "index";
+                type: "publicKey";
+                path: "subaccount";
               }
             ];
           };
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
+        {
+          name: "bump";
+          type: "u8";
+        },
+        {
+          name: "subaccount";
+          type: "publicKey";
+        },
+        {
+          name: "smallet";
+          type: "publicKey";
+        },
         {
           name: "index";
           type: "u64";
+        },
+        {
+          name: "subaccountType";
+          type: {
+            defined: "SubaccountType";
+          };
         }
       ];
-    }
-  ];
-  accounts: [
+    },
     {
-      name: "GlobalState";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "globalAdmin";
-            type: "publicKey";
-          },
-          {
-            name: "guadiansChangePeriod";
-            type: "i64";
-          },
-          {
-            name: "guadiansActionExpiresTime";
-            type: "i64";
-          },
-          {
-            name: "minAgreePermyriad";
-            type: "u16";
-          }
-        ];
-      };
+      name: "setSession";
+      accounts: [
+        {
+          name: "smallet";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "owner";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "expiresAt";
+          type: {
+            option: "i64";
+          };
+        }
+      ];
     },
     {
-      name: "Smallet";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "base";
-            type: "publicKey";
-          },
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "threshold";
-            type: "u64";
-          },
-          {
-            name: "minimumDelay";
-            type: "i64";
-          },
-          {
-            name: "gracePeriod";
-            type: "i64";
-          },
-          {
-            name: "ownerSetSeqno";
-            type: "u32";
-          },
-          {
-            name: "numTransactions";
-            type: "u64";
-          },
-          {
-            name: "owners";
-            type: {
-              vec: "publicKey";
-            };
-          },
-          {
-            name: "ownerSessions";
-            type: {
-              vec: "i64";
-            };
-          },
-          {
-            name: "guadians";
-            type: {
-              vec: "publicKey";
-            };
-          },
-          {
-            name: "numGudianActions";
-            type: "u64";
-          },
-          {
-            name: "frozen";
-            type: "bool";
-          },
-          {
-            name: "locked";
-            type: "bool";
-          },
-          {
-            name: "reserved";
-            type: {
-              array: ["u64", 16];
-            };
-          }
-        ];
-      };
+      name: "heartbeat";
+      accounts: [
+        {
+          name: "smallet";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "owner";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [];
     },
     {
-      name: "GuadianAction";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "smallet";
-            type: "publicKey";
-          },
-          {
-            name: "actionRequestedTime";
-            type: "i64";
-          },
-          {
-            name: "actionType";
-            type: {
-              defined: "GuadianActionType";
-            };
-          },
-          {
-            name: "performed";
-            type: "bool";
-          },
-          {
-            name: "agreedSigns";
-            type: {
-              vec: "bool";
-            };
-          },
-          {
-            name: "addresses";
-            type: {
-              vec: "publicKey";
-            };
-          }
-        ];
-      };
-    },
-    {
-      name: "Transaction";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "smallet";
-            type: "publicKey";
-          },
-          {
-            name: "index";
-            type: "u64";
-          },
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "proposer";
-            type: "publicKey";
-          },
-          {
-            name: "instructions";
-            type: {
-              vec: {
-                defined: "TXInstruction";
-              };
-            };
-          },
-          {
-            name: "signers";
-            type: {
-              vec: "bool";
-            };
-          },
-          {
-            name: "ownerSetSeqno";
-            type: "u32";
-          },
-          {
-            name: "eta";
-            type: "i64";
-          },
-          {
-            name: "executor";
-            type: "publicKey";
-          },
-          {
-            name: "executedAt";
-            type: "i64";
-          }
-        ];
-      };
-    },
-    {
-      name: "SubaccountInfo";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "smallet";
-            type: "publicKey";
-          },
-          {
-            name: "subaccountType";
-            type: {
-              defined: "SubaccountType";
-            };
-          },
-          {
-            name: "index";
-            type: "u64";
-          }
-        ];
-      };
-    }
-  ];
-  types: [
-    {
-      name: "TXInstruction";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "programId";
-            type: "publicKey";
-          },
-          {
-            name: "keys";
-            type: {
-              vec: {
-                defined: "TXAccountMeta";
-              };
-            };
-          },
-          {
-            name: "data";
-            type: "bytes";
-          }
-        ];
-      };
-    },
-    {
-      name: "TXAccountMeta";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "pubkey";
-            type: "publicKey";
-          },
-          {
-            name: "isSigner";
-            type: "bool";
-          },
-          {
-            name: "isWritable";
-            type: "bool";
-          }
-        ];
-      };
-    },
-    {
-      name: "GuadianActionType";
-      type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "NoAction";
-          },
-          {
-            name: "UnlockSmallet";
-          },
-          {
-            name: "SetOwners";
-          },
-          {
-            name: "SetGuadians";
-          }
-        ];
-      };
-    },
-    {
-      name: "SubaccountType";
-      type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "Derived";
-          },
-          {
-            name: "OwnerInvoker";
-          }
-        ];
-      };
-    }
-  ];
-  events: [
-    {
-      name: "WalletCreateEvent";
-      fields: [
+      name: "setInactivityPeriod";
+      accounts: [
         {
           name: "smallet";
-          type: "publicKey";
-          index: true;
-        },
-        {
-          name: "owners";
-          type: {
-            vec: "publicKey";
-          };
-          index: false;
-        },
-        {
-          name: "threshold";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "minimumDelay";
-          type: "i64";
-          index: false;
-        },
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
         {
-          name: "timestamp";
+          name: "inactivityPeriod";
           type: "i64";
-          index: false;
         }
       ];
     },
     {
-      name: "WalletSetOwnersEvent";
-      fields: [
+      name: "setGuardianPolicy";
+      accounts: [
         {
           name: "smallet";
-          type: "publicKey";
-          index: true;
-        },
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
         {
-          name: "owners";
+          name: "guardianPolicy";
           type: {
-            vec: "publicKey";
+            option: {
+              defined: "GuardianPolicy";
+            };
           };
-          index: false;
-        },
-        {
-          name: "timestamp";
-          type: "i64";
-          index: false;
         }
       ];
     },
     {
-      name: "WalletChangeThresholdEvent";
-      fields: [
+      name: "setGuardianQuorums";
+      accounts: [
         {
           name: "smallet";
-          type: "publicKey";
-          index: true;
-        },
-        {
-          name: "threshold";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "timestamp";
-          type: "i64";
-          index: false;
+          isMut: true;
+          isSigner: true;
         }
       ];
-    },
-    {
-      name: "TransactionCreateEvent";
-      fields: [
+      args: [
         {
-          name: "smallet";
-          type: "publicKey";
-          index: true;
-        },
-        {
-          name: "transaction";
-          type: "publicKey";
-          index: true;
-        },
-        {
-          name: "proposer";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "instructions";
+          name: "guardianQuorums";
           type: {
             vec: {
-              defined: "TXInstruction";
+              defined: "GuardianQuorum";
             };
           };
-          index: false;
-        },
+        }
+      ];
+    },
+    {
+      name: "setFrozen";
+      accounts: [
         {
-          name: "eta";
-          type: "i64";
-          index: false;
-        },
+          name: "smallet";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
         {
-          name: "timestamp";
-          type: "i64";
-          index: false;
+          name: "frozen";
+          type: "bool";
         }
       ];
     },
     {
-      name: "TransactionApproveEvent";
-      fields: [
+      name: "emergencyFreeze";
+      accounts: [
         {
           name: "smallet";
-          type: "publicKey";
-          index: true;
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "transaction";
-          type: "publicKey";
-          index: true;
-        },
+          name: "freezer";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setEmergencyKey";
+      accounts: [
         {
-          name: "owner";
-          type: "publicKey";
-          index: false;
-        },
+          name: "smallet";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
         {
-          name: "timestamp";
-          type: "i64";
-          index: false;
+          name: "emergencyKey";
+          type: {
+            option: "publicKey";
+          };
         }
       ];
     },
     {
-      name: "TransactionUnapproveEvent";
-      fields: [
+      name: "setFrozenAdmin";
+      accounts: [
         {
           name: "smallet";
-          type: "publicKey";
-          index: true;
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "transaction";
-          type: "publicKey";
-          index: true;
+          name: "globalState";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "owner";
-          type: "publicKey";
-          index: false;
-        },
+          name: "admin";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
         {
-          name: "timestamp";
-          type: "i64";
-          index: false;
+          name: "frozen";
+          type: "bool";
         }
       ];
     },
     {
-      name: "TransactionExecuteEvent";
-      fields: [
+      name: "setSequential";
+      accounts: [
         {
           name: "smallet";
-          type: "publicKey";
-          index: true;
-        },
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
         {
-          name: "transaction";
-          type: "publicKey";
-          index: true;
-        },
+          name: "sequential";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "setMinimumDelay";
+      accounts: [
         {
-          name: "executor";
-          type: "publicKey";
-          index: false;
-        },
+          name: "smallet";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
         {
-          name: "timestamp";
+          name: "minimumDelay";
           type: "i64";
-          index: false;
         }
       ];
     },
     {
-      name: "OwnerSetSessionEvent";
-      fields: [
+      name: "setGracePeriod";
+      accounts: [
         {
           name: "smallet";
-          type: "publicKey";
-          index: true;
-        },
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
         {
-          name: "expiresAt";
+          name: "gracePeriod";
           type: "i64";
-          index: false;
-        },
+        }
+      ];
+    },
+    {
+      name: "setLockDuration";
+      accounts: [
         {
-          name: "timestamp";
+          name: "smallet";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "lockDuration";
           type: "i64";
-          index: false;
         }
       ];
     },
     {
-      name: "OwnerSetFrozenEvent";
-      fields: [
+      name: "lockSmallet";
+      accounts: [
         {
-          name: "smallet";
-          type: "publicKey";
-          index: true;
+          name: "guardian";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "frozen";
-          type: "bool";
-          index: false;
+          name: "globalState";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "timestamp";
-          type: "i64";
-          index: false;
+          name: "smallet";
+          isMut: true;
+          isSigner: false;
         }
       ];
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "InvalidOwner";
-      msg: "The given owner is not part of this smallet.";
-    },
-    {
-      code: 6001;
-      name: "InvalidGlobalAdmin";
-      msg: "The given owner is not the global admin.";
-    },
-    {
-      code: 6002;
-      name: "InvalidDeployer";
-      msg: "Global state initializer does not match the deployer of program";
-    },
-    {
-      code: 6003;
-      name: "InvalidProgramDataAccount";
-      msg: "Invalid ProgramData account";
-    },
-    {
-      code: 6004;
-      name: "InvalidETA";
-      msg: "Estimated execution block must satisfy delay.";
+      args: [];
     },
     {
-      code: 6005;
-      name: "DelayTooHigh";
-      msg: "Delay greater than the maximum.";
+      name: "createGuardianAction";
+      accounts: [
+        {
+          name: "guardian";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalState";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "smallet";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "guardianAction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "actionType";
+          type: {
+            defined: "GuardianActionType";
+          };
+        },
+        {
+          name: "guardiansCount";
+          type: "u8";
+        },
+        {
+          name: "addressesCount";
+          type: "u8";
+        }
+      ];
     },
     {
-      code: 6006;
-      name: "NotEnoughSigners";
-      msg: "Not enough owners signed this transaction.";
-    },
-    {
-      code: 6007;
-      name: "TransactionIsStale";
-      msg: "Transaction is past the grace period.";
-    },
-    {
-      code: 6008;
-      name: "TransactionNotReady";
-      msg: "Transaction hasn't surpassed time lock.";
-    },
-    {
-      code: 6009;
-      name: "AlreadyExecuted";
-      msg: "The given transaction has already been executed.";
-    },
-    {
-      code: 6010;
-      name: "InvalidThreshold";
-      msg: "Threshold must be less than or equal to the number of owners.";
-    },
-    {
-      code: 6011;
-      name: "OwnerSetChanged";
-      msg: "Owner set has changed since the creation of the transaction.";
-    },
-    {
-      code: 6012;
-      name: "SubaccountOwnerMismatch";
-      msg: "Subaccount does not belong to smallet.";
-    },
-    {
-      code: 6013;
-      name: "BufferFinalized";
-      msg: "Buffer already finalized.";
-    },
-    {
-      code: 6014;
-      name: "BufferBundleNotFound";
-      msg: "Buffer bundle not found.";
-    },
-    {
-      code: 6015;
-      name: "BufferBundleOutOfRange";
-      msg: "Buffer index specified is out of range.";
-    },
-    {
-      code: 6016;
-      name: "BufferBundleNotFinalized";
-      msg: "Buffer has not been finalized.";
-    },
-    {
-      code: 6017;
-      name: "BufferBundleExecuted";
-      msg: "Buffer bundle has already been executed.";
-    },
-    {
-      code: 6018;
-      name: "AccountFrozen";
-      msg: "The Smallet account is frozen.";
-    },
-    {
-      code: 6019;
-      name: "InvalidGuadian";
-      msg: "The given address is not guadian or global admin.";
-    },
-    {
-      code: 6020;
-      name: "IncorrectGuadiansCount";
-      msg: "The given guadians count not matched with smallet guadians count.";
-    },
-    {
-      code: 6021;
-      name: "IncorrectAddressesCount";
-      msg: "The given new addresses count not matched with remaining account of context.";
-    },
-    {
-      code: 6022;
-      name: "ActionExpired";
-      msg: "Passed expiration time for enough sign to perform the guadian action.";
-    },
-    {
-      code: 6023;
-      name: "NotEnoughChangePeriod";
-      msg: "Not enough time passed to change guadians.";
-    },
-    {
-      code: 6024;
-      name: "InvalidGuadianAction";
-      msg: "Guadian action's smallet address not matched with provided smallet account.";
-    },
-    {
-      code: 6025;
-      name: "ActionAlreadyPerformed";
-      msg: "Guadian action is already performed.";
-    }
-  ];
-};
-export const SmalletJSON: SmalletIDL = {
-  version: "0.11.1",
-  name: "smallet",
-  instructions: [
-    {
-      name: "initializeGlobalState",
-      accounts: [
-        {
-          name: "admin",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "globalState",
-          isMut: true,
-          isSigner: false,
-          pda: {
-            seeds: [
-              {
-                kind: "const",
-                type: "string",
-                value: "Smallet",
-              },
-            ],
-          },
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
-    },
-    {
-      name: "transferGlobalAdmin",
-      accounts: [
-        {
-          name: "globalState",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "admin",
-          isMut: true,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "newAdmin",
-          type: "publicKey",
-        },
-      ],
-    },
-    {
-      name: "setGlobalThresholds",
+      name: "tryActionWithSign";
       accounts: [
         {
-          name: "globalState",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "admin",
-          isMut: true,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "changePeriod",
-          type: {
-            option: "i64",
-          },
-        },
-        {
-          name: "actionExpires",
-          type: {
-            option: "i64",
-          },
+          name: "guardian";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "agreePermyriad",
-          type: {
-            option: "u16",
-          },
+          name: "globalState";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-    },
-    {
-      name: "createSmallet",
-      accounts: [
         {
-          name: "base",
-          isMut: false,
-          isSigner: true,
+          name: "smallet";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "smallet",
-          isMut: true,
-          isSigner: false,
+          name: "guardianAction";
+          isMut: true;
+          isSigner: false;
           pda: {
             seeds: [
               {
-                kind: "const",
-                type: "string",
-                value: "Smallet",
+                kind: "const";
+                type: "string";
+                value: "GuardianAction";
               },
               {
-                kind: "account",
-                type: "publicKey",
-                path: "base",
+                kind: "account";
+                type: "publicKey";
+                account: "Smallet";
+                path: "smallet";
               },
-            ],
-          },
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
+              {
+                kind: "arg";
+                type: "u64";
+                path: "index";
+              }
+            ];
+          };
+        }
+      ];
       args: [
         {
-          name: "bump",
-          type: "u8",
-        },
-        {
-          name: "maxOwners",
-          type: "u8",
-        },
-        {
-          name: "owners",
-          type: {
-            vec: "publicKey",
-          },
-        },
-        {
-          name: "threshold",
-          type: "u64",
-        },
-        {
-          name: "minimumDelay",
+          name: "index";
+          type: "u64";
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: "GlobalState";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "globalAdmin";
+            type: "publicKey";
+          },
+          {
+            name: "guardiansChangePeriod";
+            type: "i64";
+          },
+          {
+            name: "guardiansActionExpiresTime";
+            type: "i64";
+          },
+          {
+            name: "minAgreePermyriad";
+            type: "u16";
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "domain";
+            type: "u64";
+          },
+          {
+            name: "pendingThresholds";
+            type: {
+              defined: "GuardianPolicy";
+            };
+          },
+          {
+            name: "thresholdsActivateAt";
+            type: "i64";
+          },
+          {
+            name: "reserved";
+            type: {
+              array: ["u8", 30];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "Smallet";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "base";
+            type: "publicKey";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "threshold";
+            type: "u64";
+          },
+          {
+            name: "minimumDelay";
+            type: "i64";
+          },
+          {
+            name: "gracePeriod";
+            type: "i64";
+          },
+          {
+            name: "ownerSetSeqno";
+            type: "u32";
+          },
+          {
+            name: "numTransactions";
+            type: "u64";
+          },
+          {
+            name: "owners";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "ownerSessions";
+            type: {
+              vec: "i64";
+            };
+          },
+          {
+            name: "guardians";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "numGudianActions";
+            type: "u64";
+          },
+          {
+            name: "frozen";
+            type: "bool";
+          },
+          {
+            name: "locked";
+            type: "bool";
+          },
+          {
+            name: "valueTiers";
+            type: {
+              vec: {
+                defined: "ValueTier";
+              };
+            };
+          },
+          {
+            name: "ownerRoles";
+            type: "bytes";
+          },
+          {
+            name: "emergencyKey";
+            type: "publicKey";
+          },
+          {
+            name: "lockedAt";
+            type: "i64";
+          },
+          {
+            name: "lockDuration";
+            type: "i64";
+          },
+          {
+            name: "lastOwnerActivity";
+            type: "i64";
+          },
+          {
+            name: "inactivityPeriod";
+            type: "i64";
+          },
+          {
+            name: "guardianPolicy";
+            type: {
+              option: {
+                defined: "GuardianPolicy";
+              };
+            };
+          },
+          {
+            name: "guardianQuorums";
+            type: {
+              vec: {
+                defined: "GuardianQuorum";
+              };
+            };
+          },
+          {
+            name: "pendingTransactions";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "domain";
+            type: "u64";
+          },
+          {
+            name: "sequential";
+            type: "bool";
+          },
+          {
+            name: "pendingMinimumDelay";
+            type: "i64";
+          },
+          {
+            name: "minimumDelayChangeAt";
+            type: "i64";
+          },
+          {
+            name: "pendingGracePeriod";
+            type: "i64";
+          },
+          {
+            name: "gracePeriodChangeAt";
+            type: "i64";
+          },
+          {
+            name: "reserved";
+            type: {
+              array: ["u64", 10];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "GuardianAction";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "smallet";
+            type: "publicKey";
+          },
+          {
+            name: "actionRequestedTime";
+            type: "i64";
+          },
+          {
+            name: "actionType";
+            type: {
+              defined: "GuardianActionType";
+            };
+          },
+          {
+            name: "performed";
+            type: "bool";
+          },
+          {
+            name: "agreedSigns";
+            type: {
+              vec: "bool";
+            };
+          },
+          {
+            name: "addresses";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "Transaction";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "smallet";
+            type: "publicKey";
+          },
+          {
+            name: "index";
+            type: "u64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "proposer";
+            type: "publicKey";
+          },
+          {
+            name: "instructions";
+            type: {
+              vec: {
+                defined: "TXInstruction";
+              };
+            };
+          },
+          {
+            name: "signers";
+            type: {
+              vec: "bool";
+            };
+          },
+          {
+            name: "ownerSetSeqno";
+            type: "u32";
+          },
+          {
+            name: "eta";
+            type: "i64";
+          },
+          {
+            name: "executor";
+            type: "publicKey";
+          },
+          {
+            name: "executedAt";
+            type: "i64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "executedSteps";
+            type: "u64";
+          },
+          {
+            name: "instructionsHash";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "cancelledAt";
+            type: "i64";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          },
+          {
+            name: "requiredThreshold";
+            type: "u64";
+          },
+          {
+            name: "expiredAt";
+            type: "i64";
+          },
+          {
+            name: "lookupTables";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "lookupAccounts";
+            type: {
+              vec: {
+                defined: "TXLookupAccountMeta";
+              };
+            };
+          },
+          {
+            name: "prerequisites";
+            type: {
+              vec: "u64";
+            };
+          },
+          {
+            name: "preAssertions";
+            type: {
+              vec: {
+                defined: "TXAssertion";
+              };
+            };
+          },
+          {
+            name: "postAssertions";
+            type: {
+              vec: {
+                defined: "TXAssertion";
+              };
+            };
+          },
+          {
+            name: "lookupAddressesHash";
+            type: {
+              array: ["u8", 32];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "SubaccountInfo";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "smallet";
+            type: "publicKey";
+          },
+          {
+            name: "subaccountType";
+            type: {
+              defined: "SubaccountType";
+            };
+          },
+          {
+            name: "index";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "SimulationReport";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "executed";
+            type: "u64";
+          },
+          {
+            name: "failedPreAssertion";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "failedPostAssertion";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "lamportDeltas";
+            type: {
+              vec: {
+                defined: "AccountDelta";
+              };
+            };
+          },
+          {
+            name: "tokenDeltas";
+            type: {
+              vec: {
+                defined: "AccountDelta";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "AccountDelta";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "account";
+            type: "publicKey";
+          },
+          {
+            name: "delta";
+            type: "i128";
+          }
+        ];
+      };
+    },
+    {
+      name: "GuardianPolicy";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "changePeriod";
+            type: "i64";
+          },
+          {
+            name: "actionExpiresTime";
+            type: "i64";
+          },
+          {
+            name: "minAgreePermyriad";
+            type: "u16";
+          }
+        ];
+      };
+    },
+    {
+      name: "GuardianQuorum";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "actionKind";
+            type: {
+              defined: "GuardianActionKind";
+            };
+          },
+          {
+            name: "minAgreePermyriad";
+            type: "u16";
+          },
+          {
+            name: "delay";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "ValueTier";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "maxAmount";
+            type: "u64";
+          },
+          {
+            name: "threshold";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "TXInstruction";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "programId";
+            type: "publicKey";
+          },
+          {
+            name: "keys";
+            type: {
+              vec: {
+                defined: "TXAccountMeta";
+              };
+            };
+          },
+          {
+            name: "data";
+            type: "bytes";
+          }
+        ];
+      };
+    },
+    {
+      name: "TXReturnData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "programId";
+            type: "publicKey";
+          },
+          {
+            name: "data";
+            type: "bytes";
+          }
+        ];
+      };
+    },
+    {
+      name: "TransactionOptions";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "prerequisites";
+            type: {
+              vec: "u64";
+            };
+          },
+          {
+            name: "lookupTables";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "lookupAccounts";
+            type: {
+              vec: {
+                defined: "TXLookupAccountMeta";
+              };
+            };
+          },
+          {
+            name: "preAssertions";
+            type: {
+              vec: {
+                defined: "TXAssertion";
+              };
+            };
+          },
+          {
+            name: "postAssertions";
+            type: {
+              vec: {
+                defined: "TXAssertion";
+              };
+            };
+          },
+          {
+            name: "hashCommitted";
+            type: "bool";
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "TXLookupAccountMeta";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "instructionIndex";
+            type: "u8";
+          },
+          {
+            name: "tableIndex";
+            type: "u8";
+          },
+          {
+            name: "addressIndex";
+            type: "u8";
+          },
+          {
+            name: "isWritable";
+            type: "bool";
+          },
+          {
+            name: "position";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "TXAccountMeta";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pubkey";
+            type: "publicKey";
+          },
+          {
+            name: "isSigner";
+            type: "bool";
+          },
+          {
+            name: "isWritable";
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "GlobalStateV0";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "globalAdmin";
+            type: "publicKey";
+          },
+          {
+            name: "guardiansChangePeriod";
+            type: "i64";
+          },
+          {
+            name: "guardiansActionExpiresTime";
+            type: "i64";
+          },
+          {
+            name: "minAgreePermyriad";
+            type: "u16";
+          }
+        ];
+      };
+    },
+    {
+      name: "SmalletV0";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "base";
+            type: "publicKey";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "threshold";
+            type: "u64";
+          },
+          {
+            name: "minimumDelay";
+            type: "i64";
+          },
+          {
+            name: "gracePeriod";
+            type: "i64";
+          },
+          {
+            name: "ownerSetSeqno";
+            type: "u32";
+          },
+          {
+            name: "numTransactions";
+            type: "u64";
+          },
+          {
+            name: "owners";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "ownerSessions";
+            type: {
+              vec: "i64";
+            };
+          },
+          {
+            name: "guardians";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "numGudianActions";
+            type: "u64";
+          },
+          {
+            name: "frozen";
+            type: "bool";
+          },
+          {
+            name: "locked";
+            type: "bool";
+          },
+          {
+            name: "reserved";
+            type: {
+              array: ["u64", 16];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "SmalletV1";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "base";
+            type: "publicKey";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "threshold";
+            type: "u64";
+          },
+          {
+            name: "minimumDelay";
+            type: "i64";
+          },
+          {
+            name: "gracePeriod";
+            type: "i64";
+          },
+          {
+            name: "ownerSetSeqno";
+            type: "u32";
+          },
+          {
+            name: "numTransactions";
+            type: "u64";
+          },
+          {
+            name: "owners";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "ownerSessions";
+            type: {
+              vec: "i64";
+            };
+          },
+          {
+            name: "guardians";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "numGudianActions";
+            type: "u64";
+          },
+          {
+            name: "frozen";
+            type: "bool";
+          },
+          {
+            name: "locked";
+            type: "bool";
+          },
+          {
+            name: "valueTiers";
+            type: {
+              vec: {
+                defined: "ValueTier";
+              };
+            };
+          },
+          {
+            name: "ownerRoles";
+            type: "bytes";
+          },
+          {
+            name: "emergencyKey";
+            type: "publicKey";
+          },
+          {
+            name: "lockedAt";
+            type: "i64";
+          },
+          {
+            name: "lockDuration";
+            type: "i64";
+          },
+          {
+            name: "lastOwnerActivity";
+            type: "i64";
+          },
+          {
+            name: "inactivityPeriod";
+            type: "i64";
+          },
+          {
+            name: "guardianPolicy";
+            type: {
+              option: {
+                defined: "GuardianPolicy";
+              };
+            };
+          },
+          {
+            name: "guardianQuorums";
+            type: {
+              vec: {
+                defined: "GuardianQuorum";
+              };
+            };
+          },
+          {
+            name: "pendingTransactions";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "TransactionV0";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "smallet";
+            type: "publicKey";
+          },
+          {
+            name: "index";
+            type: "u64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "proposer";
+            type: "publicKey";
+          },
+          {
+            name: "instructions";
+            type: {
+              vec: {
+                defined: "TXInstruction";
+              };
+            };
+          },
+          {
+            name: "signers";
+            type: {
+              vec: "bool";
+            };
+          },
+          {
+            name: "ownerSetSeqno";
+            type: "u32";
+          },
+          {
+            name: "eta";
+            type: "i64";
+          },
+          {
+            name: "executor";
+            type: "publicKey";
+          },
+          {
+            name: "executedAt";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "TransactionV1";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "v0";
+            type: {
+              defined: "TransactionV0";
+            };
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "reserved";
+            type: {
+              array: ["u64", 8];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "TXLookupAccountMetaV0";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "instructionIndex";
+            type: "u8";
+          },
+          {
+            name: "tableIndex";
+            type: "u8";
+          },
+          {
+            name: "addressIndex";
+            type: "u8";
+          },
+          {
+            name: "isWritable";
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "TransactionV2";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "v1";
+            type: {
+              defined: "TransactionV1";
+            };
+          },
+          {
+            name: "lookupTables";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "lookupAccounts";
+            type: {
+              vec: {
+                defined: "TXLookupAccountMetaV0";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "TransactionV3";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "v0";
+            type: {
+              defined: "TransactionV0";
+            };
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "executedSteps";
+            type: "u64";
+          },
+          {
+            name: "instructionsHash";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "cancelledAt";
+            type: "i64";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          },
+          {
+            name: "requiredThreshold";
+            type: "u64";
+          },
+          {
+            name: "expiredAt";
+            type: "i64";
+          },
+          {
+            name: "lookupTables";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "lookupAccounts";
+            type: {
+              vec: {
+                defined: "TXLookupAccountMeta";
+              };
+            };
+          },
+          {
+            name: "prerequisites";
+            type: {
+              vec: "u64";
+            };
+          },
+          {
+            name: "preAssertions";
+            type: {
+              vec: {
+                defined: "TXAssertion";
+              };
+            };
+          },
+          {
+            name: "postAssertions";
+            type: {
+              vec: {
+                defined: "TXAssertion";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "GuardianActionV0";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "smallet";
+            type: "publicKey";
+          },
+          {
+            name: "actionRequestedTime";
+            type: "i64";
+          },
+          {
+            name: "actionType";
+            type: {
+              defined: "GuardianActionType";
+            };
+          },
+          {
+            name: "performed";
+            type: "bool";
+          },
+          {
+            name: "agreedSigns";
+            type: {
+              vec: "bool";
+            };
+          },
+          {
+            name: "addresses";
+            type: {
+              vec: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "SubaccountInfoV0";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "smallet";
+            type: "publicKey";
+          },
+          {
+            name: "subaccountType";
+            type: {
+              defined: "SubaccountType";
+            };
+          },
+          {
+            name: "index";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "GuardianActionType";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "NoAction";
+          },
+          {
+            name: "UnlockSmallet";
+          },
+          {
+            name: "SetOwners";
+          },
+          {
+            name: "SetGuardians";
+          },
+          {
+            name: "InheritOwners";
+          },
+          {
+            name: "SetThreshold";
+            fields: [
+              {
+                name: "threshold";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "ReplaceOwner";
+            fields: [
+              {
+                name: "old";
+                type: "publicKey";
+              },
+              {
+                name: "new";
+                type: "publicKey";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "GuardianActionKind";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "NoAction";
+          },
+          {
+            name: "UnlockSmallet";
+          },
+          {
+            name: "SetOwners";
+          },
+          {
+            name: "SetGuardians";
+          },
+          {
+            name: "InheritOwners";
+          },
+          {
+            name: "SetThreshold";
+          },
+          {
+            name: "ReplaceOwner";
+          }
+        ];
+      };
+    },
+    {
+      name: "TXAssertion";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "MinTokenBalance";
+            fields: [
+              {
+                name: "account";
+                type: "publicKey";
+              },
+              {
+                name: "amount";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "MinLamports";
+            fields: [
+              {
+                name: "account";
+                type: "publicKey";
+              },
+              {
+                name: "lamports";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "MaxLamportsDecrease";
+            fields: [
+              {
+                name: "account";
+                type: "publicKey";
+              },
+              {
+                name: "lamports";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "DataEquals";
+            fields: [
+              {
+                name: "account";
+                type: "publicKey";
+              },
+              {
+                name: "offset";
+                type: "u32";
+              },
+              {
+                name: "bytes";
+                type: "bytes";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "SubaccountType";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Derived";
+          },
+          {
+            name: "OwnerInvoker";
+          }
+        ];
+      };
+    }
+  ];
+  events: [
+    {
+      name: "WalletCreateEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "owners";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "threshold";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minimumDelay";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletResizeEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "space";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetOwnersEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "owners";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetOwnerRolesEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "roles";
+          type: "bytes";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "GuardianActionPerformEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "guardianAction";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "actionType";
+          type: {
+            defined: "GuardianActionType";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetGuardiansEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "guardians";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletChangeThresholdEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "threshold";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetValueTiersEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "valueTiers";
+          type: {
+            vec: {
+              defined: "ValueTier";
+            };
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TransactionCreateEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "transaction";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "proposer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "instructions";
+          type: {
+            vec: {
+              defined: "TXInstruction";
+            };
+          };
+          index: false;
+        },
+        {
+          name: "eta";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "expiresAt";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "lookupTables";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "lookupAccounts";
+          type: {
+            vec: {
+              defined: "TXLookupAccountMeta";
+            };
+          };
+          index: false;
+        },
+        {
+          name: "lookupAddresses";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "prerequisites";
+          type: {
+            vec: "u64";
+          };
+          index: false;
+        },
+        {
+          name: "preAssertions";
+          type: {
+            vec: {
+              defined: "TXAssertion";
+            };
+          };
+          index: false;
+        },
+        {
+          name: "postAssertions";
+          type: {
+            vec: {
+              defined: "TXAssertion";
+            };
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TransactionCancelEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "transaction";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "proposer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TransactionExpireEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "transaction";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "expiresAt";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetSequentialEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "sequential";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TransactionApproveEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "transaction";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "owner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TransactionUnapproveEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "transaction";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "owner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TransactionExecuteEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "transaction";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "executor";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "firstStep";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "returnData";
+          type: {
+            vec: {
+              option: {
+                defined: "TXReturnData";
+              };
+            };
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TransactionStepExecuteEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "transaction";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "executor";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "step";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "returnData";
+          type: {
+            option: {
+              defined: "TXReturnData";
+            };
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "OwnerSetSessionEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "expiresAt";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "OwnerSetFrozenEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "frozen";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "EmergencyFreezeEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "freezer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetEmergencyKeyEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "emergencyKey";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetLockDurationEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "lockDuration";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "OwnerHeartbeatEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "owner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetInactivityPeriodEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "inactivityPeriod";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetGuardianPolicyEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "guardianPolicy";
+          type: {
+            option: {
+              defined: "GuardianPolicy";
+            };
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetGuardianQuorumsEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "guardianQuorums";
+          type: {
+            vec: {
+              defined: "GuardianQuorum";
+            };
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletCloseEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "recipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "GlobalThresholdsScheduledEvent";
+      fields: [
+        {
+          name: "globalState";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "thresholds";
+          type: {
+            defined: "GuardianPolicy";
+          };
+          index: false;
+        },
+        {
+          name: "activateAt";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "GlobalThresholdsActivatedEvent";
+      fields: [
+        {
+          name: "globalState";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "thresholds";
+          type: {
+            defined: "GuardianPolicy";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetMinimumDelayEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "minimumDelay";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "effectiveAt";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WalletSetGracePeriodEvent";
+      fields: [
+        {
+          name: "smallet";
+          type: "publicKey";
+          index: true;
+        },
+        {
+          name: "gracePeriod";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "effectiveAt";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidOwner";
+      msg: "The given owner is not part of this smallet.";
+    },
+    {
+      code: 6001;
+      name: "InvalidGlobalAdmin";
+      msg: "The given owner is not the global admin.";
+    },
+    {
+      code: 6002;
+      name: "InvalidDeployer";
+      msg: "Global state initializer does not match the deployer of program";
+    },
+    {
+      code: 6003;
+      name: "InvalidProgramDataAccount";
+      msg: "Invalid ProgramData account";
+    },
+    {
+      code: 6004;
+      name: "InvalidETA";
+      msg: "Estimated execution block must satisfy delay.";
+    },
+    {
+      code: 6005;
+      name: "DelayTooHigh";
+      msg: "Delay greater than the maximum.";
+    },
+    {
+      code: 6006;
+      name: "NotEnoughSigners";
+      msg: "Not enough owners signed this transaction.";
+    },
+    {
+      code: 6007;
+      name: "TransactionIsStale";
+      msg: "Transaction is past the grace period.";
+    },
+    {
+      code: 6008;
+      name: "TransactionNotReady";
+      msg: "Transaction hasn't surpassed time lock.";
+    },
+    {
+      code: 6009;
+      name: "AlreadyExecuted";
+      msg: "The given transaction has already been executed.";
+    },
+    {
+      code: 6010;
+      name: "InvalidThreshold";
+      msg: "Threshold must be less than or equal to the number of owners.";
+    },
+    {
+      code: 6011;
+      name: "OwnerSetChanged";
+      msg: "Owner set has changed since the creation of the transaction.";
+    },
+    {
+      code: 6012;
+      name: "SubaccountOwnerMismatch";
+      msg: "Subaccount does not belong to smallet.";
+    },
+    {
+      code: 6013;
+      name: "BufferFinalized";
+      msg: "Buffer already finalized.";
+    },
+    {
+      code: 6014;
+      name: "BufferBundleNotFound";
+      msg: "Buffer bundle not found.";
+    },
+    {
+      code: 6015;
+      name: "BufferBundleOutOfRange";
+      msg: "Buffer index specified is out of range.";
+    },
+    {
+      code: 6016;
+      name: "BufferBundleNotFinalized";
+      msg: "Buffer has not been finalized.";
+    },
+    {
+      code: 6017;
+      name: "BufferBundleExecuted";
+      msg: "Buffer bundle has already been executed.";
+    },
+    {
+      code: 6018;
+      name: "AccountFrozen";
+      msg: "The Smallet account is frozen.";
+    },
+    {
+      code: 6019;
+      name: "InvalidGuardian";
+      msg: "The given address is not guardian or global admin.";
+    },
+    {
+      code: 6020;
+      name: "IncorrectGuardiansCount";
+      msg: "The given guardians count not matched with smallet guardians count.";
+    },
+    {
+      code: 6021;
+      name: "IncorrectAddressesCount";
+      msg: "The given new addresses count not matched with remaining account of context.";
+    },
+    {
+      code: 6022;
+      name: "ActionExpired";
+      msg: "Passed expiration time for enough sign to perform the guardian action.";
+    },
+    {
+      code: 6023;
+      name: "NotEnoughChangePeriod";
+      msg: "Not enough time passed to change guardians.";
+    },
+    {
+      code: 6024;
+      name: "InvalidGuardianAction";
+      msg: "Guardian action's smallet address not matched with provided smallet account.";
+    },
+    {
+      code: 6025;
+      name: "ActionAlreadyPerformed";
+      msg: "Guardian action is already performed.";
+    },
+    {
+      code: 6026;
+      name: "TooManyValueTiers";
+      msg: "Too many value tiers.";
+    },
+    {
+      code: 6027;
+      name: "InvalidValueTiers";
+      msg: "Value tiers must require a signer and increase in amount per mint.";
+    },
+    {
+      code: 6028;
+      name: "MissingTransferAccount";
+      msg: "Token account of a transfer was not provided.";
+    },
+    {
+      code: 6029;
+      name: "MissingRole";
+      msg: "The given owner does not have the role required for this action.";
+    },
+    {
+      code: 6030;
+      name: "InvalidOwnerRoles";
+      msg: "Owner roles must be given for every owner and leave enough approvers.";
+    },
+    {
+      code: 6031;
+      name: "InvalidFreezer";
+      msg: "The given address is not allowed to freeze the smallet.";
+    },
+    {
+      code: 6032;
+      name: "AccountLocked";
+      msg: "The Smallet account is locked.";
+    },
+    {
+      code: 6033;
+      name: "OwnersStillActive";
+      msg: "Owners have not been inactive for the inactivity period.";
+    },
+    {
+      code: 6034;
+      name: "InvalidGuardianPolicy";
+      msg: "Guardian policy periods must be positive and permyriad at most 10000.";
+    },
+    {
+      code: 6035;
+      name: "InvalidGuardianQuorums";
+      msg: "Guardian quorums must be unique per action type and within bounds.";
+    },
+    {
+      code: 6036;
+      name: "SmalletCapacityExceeded";
+      msg: "The Smallet account does not have enough space for the change.";
+    },
+    {
+      code: 6037;
+      name: "DuplicateAddresses";
+      msg: "Owners and guardians must be unique.";
+    },
+    {
+      code: 6038;
+      name: "DefaultAddress";
+      msg: "The default pubkey cannot be an owner or guardian.";
+    },
+    {
+      code: 6039;
+      name: "ZeroThreshold";
+      msg: "Threshold must be greater than zero.";
+    },
+    {
+      code: 6040;
+      name: "PendingTransactions";
+      msg: "The Smallet still has pending transactions.";
+    },
+    {
+      code: 6041;
+      name: "SubaccountNotEmpty";
+      msg: "A derived subaccount of the Smallet is not empty.";
+    },
+    {
+      code: 6042;
+      name: "SmalletNotClosed";
+      msg: "The Smallet has not been closed.";
+    },
+    {
+      code: 6043;
+      name: "NotSmalletAccount";
+      msg: "The account does not belong to the given Smallet.";
+    },
+    {
+      code: 6044;
+      name: "UnsupportedAccountVersion";
+      msg: "The account layout version is not supported.";
+    },
+    {
+      code: 6045;
+      name: "InvalidDomain";
+      msg: "The GlobalState does not govern the domain of the Smallet.";
+    },
+    {
+      code: 6046;
+      name: "InvalidChangePeriod";
+      msg: "Guardians change period is out of bounds.";
+    },
+    {
+      code: 6047;
+      name: "InvalidActionExpiresTime";
+      msg: "Guardian action expiry is out of bounds.";
+    },
+    {
+      code: 6048;
+      name: "InvalidAgreePermyriad";
+      msg: "Agree permyriad cannot exceed 10000.";
+    },
+    {
+      code: 6049;
+      name: "ActionExpiresBeforeChangePeriod";
+      msg: "Guardian actions cannot expire before the guardians change period.";
+    },
+    {
+      code: 6050;
+      name: "NoPendingThresholds";
+      msg: "No global thresholds are scheduled.";
+    },
+    {
+      code: 6051;
+      name: "ThresholdsNoticePeriodNotPassed";
+      msg: "The notice period of the global thresholds has not passed.";
+    },
+    {
+      code: 6052;
+      name: "MissingLookupTable";
+      msg: "A lookup table of the transaction was not provided.";
+    },
+    {
+      code: 6053;
+      name: "InvalidLookupTable";
+      msg: "Invalid address lookup table.";
+    },
+    {
+      code: 6054;
+      name: "InvalidLookupAccount";
+      msg: "Invalid lookup table account reference.";
+    },
+    {
+      code: 6055;
+      name: "InvalidExecutionStep";
+      msg: "Transaction steps must be executed in order.";
+    },
+    {
+      code: 6056;
+      name: "NotHashCommitted";
+      msg: "The transaction does not commit to a hash of its instructions.";
+    },
+    {
+      code: 6057;
+      name: "InstructionsHashMismatch";
+      msg: "The instructions do not match the committed hash.";
+    },
+    {
+      code: 6058;
+      name: "MissingInstructions";
+      msg: "The instructions of a hash-committed transaction must be supplied.";
+    },
+    {
+      code: 6059;
+      name: "TooManyPrerequisites";
+      msg: "Too many prerequisite transactions.";
+    },
+    {
+      code: 6060;
+      name: "InvalidPrerequisite";
+      msg: "Prerequisite transactions must have been created before.";
+    },
+    {
+      code: 6061;
+      name: "MissingPrerequisite";
+      msg: "A prerequisite transaction was not provided.";
+    },
+    {
+      code: 6062;
+      name: "PrerequisiteNotExecuted";
+      msg: "A prerequisite transaction has not been executed.";
+    },
+    {
+      code: 6063;
+      name: "PreviousTransactionPending";
+      msg: "The previous transaction has not been executed or cancelled.";
+    },
+    {
+      code: 6064;
+      name: "TransactionCancelled";
+      msg: "The transaction has been cancelled.";
+    },
+    {
+      code: 6065;
+      name: "TooManyAssertions";
+      msg: "Too many assertions.";
+    },
+    {
+      code: 6066;
+      name: "MissingAssertionAccount";
+      msg: "An account of an assertion was not provided.";
+    },
+    {
+      code: 6067;
+      name: "AssertionFailed";
+      msg: "An assertion of the transaction does not hold.";
+    },
+    {
+      code: 6068;
+      name: "AssertionsRequireFullExecution";
+      msg: "Transactions with assertions must be executed at once.";
+    },
+    {
+      code: 6069;
+      name: "SimulationComplete";
+      msg: "Simulation complete, every instruction succeeded.";
+    },
+    {
+      code: 6070;
+      name: "TransactionExpired";
+      msg: "Transaction has expired.";
+    },
+    {
+      code: 6071;
+      name: "QuorumDelayExceedsExpiry";
+      msg: "The quorum delay of the action exceeds the action expiry.";
+    },
+    {
+      code: 6072;
+      name: "InvalidDerivedWallet";
+      msg: "The index and bump of a derived wallet must be given together.";
+    },
+    {
+      code: 6073;
+      name: "TransactionNotExpired";
+      msg: "The transaction has not expired.";
+    },
+    {
+      code: 6074;
+      name: "LookupAddressesChanged";
+      msg: "The lookup tables no longer resolve to the accounts of the proposal.";
+    },
+    {
+      code: 6075;
+      name: "InvalidExpiry";
+      msg: "The expiry must be after the transaction can be executed.";
+    }
+  ];
+};
+export const SmalletJSON: SmalletIDL = {
+  version: "0.11.1",
+  name: "smallet",
+  instructions: [
+    {
+      name: "initializeGlobalState",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalState",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "programData",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "domain",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "transferGlobalAdmin",
+      accounts: [
+        {
+          name: "globalState",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "newAdmin",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "setGlobalThresholds",
+      accounts: [
+        {
+          name: "globalState",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "changePeriod",
+          type: {
+            option: "i64",
+          },
+        },
+        {
+          name: "actionExpires",
+          type: {
+            option: "i64",
+          },
+        },
+        {
+          name: "agreePermyriad",
+          type: {
+            option: "u16",
+          },
+        },
+      ],
+    },
+    {
+      name: "activateGlobalThresholds",
+      accounts: [
+        {
+          name: "globalState",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createSmallet",
+      accounts: [
+        {
+          name: "base",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                type: "string",
+                value: "Smallet",
+              },
+              {
+                kind: "account",
+                type: "publicKey",
+                path: "base",
+              },
+            ],
+          },
+        },
+        {
+          name: "globalState",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "bump",
+          type: "u8",
+        },
+        {
+          name: "maxOwners",
+          type: "u8",
+        },
+        {
+          name: "owners",
+          type: {
+            vec: "publicKey",
+          },
+        },
+        {
+          name: "threshold",
+          type: "u64",
+        },
+        {
+          name: "minimumDelay",
+          type: "i64",
+        },
+        {
+          name: "gudiansCount",
+          type: "u8",
+        },
+        {
+          name: "gudians",
+          type: {
+            vec: "publicKey",
+          },
+        },
+        {
+          name: "guardianPolicy",
+          type: {
+            option: {
+              defined: "GuardianPolicy",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "setOwners",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "owners",
+          type: {
+            vec: "publicKey",
+          },
+        },
+      ],
+    },
+    {
+      name: "changeThreshold",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "threshold",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "setValueTiers",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "valueTiers",
+          type: {
+            vec: {
+              defined: "ValueTier",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "setOwnerRoles",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "roles",
+          type: "bytes",
+        },
+      ],
+    },
+    {
+      name: "resizeSmallet",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "maxOwners",
+          type: "u8",
+        },
+        {
+          name: "maxGuardians",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "migrateGlobalState",
+      accounts: [
+        {
+          name: "account",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateSmallet",
+      accounts: [
+        {
+          name: "account",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateTransaction",
+      accounts: [
+        {
+          name: "account",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "smallet",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateGuardianAction",
+      accounts: [
+        {
+          name: "account",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateSubaccountInfo",
+      accounts: [
+        {
+          name: "account",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeSmallet",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "recipient",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "sweepSmalletAccount",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "account",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipient",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createTransaction",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposer",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "bump",
+          type: "u8",
+        },
+        {
+          name: "instructions",
+          type: {
+            vec: {
+              defined: "TXInstruction",
+            },
+          },
+        },
+        {
+          name: "options",
+          type: {
+            defined: "TransactionOptions",
+          },
+        },
+      ],
+    },
+    {
+      name: "createTransactionWithTimelock",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposer",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "bump",
+          type: "u8",
+        },
+        {
+          name: "instructions",
+          type: {
+            vec: {
+              defined: "TXInstruction",
+            },
+          },
+        },
+        {
+          name: "options",
+          type: {
+            defined: "TransactionOptions",
+          },
+        },
+        {
+          name: "eta",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "cancelTransaction",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposer",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "expireTransaction",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "approve",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "unapprove",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "executeTransaction",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "executeHashedTransaction",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "instructions",
+          type: {
+            vec: {
+              defined: "TXInstruction",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "executeTransactionStep",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "step",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "executeHashedTransactionStep",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "step",
+          type: "u64",
+        },
+        {
+          name: "instructions",
+          type: {
+            vec: {
+              defined: "TXInstruction",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "simulateTransaction",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "instructions",
+          type: {
+            option: {
+              vec: {
+                defined: "TXInstruction",
+              },
+            },
+          },
+        },
+        {
+          name: "index",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "bump",
+          type: {
+            option: "u8",
+          },
+        },
+      ],
+    },
+    {
+      name: "executeTransactionDerived",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transaction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "index",
+          type: "u64",
+        },
+        {
+          name: "bump",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "ownerInvokeInstruction",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "index",
+          type: "u64",
+        },
+        {
+          name: "bump",
+          type: "u8",
+        },
+        {
+          name: "ix",
+          type: {
+            defined: "TXInstruction",
+          },
+        },
+      ],
+    },
+    {
+      name: "ownerInvokeInstructionV2",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "index",
+          type: "u64",
+        },
+        {
+          name: "bump",
+          type: "u8",
+        },
+        {
+          name: "invoker",
+          type: "publicKey",
+        },
+        {
+          name: "data",
+          type: "bytes",
+        },
+      ],
+    },
+    {
+      name: "createSubaccountInfo",
+      accounts: [
+        {
+          name: "subaccountInfo",
+          isMut: true,
+          isSigner: false,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                type: "string",
+                value: "SubaccountInfo",
+              },
+              {
+                kind: "arg",
+                type: "publicKey",
+                path: "subaccount",
+              },
+            ],
+          },
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "bump",
+          type: "u8",
+        },
+        {
+          name: "subaccount",
+          type: "publicKey",
+        },
+        {
+          name: "smallet",
+          type: "publicKey",
+        },
+        {
+          name: "index",
+          type: "u64",
+        },
+        {
+          name: "subaccountType",
+          type: {
+            defined: "SubaccountType",
+          },
+        },
+      ],
+    },
+    {
+      name: "setSession",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "expiresAt",
+          type: {
+            option: "i64",
+          },
+        },
+      ],
+    },
+    {
+      name: "heartbeat",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setInactivityPeriod",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "inactivityPeriod",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "setGuardianPolicy",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "guardianPolicy",
+          type: {
+            option: {
+              defined: "GuardianPolicy",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "setGuardianQuorums",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "guardianQuorums",
+          type: {
+            vec: {
+              defined: "GuardianQuorum",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "setFrozen",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "frozen",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "emergencyFreeze",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "freezer",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setEmergencyKey",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "emergencyKey",
+          type: {
+            option: "publicKey",
+          },
+        },
+      ],
+    },
+    {
+      name: "setFrozenAdmin",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "globalState",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "frozen",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "setSequential",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "sequential",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "setMinimumDelay",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "minimumDelay",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "setGracePeriod",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "gracePeriod",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "setLockDuration",
+      accounts: [
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "lockDuration",
           type: "i64",
         },
+      ],
+    },
+    {
+      name: "lockSmallet",
+      accounts: [
+        {
+          name: "guardian",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalState",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createGuardianAction",
+      accounts: [
+        {
+          name: "guardian",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalState",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "guardianAction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "actionType",
+          type: {
+            defined: "GuardianActionType",
+          },
+        },
+        {
+          name: "guardiansCount",
+          type: "u8",
+        },
         {
-          name: "gudiansCount",
+          name: "addressesCount",
           type: "u8",
         },
+      ],
+    },
+    {
+      name: "tryActionWithSign",
+      accounts: [
+        {
+          name: "guardian",
+          isMut: true,
+          isSigner: true,
+        },
         {
-          name: "gudians",
-          type: {
-            vec: "publicKey",
+          name: "globalState",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "smallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "guardianAction",
+          isMut: true,
+          isSigner: false,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                type: "string",
+                value: "GuardianAction",
+              },
+              {
+                kind: "account",
+                type: "publicKey",
+                account: "Smallet",
+                path: "smallet",
+              },
+              {
+                kind: "arg",
+                type: "u64",
+                path: "index",
+              },
+            ],
+          },
+        },
+      ],
+      args: [
+        {
+          name: "index",
+          type: "u64",
+        },
+      ],
+    },
+  ],
+  accounts: [
+    {
+      name: "GlobalState",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "globalAdmin",
+            type: "publicKey",
+          },
+          {
+            name: "guardiansChangePeriod",
+            type: "i64",
+          },
+          {
+            name: "guardiansActionExpiresTime",
+            type: "i64",
+          },
+          {
+            name: "minAgreePermyriad",
+            type: "u16",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "domain",
+            type: "u64",
+          },
+          {
+            name: "pendingThresholds",
+            type: {
+              defined: "GuardianPolicy",
+            },
+          },
+          {
+            name: "thresholdsActivateAt",
+            type: "i64",
+          },
+          {
+            name: "reserved",
+            type: {
+              array: ["u8", 30],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "Smallet",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "base",
+            type: "publicKey",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "threshold",
+            type: "u64",
+          },
+          {
+            name: "minimumDelay",
+            type: "i64",
+          },
+          {
+            name: "gracePeriod",
+            type: "i64",
+          },
+          {
+            name: "ownerSetSeqno",
+            type: "u32",
+          },
+          {
+            name: "numTransactions",
+            type: "u64",
+          },
+          {
+            name: "owners",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "ownerSessions",
+            type: {
+              vec: "i64",
+            },
+          },
+          {
+            name: "guardians",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "numGudianActions",
+            type: "u64",
+          },
+          {
+            name: "frozen",
+            type: "bool",
+          },
+          {
+            name: "locked",
+            type: "bool",
+          },
+          {
+            name: "valueTiers",
+            type: {
+              vec: {
+                defined: "ValueTier",
+              },
+            },
+          },
+          {
+            name: "ownerRoles",
+            type: "bytes",
+          },
+          {
+            name: "emergencyKey",
+            type: "publicKey",
+          },
+          {
+            name: "lockedAt",
+            type: "i64",
+          },
+          {
+            name: "lockDuration",
+            type: "i64",
+          },
+          {
+            name: "lastOwnerActivity",
+            type: "i64",
+          },
+          {
+            name: "inactivityPeriod",
+            type: "i64",
+          },
+          {
+            name: "guardianPolicy",
+            type: {
+              option: {
+                defined: "GuardianPolicy",
+              },
+            },
+          },
+          {
+            name: "guardianQuorums",
+            type: {
+              vec: {
+                defined: "GuardianQuorum",
+              },
+            },
+          },
+          {
+            name: "pendingTransactions",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "domain",
+            type: "u64",
+          },
+          {
+            name: "sequential",
+            type: "bool",
+          },
+          {
+            name: "pendingMinimumDelay",
+            type: "i64",
+          },
+          {
+            name: "minimumDelayChangeAt",
+            type: "i64",
+          },
+          {
+            name: "pendingGracePeriod",
+            type: "i64",
+          },
+          {
+            name: "gracePeriodChangeAt",
+            type: "i64",
+          },
+          {
+            name: "reserved",
+            type: {
+              array: ["u64", 10],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "GuardianAction",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "smallet",
+            type: "publicKey",
+          },
+          {
+            name: "actionRequestedTime",
+            type: "i64",
+          },
+          {
+            name: "actionType",
+            type: {
+              defined: "GuardianActionType",
+            },
+          },
+          {
+            name: "performed",
+            type: "bool",
+          },
+          {
+            name: "agreedSigns",
+            type: {
+              vec: "bool",
+            },
+          },
+          {
+            name: "addresses",
+            type: {
+              vec: "publicKey",
+            },
           },
-        },
-      ],
-    },
-    {
-      name: "setOwners",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: true,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "owners",
-          type: {
-            vec: "publicKey",
+          {
+            name: "version",
+            type: "u8",
           },
-        },
-      ],
-    },
-    {
-      name: "changeThreshold",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: true,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "threshold",
-          type: "u64",
-        },
-      ],
+        ],
+      },
     },
     {
-      name: "createTransaction",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "transaction",
-          isMut: true,
-          isSigner: false,
-          pda: {
-            seeds: [
-              {
-                kind: "const",
-                type: "string",
-                value: "Transaction",
-              },
-              {
-                kind: "account",
-                type: "publicKey",
-                account: "Smallet",
-                path: "smallet",
-              },
-              {
-                kind: "account",
-                type: "u64",
-                account: "Smallet",
-                path: "smallet.num_transactions",
+      name: "Transaction",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "smallet",
+            type: "publicKey",
+          },
+          {
+            name: "index",
+            type: "u64",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "proposer",
+            type: "publicKey",
+          },
+          {
+            name: "instructions",
+            type: {
+              vec: {
+                defined: "TXInstruction",
               },
-            ],
+            },
           },
-        },
-        {
-          name: "proposer",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "bump",
-          type: "u8",
-        },
-        {
-          name: "instructions",
-          type: {
-            vec: {
-              defined: "TXInstruction",
+          {
+            name: "signers",
+            type: {
+              vec: "bool",
             },
           },
-        },
-      ],
-    },
-    {
-      name: "createTransactionWithTimelock",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "transaction",
-          isMut: true,
-          isSigner: false,
-          pda: {
-            seeds: [
-              {
-                kind: "const",
-                type: "string",
-                value: "Transaction",
+          {
+            name: "ownerSetSeqno",
+            type: "u32",
+          },
+          {
+            name: "eta",
+            type: "i64",
+          },
+          {
+            name: "executor",
+            type: "publicKey",
+          },
+          {
+            name: "executedAt",
+            type: "i64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "executedSteps",
+            type: "u64",
+          },
+          {
+            name: "instructionsHash",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "cancelledAt",
+            type: "i64",
+          },
+          {
+            name: "expiresAt",
+            type: "i64",
+          },
+          {
+            name: "requiredThreshold",
+            type: "u64",
+          },
+          {
+            name: "expiredAt",
+            type: "i64",
+          },
+          {
+            name: "lookupTables",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "lookupAccounts",
+            type: {
+              vec: {
+                defined: "TXLookupAccountMeta",
               },
-              {
-                kind: "account",
-                type: "publicKey",
-                account: "Smallet",
-                path: "smallet",
+            },
+          },
+          {
+            name: "prerequisites",
+            type: {
+              vec: "u64",
+            },
+          },
+          {
+            name: "preAssertions",
+            type: {
+              vec: {
+                defined: "TXAssertion",
               },
-              {
-                kind: "account",
-                type: "u64",
-                account: "Smallet",
-                path: "smallet.num_transactions",
+            },
+          },
+          {
+            name: "postAssertions",
+            type: {
+              vec: {
+                defined: "TXAssertion",
               },
-            ],
+            },
           },
-        },
-        {
-          name: "proposer",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "bump",
-          type: "u8",
-        },
-        {
-          name: "instructions",
-          type: {
-            vec: {
-              defined: "TXInstruction",
+          {
+            name: "lookupAddressesHash",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SubaccountInfo",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "smallet",
+            type: "publicKey",
+          },
+          {
+            name: "subaccountType",
+            type: {
+              defined: "SubaccountType",
             },
           },
-        },
-        {
-          name: "eta",
-          type: "i64",
-        },
-      ],
+          {
+            name: "index",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
     },
+  ],
+  types: [
     {
-      name: "approve",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "transaction",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "owner",
-          isMut: false,
-          isSigner: true,
-        },
-      ],
-      args: [],
+      name: "SimulationReport",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "executed",
+            type: "u64",
+          },
+          {
+            name: "failedPreAssertion",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "failedPostAssertion",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "lamportDeltas",
+            type: {
+              vec: {
+                defined: "AccountDelta",
+              },
+            },
+          },
+          {
+            name: "tokenDeltas",
+            type: {
+              vec: {
+                defined: "AccountDelta",
+              },
+            },
+          },
+        ],
+      },
     },
     {
-      name: "unapprove",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "transaction",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "owner",
-          isMut: false,
-          isSigner: true,
-        },
-      ],
-      args: [],
+      name: "AccountDelta",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "account",
+            type: "publicKey",
+          },
+          {
+            name: "delta",
+            type: "i128",
+          },
+        ],
+      },
     },
     {
-      name: "executeTransaction",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "transaction",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "owner",
-          isMut: false,
-          isSigner: true,
-        },
-      ],
-      args: [],
+      name: "GuardianPolicy",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "changePeriod",
+            type: "i64",
+          },
+          {
+            name: "actionExpiresTime",
+            type: "i64",
+          },
+          {
+            name: "minAgreePermyriad",
+            type: "u16",
+          },
+        ],
+      },
     },
     {
-      name: "executeTransactionDerived",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "transaction",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "owner",
-          isMut: false,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "index",
-          type: "u64",
-        },
-        {
-          name: "bump",
-          type: "u8",
-        },
-      ],
+      name: "GuardianQuorum",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "actionKind",
+            type: {
+              defined: "GuardianActionKind",
+            },
+          },
+          {
+            name: "minAgreePermyriad",
+            type: "u16",
+          },
+          {
+            name: "delay",
+            type: "i64",
+          },
+        ],
+      },
     },
     {
-      name: "ownerInvokeInstruction",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "owner",
-          isMut: false,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "index",
-          type: "u64",
-        },
-        {
-          name: "bump",
-          type: "u8",
-        },
-        {
-          name: "ix",
-          type: {
-            defined: "TXInstruction",
+      name: "ValueTier",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: "publicKey",
           },
-        },
-      ],
+          {
+            name: "maxAmount",
+            type: "u64",
+          },
+          {
+            name: "threshold",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "ownerInvokeInstructionV2",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "owner",
-          isMut: false,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "index",
-          type: "u64",
-        },
-        {
-          name: "bump",
-          type: "u8",
-        },
-        {
-          name: "invoker",
-          type: "publicKey",
-        },
-        {
-          name: "data",
-          type: "bytes",
-        },
-      ],
+      name: "TXInstruction",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "programId",
+            type: "publicKey",
+          },
+          {
+            name: "keys",
+            type: {
+              vec: {
+                defined: "TXAccountMeta",
+              },
+            },
+          },
+          {
+            name: "data",
+            type: "bytes",
+          },
+        ],
+      },
     },
     {
-      name: "createSubaccountInfo",
-      accounts: [
-        {
-          name: "subaccountInfo",
-          isMut: true,
-          isSigner: false,
-          pda: {
-            seeds: [
-              {
-                kind: "const",
-                type: "string",
-                value: "SubaccountInfo",
-              },
-              {
-                kind: "arg",
-                type: "publicKey",
-                path: "subaccount",
-              },
-            ],
+      name: "TXReturnData",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "programId",
+            type: "publicKey",
           },
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "bump",
-          type: "u8",
-        },
-        {
-          name: "subaccount",
-          type: "publicKey",
-        },
-        {
-          name: "smallet",
-          type: "publicKey",
-        },
-        {
-          name: "index",
-          type: "u64",
-        },
-        {
-          name: "subaccountType",
-          type: {
-            defined: "SubaccountType",
+          {
+            name: "data",
+            type: "bytes",
           },
-        },
-      ],
+        ],
+      },
     },
     {
-      name: "setSession",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "owner",
-          isMut: false,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "expiresAt",
-          type: {
-            option: "i64",
+      name: "TransactionOptions",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "prerequisites",
+            type: {
+              vec: "u64",
+            },
           },
-        },
-      ],
+          {
+            name: "lookupTables",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "lookupAccounts",
+            type: {
+              vec: {
+                defined: "TXLookupAccountMeta",
+              },
+            },
+          },
+          {
+            name: "preAssertions",
+            type: {
+              vec: {
+                defined: "TXAssertion",
+              },
+            },
+          },
+          {
+            name: "postAssertions",
+            type: {
+              vec: {
+                defined: "TXAssertion",
+              },
+            },
+          },
+          {
+            name: "hashCommitted",
+            type: "bool",
+          },
+          {
+            name: "expiresAt",
+            type: {
+              option: "i64",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "setFrozen",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: true,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "frozen",
-          type: "bool",
-        },
-      ],
+      name: "TXLookupAccountMeta",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "instructionIndex",
+            type: "u8",
+          },
+          {
+            name: "tableIndex",
+            type: "u8",
+          },
+          {
+            name: "addressIndex",
+            type: "u8",
+          },
+          {
+            name: "isWritable",
+            type: "bool",
+          },
+          {
+            name: "position",
+            type: "u8",
+          },
+        ],
+      },
     },
     {
-      name: "setFrozenAdmin",
-      accounts: [
-        {
-          name: "smallet",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "globalState",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "admin",
-          isMut: true,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "frozen",
-          type: "bool",
-        },
-      ],
+      name: "TXAccountMeta",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "pubkey",
+            type: "publicKey",
+          },
+          {
+            name: "isSigner",
+            type: "bool",
+          },
+          {
+            name: "isWritable",
+            type: "bool",
+          },
+        ],
+      },
     },
     {
-      name: "lockSmallet",
-      accounts: [
-        {
-          name: "guadian",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "globalState",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "smallet",
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: "GlobalStateV0",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "globalAdmin",
+            type: "publicKey",
+          },
+          {
+            name: "guardiansChangePeriod",
+            type: "i64",
+          },
+          {
+            name: "guardiansActionExpiresTime",
+            type: "i64",
+          },
+          {
+            name: "minAgreePermyriad",
+            type: "u16",
+          },
+        ],
+      },
     },
     {
-      name: "createGuadianAction",
-      accounts: [
-        {
-          name: "guadian",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "globalState",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "smallet",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "guadianAction",
-          isMut: true,
-          isSigner: false,
-          pda: {
-            seeds: [
-              {
-                kind: "const",
-                type: "string",
-                value: "GuadianAction",
-              },
-              {
-                kind: "account",
-                type: "publicKey",
-                account: "Smallet",
-                path: "smallet",
-              },
-              {
-                kind: "account",
-                type: "u64",
-                account: "Smallet",
-                path: "smallet.num_gudian_actions",
-              },
-            ],
+      name: "SmalletV0",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "base",
+            type: "publicKey",
           },
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "actionType",
-          type: {
-            defined: "GuadianActionType",
+          {
+            name: "bump",
+            type: "u8",
           },
-        },
-        {
-          name: "guadiansCount",
-          type: "u8",
-        },
-        {
-          name: "addressesCount",
-          type: "u8",
-        },
-      ],
+          {
+            name: "threshold",
+            type: "u64",
+          },
+          {
+            name: "minimumDelay",
+            type: "i64",
+          },
+          {
+            name: "gracePeriod",
+            type: "i64",
+          },
+          {
+            name: "ownerSetSeqno",
+            type: "u32",
+          },
+          {
+            name: "numTransactions",
+            type: "u64",
+          },
+          {
+            name: "owners",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "ownerSessions",
+            type: {
+              vec: "i64",
+            },
+          },
+          {
+            name: "guardians",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "numGudianActions",
+            type: "u64",
+          },
+          {
+            name: "frozen",
+            type: "bool",
+          },
+          {
+            name: "locked",
+            type: "bool",
+          },
+          {
+            name: "reserved",
+            type: {
+              array: ["u64", 16],
+            },
+          },
+        ],
+      },
     },
     {
-      name: "tryActionWithSign",
-      accounts: [
-        {
-          name: "guadian",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "globalState",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "smallet",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "guadianAction",
-          isMut: true,
-          isSigner: false,
-          pda: {
-            seeds: [
-              {
-                kind: "const",
-                type: "string",
-                value: "GuadianAction",
-              },
-              {
-                kind: "account",
-                type: "publicKey",
-                account: "Smallet",
-                path: "smallet",
+      name: "SmalletV1",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "base",
+            type: "publicKey",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "threshold",
+            type: "u64",
+          },
+          {
+            name: "minimumDelay",
+            type: "i64",
+          },
+          {
+            name: "gracePeriod",
+            type: "i64",
+          },
+          {
+            name: "ownerSetSeqno",
+            type: "u32",
+          },
+          {
+            name: "numTransactions",
+            type: "u64",
+          },
+          {
+            name: "owners",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "ownerSessions",
+            type: {
+              vec: "i64",
+            },
+          },
+          {
+            name: "guardians",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "numGudianActions",
+            type: "u64",
+          },
+          {
+            name: "frozen",
+            type: "bool",
+          },
+          {
+            name: "locked",
+            type: "bool",
+          },
+          {
+            name: "valueTiers",
+            type: {
+              vec: {
+                defined: "ValueTier",
               },
-              {
-                kind: "arg",
-                type: "u64",
-                path: "index",
+            },
+          },
+          {
+            name: "ownerRoles",
+            type: "bytes",
+          },
+          {
+            name: "emergencyKey",
+            type: "publicKey",
+          },
+          {
+            name: "lockedAt",
+            type: "i64",
+          },
+          {
+            name: "lockDuration",
+            type: "i64",
+          },
+          {
+            name: "lastOwnerActivity",
+            type: "i64",
+          },
+          {
+            name: "inactivityPeriod",
+            type: "i64",
+          },
+          {
+            name: "guardianPolicy",
+            type: {
+              option: {
+                defined: "GuardianPolicy",
               },
-            ],
+            },
           },
-        },
-      ],
-      args: [
-        {
-          name: "index",
-          type: "u64",
-        },
-      ],
+          {
+            name: "guardianQuorums",
+            type: {
+              vec: {
+                defined: "GuardianQuorum",
+              },
+            },
+          },
+          {
+            name: "pendingTransactions",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
     },
-  ],
-  accounts: [
     {
-      name: "GlobalState",
+      name: "TransactionV0",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "globalAdmin",
+            name: "smallet",
             type: "publicKey",
           },
           {
-            name: "guadiansChangePeriod",
-            type: "i64",
+            name: "index",
+            type: "u64",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "proposer",
+            type: "publicKey",
+          },
+          {
+            name: "instructions",
+            type: {
+              vec: {
+                defined: "TXInstruction",
+              },
+            },
+          },
+          {
+            name: "signers",
+            type: {
+              vec: "bool",
+            },
+          },
+          {
+            name: "ownerSetSeqno",
+            type: "u32",
           },
           {
-            name: "guadiansActionExpiresTime",
+            name: "eta",
             type: "i64",
           },
           {
-            name: "minAgreePermyriad",
-            type: "u16",
+            name: "executor",
+            type: "publicKey",
+          },
+          {
+            name: "executedAt",
+            type: "i64",
           },
         ],
       },
     },
     {
-      name: "Smallet",
+      name: "TransactionV1",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "base",
-            type: "publicKey",
+            name: "v0",
+            type: {
+              defined: "TransactionV0",
+            },
           },
           {
-            name: "bump",
+            name: "version",
             type: "u8",
           },
           {
-            name: "threshold",
-            type: "u64",
+            name: "reserved",
+            type: {
+              array: ["u64", 8],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "TXLookupAccountMetaV0",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "instructionIndex",
+            type: "u8",
           },
           {
-            name: "minimumDelay",
-            type: "i64",
+            name: "tableIndex",
+            type: "u8",
           },
           {
-            name: "gracePeriod",
-            type: "i64",
+            name: "addressIndex",
+            type: "u8",
           },
           {
-            name: "ownerSetSeqno",
-            type: "u32",
+            name: "isWritable",
+            type: "bool",
           },
+        ],
+      },
+    },
+    {
+      name: "TransactionV2",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "numTransactions",
-            type: "u64",
+            name: "v1",
+            type: {
+              defined: "TransactionV1",
+            },
           },
           {
-            name: "owners",
+            name: "lookupTables",
             type: {
               vec: "publicKey",
             },
           },
           {
-            name: "ownerSessions",
+            name: "lookupAccounts",
             type: {
-              vec: "i64",
+              vec: {
+                defined: "TXLookupAccountMetaV0",
+              },
             },
           },
+        ],
+      },
+    },
+    {
+      name: "TransactionV3",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "guadians",
+            name: "v0",
             type: {
-              vec: "publicKey",
+              defined: "TransactionV0",
             },
           },
           {
-            name: "numGudianActions",
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "executedSteps",
             type: "u64",
           },
           {
-            name: "frozen",
-            type: "bool",
+            name: "instructionsHash",
+            type: {
+              array: ["u8", 32],
+            },
           },
           {
-            name: "locked",
-            type: "bool",
+            name: "cancelledAt",
+            type: "i64",
           },
           {
-            name: "reserved",
+            name: "expiresAt",
+            type: "i64",
+          },
+          {
+            name: "requiredThreshold",
+            type: "u64",
+          },
+          {
+            name: "expiredAt",
+            type: "i64",
+          },
+          {
+            name: "lookupTables",
             type: {
-              array: ["u64", 16],
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "lookupAccounts",
+            type: {
+              vec: {
+                defined: "TXLookupAccountMeta",
+              },
+            },
+          },
+          {
+            name: "prerequisites",
+            type: {
+              vec: "u64",
+            },
+          },
+          {
+            name: "preAssertions",
+            type: {
+              vec: {
+                defined: "TXAssertion",
+              },
+            },
+          },
+          {
+            name: "postAssertions",
+            type: {
+              vec: {
+                defined: "TXAssertion",
+              },
             },
           },
         ],
       },
     },
     {
-      name: "GuadianAction",
+      name: "GuardianActionV0",
       type: {
         kind: "struct",
         fields: [
@@ -2280,7 +6150,7 @@ export const SmalletJSON: SmalletIDL = {
           {
             name: "actionType",
             type: {
-              defined: "GuadianActionType",
+              defined: "GuardianActionType",
             },
           },
           {
@@ -2303,7 +6173,7 @@ export const SmalletJSON: SmalletIDL = {
       },
     },
     {
-      name: "Transaction",
+      name: "SubaccountInfoV0",
       type: {
         kind: "struct",
         fields: [
@@ -2311,157 +6181,654 @@ export const SmalletJSON: SmalletIDL = {
             name: "smallet",
             type: "publicKey",
           },
+          {
+            name: "subaccountType",
+            type: {
+              defined: "SubaccountType",
+            },
+          },
           {
             name: "index",
             type: "u64",
           },
+        ],
+      },
+    },
+    {
+      name: "GuardianActionType",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "NoAction",
+          },
           {
-            name: "bump",
-            type: "u8",
+            name: "UnlockSmallet",
           },
           {
-            name: "proposer",
-            type: "publicKey",
+            name: "SetOwners",
           },
           {
-            name: "instructions",
-            type: {
-              vec: {
-                defined: "TXInstruction",
+            name: "SetGuardians",
+          },
+          {
+            name: "InheritOwners",
+          },
+          {
+            name: "SetThreshold",
+            fields: [
+              {
+                name: "threshold",
+                type: "u64",
               },
-            },
+            ],
           },
           {
-            name: "signers",
-            type: {
-              vec: "bool",
-            },
+            name: "ReplaceOwner",
+            fields: [
+              {
+                name: "old",
+                type: "publicKey",
+              },
+              {
+                name: "new",
+                type: "publicKey",
+              },
+            ],
           },
+        ],
+      },
+    },
+    {
+      name: "GuardianActionKind",
+      type: {
+        kind: "enum",
+        variants: [
           {
-            name: "ownerSetSeqno",
-            type: "u32",
+            name: "NoAction",
           },
           {
-            name: "eta",
-            type: "i64",
+            name: "UnlockSmallet",
           },
           {
-            name: "executor",
-            type: "publicKey",
+            name: "SetOwners",
+          },
+          {
+            name: "SetGuardians",
+          },
+          {
+            name: "InheritOwners",
+          },
+          {
+            name: "SetThreshold",
+          },
+          {
+            name: "ReplaceOwner",
+          },
+        ],
+      },
+    },
+    {
+      name: "TXAssertion",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "MinTokenBalance",
+            fields: [
+              {
+                name: "account",
+                type: "publicKey",
+              },
+              {
+                name: "amount",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "MinLamports",
+            fields: [
+              {
+                name: "account",
+                type: "publicKey",
+              },
+              {
+                name: "lamports",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "MaxLamportsDecrease",
+            fields: [
+              {
+                name: "account",
+                type: "publicKey",
+              },
+              {
+                name: "lamports",
+                type: "u64",
+              },
+            ],
           },
           {
-            name: "executedAt",
-            type: "i64",
+            name: "DataEquals",
+            fields: [
+              {
+                name: "account",
+                type: "publicKey",
+              },
+              {
+                name: "offset",
+                type: "u32",
+              },
+              {
+                name: "bytes",
+                type: "bytes",
+              },
+            ],
           },
         ],
       },
     },
     {
-      name: "SubaccountInfo",
+      name: "SubaccountType",
       type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "smallet",
-            type: "publicKey",
-          },
+        kind: "enum",
+        variants: [
           {
-            name: "subaccountType",
-            type: {
-              defined: "SubaccountType",
-            },
+            name: "Derived",
           },
           {
-            name: "index",
-            type: "u64",
+            name: "OwnerInvoker",
           },
         ],
       },
     },
   ],
-  types: [
+  events: [
     {
-      name: "TXInstruction",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "programId",
-            type: "publicKey",
+      name: "WalletCreateEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "owners",
+          type: {
+            vec: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "threshold",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "minimumDelay",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "WalletResizeEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "space",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "WalletSetOwnersEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "owners",
+          type: {
+            vec: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "WalletSetOwnerRolesEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "roles",
+          type: "bytes",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "GuardianActionPerformEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "guardianAction",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "actionType",
+          type: {
+            defined: "GuardianActionType",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "WalletSetGuardiansEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "guardians",
+          type: {
+            vec: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "WalletChangeThresholdEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "threshold",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "WalletSetValueTiersEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "valueTiers",
+          type: {
+            vec: {
+              defined: "ValueTier",
+            },
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TransactionCreateEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "transaction",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "proposer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "instructions",
+          type: {
+            vec: {
+              defined: "TXInstruction",
+            },
+          },
+          index: false,
+        },
+        {
+          name: "eta",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "expiresAt",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "lookupTables",
+          type: {
+            vec: "publicKey",
           },
-          {
-            name: "keys",
-            type: {
-              vec: {
-                defined: "TXAccountMeta",
-              },
+          index: false,
+        },
+        {
+          name: "lookupAccounts",
+          type: {
+            vec: {
+              defined: "TXLookupAccountMeta",
             },
           },
-          {
-            name: "data",
-            type: "bytes",
+          index: false,
+        },
+        {
+          name: "lookupAddresses",
+          type: {
+            vec: "publicKey",
           },
-        ],
-      },
-    },
-    {
-      name: "TXAccountMeta",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "pubkey",
-            type: "publicKey",
+          index: false,
+        },
+        {
+          name: "prerequisites",
+          type: {
+            vec: "u64",
           },
-          {
-            name: "isSigner",
-            type: "bool",
+          index: false,
+        },
+        {
+          name: "preAssertions",
+          type: {
+            vec: {
+              defined: "TXAssertion",
+            },
           },
-          {
-            name: "isWritable",
-            type: "bool",
+          index: false,
+        },
+        {
+          name: "postAssertions",
+          type: {
+            vec: {
+              defined: "TXAssertion",
+            },
           },
-        ],
-      },
+          index: false,
+        },
+      ],
     },
     {
-      name: "GuadianActionType",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "NoAction",
-          },
-          {
-            name: "UnlockSmallet",
-          },
-          {
-            name: "SetOwners",
-          },
-          {
-            name: "SetGuadians",
-          },
-        ],
-      },
+      name: "TransactionCancelEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "transaction",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "proposer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "SubaccountType",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "Derived",
+      name: "TransactionExpireEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "transaction",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "expiresAt",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "WalletSetSequentialEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "sequential",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TransactionApproveEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "transaction",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "owner",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TransactionUnapproveEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "transaction",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "owner",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TransactionExecuteEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "transaction",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "executor",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "firstStep",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "returnData",
+          type: {
+            vec: {
+              option: {
+                defined: "TXReturnData",
+              },
+            },
           },
-          {
-            name: "OwnerInvoker",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TransactionStepExecuteEvent",
+      fields: [
+        {
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "transaction",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "executor",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "step",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "returnData",
+          type: {
+            option: {
+              defined: "TXReturnData",
+            },
           },
-        ],
-      },
+          index: false,
+        },
+      ],
     },
-  ],
-  events: [
     {
-      name: "WalletCreateEvent",
+      name: "OwnerSetSessionEvent",
       fields: [
         {
           name: "smallet",
@@ -2469,20 +6836,28 @@ export const SmalletJSON: SmalletIDL = {
           index: true,
         },
         {
-          name: "owners",
-          type: {
-            vec: "publicKey",
-          },
+          name: "expiresAt",
+          type: "i64",
           index: false,
         },
         {
-          name: "threshold",
-          type: "u64",
+          name: "timestamp",
+          type: "i64",
           index: false,
         },
+      ],
+    },
+    {
+      name: "OwnerSetFrozenEvent",
+      fields: [
         {
-          name: "minimumDelay",
-          type: "i64",
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "frozen",
+          type: "bool",
           index: false,
         },
         {
@@ -2493,7 +6868,7 @@ export const SmalletJSON: SmalletIDL = {
       ],
     },
     {
-      name: "WalletSetOwnersEvent",
+      name: "EmergencyFreezeEvent",
       fields: [
         {
           name: "smallet",
@@ -2501,10 +6876,8 @@ export const SmalletJSON: SmalletIDL = {
           index: true,
         },
         {
-          name: "owners",
-          type: {
-            vec: "publicKey",
-          },
+          name: "freezer",
+          type: "publicKey",
           index: false,
         },
         {
@@ -2515,7 +6888,7 @@ export const SmalletJSON: SmalletIDL = {
       ],
     },
     {
-      name: "WalletChangeThresholdEvent",
+      name: "WalletSetEmergencyKeyEvent",
       fields: [
         {
           name: "smallet",
@@ -2523,8 +6896,10 @@ export const SmalletJSON: SmalletIDL = {
           index: true,
         },
         {
-          name: "threshold",
-          type: "u64",
+          name: "emergencyKey",
+          type: {
+            option: "publicKey",
+          },
           index: false,
         },
         {
@@ -2535,7 +6910,7 @@ export const SmalletJSON: SmalletIDL = {
       ],
     },
     {
-      name: "TransactionCreateEvent",
+      name: "WalletSetLockDurationEvent",
       fields: [
         {
           name: "smallet",
@@ -2543,26 +6918,47 @@ export const SmalletJSON: SmalletIDL = {
           index: true,
         },
         {
-          name: "transaction",
+          name: "lockDuration",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "OwnerHeartbeatEvent",
+      fields: [
+        {
+          name: "smallet",
           type: "publicKey",
           index: true,
         },
         {
-          name: "proposer",
+          name: "owner",
           type: "publicKey",
           index: false,
         },
         {
-          name: "instructions",
-          type: {
-            vec: {
-              defined: "TXInstruction",
-            },
-          },
+          name: "timestamp",
+          type: "i64",
           index: false,
         },
+      ],
+    },
+    {
+      name: "WalletSetInactivityPeriodEvent",
+      fields: [
         {
-          name: "eta",
+          name: "smallet",
+          type: "publicKey",
+          index: true,
+        },
+        {
+          name: "inactivityPeriod",
           type: "i64",
           index: false,
         },
@@ -2574,7 +6970,7 @@ export const SmalletJSON: SmalletIDL = {
       ],
     },
     {
-      name: "TransactionApproveEvent",
+      name: "WalletSetGuardianPolicyEvent",
       fields: [
         {
           name: "smallet",
@@ -2582,13 +6978,12 @@ export const SmalletJSON: SmalletIDL = {
           index: true,
         },
         {
-          name: "transaction",
-          type: "publicKey",
-          index: true,
-        },
-        {
-          name: "owner",
-          type: "publicKey",
+          name: "guardianPolicy",
+          type: {
+            option: {
+              defined: "GuardianPolicy",
+            },
+          },
           index: false,
         },
         {
@@ -2599,7 +6994,7 @@ export const SmalletJSON: SmalletIDL = {
       ],
     },
     {
-      name: "TransactionUnapproveEvent",
+      name: "WalletSetGuardianQuorumsEvent",
       fields: [
         {
           name: "smallet",
@@ -2607,12 +7002,31 @@ export const SmalletJSON: SmalletIDL = {
           index: true,
         },
         {
-          name: "transaction",
+          name: "guardianQuorums",
+          type: {
+            vec: {
+              defined: "GuardianQuorum",
+            },
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "WalletCloseEvent",
+      fields: [
+        {
+          name: "smallet",
           type: "publicKey",
           index: true,
         },
         {
-          name: "owner",
+          name: "recipient",
           type: "publicKey",
           index: false,
         },
@@ -2624,21 +7038,45 @@ export const SmalletJSON: SmalletIDL = {
       ],
     },
     {
-      name: "TransactionExecuteEvent",
+      name: "GlobalThresholdsScheduledEvent",
       fields: [
         {
-          name: "smallet",
+          name: "globalState",
           type: "publicKey",
           index: true,
         },
         {
-          name: "transaction",
+          name: "thresholds",
+          type: {
+            defined: "GuardianPolicy",
+          },
+          index: false,
+        },
+        {
+          name: "activateAt",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "GlobalThresholdsActivatedEvent",
+      fields: [
+        {
+          name: "globalState",
           type: "publicKey",
           index: true,
         },
         {
-          name: "executor",
-          type: "publicKey",
+          name: "thresholds",
+          type: {
+            defined: "GuardianPolicy",
+          },
           index: false,
         },
         {
@@ -2649,7 +7087,7 @@ export const SmalletJSON: SmalletIDL = {
       ],
     },
     {
-      name: "OwnerSetSessionEvent",
+      name: "WalletSetMinimumDelayEvent",
       fields: [
         {
           name: "smallet",
@@ -2657,7 +7095,12 @@ export const SmalletJSON: SmalletIDL = {
           index: true,
         },
         {
-          name: "expiresAt",
+          name: "minimumDelay",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "effectiveAt",
           type: "i64",
           index: false,
         },
@@ -2669,7 +7112,7 @@ export const SmalletJSON: SmalletIDL = {
       ],
     },
     {
-      name: "OwnerSetFrozenEvent",
+      name: "WalletSetGracePeriodEvent",
       fields: [
         {
           name: "smallet",
@@ -2677,8 +7120,13 @@ export const SmalletJSON: SmalletIDL = {
           index: true,
         },
         {
-          name: "frozen",
-          type: "bool",
+          name: "gracePeriod",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "effectiveAt",
+          type: "i64",
           index: false,
         },
         {
@@ -2787,13 +7235,13 @@ export const SmalletJSON: SmalletIDL = {
     },
     {
       code: 6019,
-      name: "InvalidGuadian",
-      msg: "The given address is not guadian or global admin.",
+      name: "InvalidGuardian",
+      msg: "The given address is not guardian or global admin.",
     },
     {
       code: 6020,
-      name: "IncorrectGuadiansCount",
-      msg: "The given guadians count not matched with smallet guadians count.",
+      name: "IncorrectGuardiansCount",
+      msg: "The given guardians count not matched with smallet guardians count.",
     },
     {
       code: 6021,
@@ -2803,22 +7251,272 @@ export const SmalletJSON: SmalletIDL = {
     {
       code: 6022,
       name: "ActionExpired",
-      msg: "Passed expiration time for enough sign to perform the guadian action.",
+      msg: "Passed expiration time for enough sign to perform the guardian action.",
     },
     {
       code: 6023,
       name: "NotEnoughChangePeriod",
-      msg: "Not enough time passed to change guadians.",
+      msg: "Not enough time passed to change guardians.",
     },
     {
       code: 6024,
-      name: "InvalidGuadianAction",
-      msg: "Guadian action's smallet address not matched with provided smallet account.",
+      name: "InvalidGuardianAction",
+      msg: "Guardian action's smallet address not matched with provided smallet account.",
     },
     {
       code: 6025,
       name: "ActionAlreadyPerformed",
-      msg: "Guadian action is already performed.",
+      msg: "Guardian action is already performed.",
+    },
+    {
+      code: 6026,
+      name: "TooManyValueTiers",
+      msg: "Too many value tiers.",
+    },
+    {
+      code: 6027,
+      name: "InvalidValueTiers",
+      msg: "Value tiers must require a signer and increase in amount per mint.",
+    },
+    {
+      code: 6028,
+      name: "MissingTransferAccount",
+      msg: "Token account of a transfer was not provided.",
+    },
+    {
+      code: 6029,
+      name: "MissingRole",
+      msg: "The given owner does not have the role required for this action.",
+    },
+    {
+      code: 6030,
+      name: "InvalidOwnerRoles",
+      msg: "Owner roles must be given for every owner and leave enough approvers.",
+    },
+    {
+      code: 6031,
+      name: "InvalidFreezer",
+      msg: "The given address is not allowed to freeze the smallet.",
+    },
+    {
+      code: 6032,
+      name: "AccountLocked",
+      msg: "The Smallet account is locked.",
+    },
+    {
+      code: 6033,
+      name: "OwnersStillActive",
+      msg: "Owners have not been inactive for the inactivity period.",
+    },
+    {
+      code: 6034,
+      name: "InvalidGuardianPolicy",
+      msg: "Guardian policy periods must be positive and permyriad at most 10000.",
+    },
+    {
+      code: 6035,
+      name: "InvalidGuardianQuorums",
+      msg: "Guardian quorums must be unique per action type and within bounds.",
+    },
+    {
+      code: 6036,
+      name: "SmalletCapacityExceeded",
+      msg: "The Smallet account does not have enough space for the change.",
+    },
+    {
+      code: 6037,
+      name: "DuplicateAddresses",
+      msg: "Owners and guardians must be unique.",
+    },
+    {
+      code: 6038,
+      name: "DefaultAddress",
+      msg: "The default pubkey cannot be an owner or guardian.",
+    },
+    {
+      code: 6039,
+      name: "ZeroThreshold",
+      msg: "Threshold must be greater than zero.",
+    },
+    {
+      code: 6040,
+      name: "PendingTransactions",
+      msg: "The Smallet still has pending transactions.",
+    },
+    {
+      code: 6041,
+      name: "SubaccountNotEmpty",
+      msg: "A derived subaccount of the Smallet is not empty.",
+    },
+    {
+      code: 6042,
+      name: "SmalletNotClosed",
+      msg: "The Smallet has not been closed.",
+    },
+    {
+      code: 6043,
+      name: "NotSmalletAccount",
+      msg: "The account does not belong to the given Smallet.",
+    },
+    {
+      code: 6044,
+      name: "UnsupportedAccountVersion",
+      msg: "The account layout version is not supported.",
+    },
+    {
+      code: 6045,
+      name: "InvalidDomain",
+      msg: "The GlobalState does not govern the domain of the Smallet.",
+    },
+    {
+      code: 6046,
+      name: "InvalidChangePeriod",
+      msg: "Guardians change period is out of bounds.",
+    },
+    {
+      code: 6047,
+      name: "InvalidActionExpiresTime",
+      msg: "Guardian action expiry is out of bounds.",
+    },
+    {
+      code: 6048,
+      name: "InvalidAgreePermyriad",
+      msg: "Agree permyriad cannot exceed 10000.",
+    },
+    {
+      code: 6049,
+      name: "ActionExpiresBeforeChangePeriod",
+      msg: "Guardian actions cannot expire before the guardians change period.",
+    },
+    {
+      code: 6050,
+      name: "NoPendingThresholds",
+      msg: "No global thresholds are scheduled.",
+    },
+    {
+      code: 6051,
+      name: "ThresholdsNoticePeriodNotPassed",
+      msg: "The notice period of the global thresholds has not passed.",
+    },
+    {
+      code: 6052,
+      name: "MissingLookupTable",
+      msg: "A lookup table of the transaction was not provided.",
+    },
+    {
+      code: 6053,
+      name: "InvalidLookupTable",
+      msg: "Invalid address lookup table.",
+    },
+    {
+      code: 6054,
+      name: "InvalidLookupAccount",
+      msg: "Invalid lookup table account reference.",
+    },
+    {
+      code: 6055,
+      name: "InvalidExecutionStep",
+      msg: "Transaction steps must be executed in order.",
+    },
+    {
+      code: 6056,
+      name: "NotHashCommitted",
+      msg: "The transaction does not commit to a hash of its instructions.",
+    },
+    {
+      code: 6057,
+      name: "InstructionsHashMismatch",
+      msg: "The instructions do not match the committed hash.",
+    },
+    {
+      code: 6058,
+      name: "MissingInstructions",
+      msg: "The instructions of a hash-committed transaction must be supplied.",
+    },
+    {
+      code: 6059,
+      name: "TooManyPrerequisites",
+      msg: "Too many prerequisite transactions.",
+    },
+    {
+      code: 6060,
+      name: "InvalidPrerequisite",
+      msg: "Prerequisite transactions must have been created before.",
+    },
+    {
+      code: 6061,
+      name: "MissingPrerequisite",
+      msg: "A prerequisite transaction was not provided.",
+    },
+    {
+      code: 6062,
+      name: "PrerequisiteNotExecuted",
+      msg: "A prerequisite transaction has not been executed.",
+    },
+    {
+      code: 6063,
+      name: "PreviousTransactionPending",
+      msg: "The previous transaction has not been executed or cancelled.",
+    },
+    {
+      code: 6064,
+      name: "TransactionCancelled",
+      msg: "The transaction has been cancelled.",
+    },
+    {
+      code: 6065,
+      name: "TooManyAssertions",
+      msg: "Too many assertions.",
+    },
+    {
+      code: 6066,
+      name: "MissingAssertionAccount",
+      msg: "An account of an assertion was not provided.",
+    },
+    {
+      code: 6067,
+      name: "AssertionFailed",
+      msg: "An assertion of the transaction does not hold.",
+    },
+    {
+      code: 6068,
+      name: "AssertionsRequireFullExecution",
+      msg: "Transactions with assertions must be executed at once.",
+    },
+    {
+      code: 6069,
+      name: "SimulationComplete",
+      msg: "Simulation complete, every instruction succeeded.",
+    },
+    {
+      code: 6070,
+      name: "TransactionExpired",
+      msg: "Transaction has expired.",
+    },
+    {
+      code: 6071,
+      name: "QuorumDelayExceedsExpiry",
+      msg: "The quorum delay of the action exceeds the action expiry.",
+    },
+    {
+      code: 6072,
+      name: "InvalidDerivedWallet",
+      msg: "The index and bump of a derived wallet must be given together.",
+    },
+    {
+      code: 6073,
+      name: "TransactionNotExpired",
+      msg: "The transaction has not expired.",
+    },
+    {
+      code: 6074,
+      name: "LookupAddressesChanged",
+      msg: "The lookup tables no longer resolve to the accounts of the proposal.",
+    },
+    {
+      code: 6075,
+      name: "InvalidExpiry",
+      msg: "The expiry must be after the transaction can be executed.",
     },
   ],
 };
@@ -9,7 +9,7 @@ export type SmalletTypes = AnchorTypes<
   SmalletIDL,
   {
     globalState: GlobalStateData;
-    guadianAction: GuadianActionData;
+    guardianAction: GuardianActionData;
     smallet: SmalletData;
     transaction: SmalletTransactionData;
     subaccountInfo: SubaccountInfoData;
@@ -21,11 +21,21 @@ export type SmalletTypes = AnchorTypes<
 >;
 
 type Accounts = SmalletTypes["Accounts"];
+type Defined = SmalletTypes["Defined"];
 export type GlobalStateData = Accounts["GlobalState"];
 export type SmalletData = Accounts["Smallet"];
-export type GuadianActionData = Accounts["GuadianAction"];
+export type GuardianActionData = Accounts["GuardianAction"];
+/** @deprecated Use [GuardianActionData]. */
+export type GuadianActionData = GuardianActionData;
 export type SmalletTransactionData = Accounts["Transaction"];
 export type SubaccountInfoData = Accounts["SubaccountInfo"];
+export type GuardianPolicy = Defined["GuardianPolicy"];
+export type GuardianQuorum = Defined["GuardianQuorum"];
+export type ValueTier = Defined["ValueTier"];
+export type TXLookupAccountMeta = Defined["TXLookupAccountMeta"];
+export type TXAssertion = Defined["TXAssertion"];
+export type TransactionOptions = Defined["TransactionOptions"];
+export type SimulationReport = Defined["SimulationReport"];
 
 export type SmalletInstruction = Omit<
   AnchorDefined<SmalletIDL>["TXInstruction"],
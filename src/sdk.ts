@@ -9,6 +9,7 @@ import type { PublicKey, Signer } from "@solana/web3.js";
 import { Keypair, SYSVAR_RENT_PUBKEY, SystemProgram } from "@solana/web3.js";
 import BN from "bn.js";
 import mapValues from "lodash.mapvalues";
+import invariant from "tiny-invariant";
 
 import type { Programs } from "./constants";
 import { SMALLET_ADDRESSES, SMALLET_IDLS } from "./constants";
@@ -16,13 +17,21 @@ import type { PendingGlobalState, PendingSmallet } from "./wrappers/smallet";
 import {
   findGlobalState,
   findOwnerInvokerAddress,
-  // findProgramData,
+  findProgramData,
   findSmallet,
   findSubaccountInfoAddress,
   findWalletDerivedAddress,
   SmalletWrapper,
 } from "./wrappers/smallet";
-import { GlobalStateData } from "./programs";
+import type { GlobalStateData, GuardianPolicy } from "./programs";
+
+/** Kinds of accounts that can be migrated to the current layout. */
+export type MigratableAccount =
+  | "globalState"
+  | "smallet"
+  | "transaction"
+  | "guardianAction"
+  | "subaccountInfo";
 
 /** SMALLET SDK */
 export class SMALLETSDK {
@@ -46,23 +55,29 @@ export class SMALLETSDK {
     return SmalletWrapper.load(this, key);
   }
   /**
-   * Initialize global state
+   * Initialize the global state of a domain
    */
-  async initializeGlobalState(): Promise<PendingGlobalState> {
-    const [globalState] = await findGlobalState();
-    // TODO: Should uncomment these lines too
-    // const [programData] = await findProgramData();
+  async initializeGlobalState({
+    domain = 0,
+  }: {
+    domain?: number | BN;
+  } = {}): Promise<PendingGlobalState> {
+    const [globalState] = await findGlobalState(domain);
+    const [programData] = await findProgramData();
 
-    const ix = this.programs.Smallet.instruction.initializeGlobalState({
-      accounts: {
-        admin: this.provider.wallet.publicKey,
-        globalState,
-        systemProgram: SystemProgram.programId,
-        rent: SYSVAR_RENT_PUBKEY,
-        // program: this.programs.Smallet.programId,
-        // programData,
-      },
-    });
+    const ix = this.programs.Smallet.instruction.initializeGlobalState(
+      new BN(domain),
+      {
+        accounts: {
+          admin: this.provider.wallet.publicKey,
+          globalState,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+          program: this.programs.Smallet.programId,
+          programData,
+        },
+      }
+    );
     return {
       tx: new TransactionEnvelope(this.provider, [ix]),
     };
@@ -71,8 +86,8 @@ export class SMALLETSDK {
   /**
    * Transfer global Admin
    */
-  async transferGlobalAdmin(newAdmin: PublicKey) {
-    const [globalState] = await findGlobalState();
+  async transferGlobalAdmin(newAdmin: PublicKey, domain: number | BN = 0) {
+    const [globalState] = await findGlobalState(domain);
     const ix = this.programs.Smallet.instruction.transferGlobalAdmin(newAdmin, {
       accounts: {
         globalState,
@@ -91,16 +106,18 @@ export class SMALLETSDK {
     changePeriod,
     actionExpires,
     minAgreePermyriad,
+    domain = 0,
   }: {
     changePeriod?: number;
     actionExpires?: number;
     minAgreePermyriad?: number;
+    domain?: number | BN;
   }) {
-    const [globalState] = await findGlobalState();
+    const [globalState] = await findGlobalState(domain);
     const ix = this.programs.Smallet.instruction.setGlobalThresholds(
-      changePeriod ? new BN(changePeriod) : null,
-      actionExpires ? new BN(actionExpires) : null,
-      minAgreePermyriad ? new BN(minAgreePermyriad) : null,
+      changePeriod !== undefined ? new BN(changePeriod) : null,
+      actionExpires !== undefined ? new BN(actionExpires) : null,
+      minAgreePermyriad ?? null,
       {
         accounts: {
           globalState,
@@ -113,8 +130,21 @@ export class SMALLETSDK {
     };
   }
 
-  async reloadGlobalData(): Promise<GlobalStateData> {
-    const [globalKey] = await findGlobalState();
+  /**
+   * Activates the global thresholds once their notice period has passed
+   */
+  async activateGlobalThresholds(domain: number | BN = 0) {
+    const [globalState] = await findGlobalState(domain);
+    const ix = this.programs.Smallet.instruction.activateGlobalThresholds({
+      accounts: { globalState },
+    });
+    return {
+      tx: new TransactionEnvelope(this.provider, [ix]),
+    };
+  }
+
+  async reloadGlobalData(domain: number | BN = 0): Promise<GlobalStateData> {
+    const [globalKey] = await findGlobalState(domain);
     this._data = await this.programs.Smallet.account.globalState.fetch(
       globalKey
     );
@@ -172,8 +202,12 @@ export class SMALLETSDK {
     numOwners,
     guadians,
     numGuadians,
+    guardians = guadians,
+    numGuardians = numGuadians,
     base = Keypair.generate(),
     delay = new BN(0),
+    guardianPolicy = null,
+    domain = 0,
     payer = this.provider.wallet.publicKey,
   }: {
     owners: PublicKey[];
@@ -182,19 +216,36 @@ export class SMALLETSDK {
      * Number of owners in the smart wallet.
      */
     numOwners: number;
-    guadians: PublicKey[];
+    guardians?: PublicKey[];
     /**
-     * Number of guadians in the smart wallet.
+     * Number of guardians in the smart wallet.
      */
-    numGuadians: number;
+    numGuardians?: number;
+    /** @deprecated Use `guardians`. */
+    guadians?: PublicKey[];
+    /** @deprecated Use `numGuardians`. */
+    numGuadians?: number;
     base?: Signer;
     /**
      * Timelock delay in seconds
      */
     delay?: BN;
+    /**
+     * Guardian policy of the smart wallet, the global one if null.
+     */
+    guardianPolicy?: GuardianPolicy | null;
+    /**
+     * Domain of the [GlobalState] the smart wallet joins.
+     */
+    domain?: number | BN;
     payer?: PublicKey;
   }): Promise<PendingSmallet> {
+    invariant(
+      guardians && numGuardians !== undefined,
+      "guardians and numGuardians are required"
+    );
     const [smallet, bump] = await findSmallet(base.publicKey);
+    const [globalState] = await findGlobalState(domain);
 
     const ix = this.programs.Smallet.instruction.createSmallet(
       bump,
@@ -202,12 +253,14 @@ export class SMALLETSDK {
       owners,
       threshold,
       delay,
-      numGuadians,
-      guadians,
+      numGuardians,
+      guardians,
+      guardianPolicy,
       {
         accounts: {
           base: base.publicKey,
           smallet,
+          globalState,
           payer,
           systemProgram: SystemProgram.programId,
         },
@@ -224,6 +277,45 @@ export class SMALLETSDK {
     };
   }
 
+  /**
+   * Rewrites an account of an older layout version in the current one.
+   */
+  migrateAccount({
+    kind,
+    account,
+    smallet,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    kind: MigratableAccount;
+    account: PublicKey;
+    /**
+     * Smallet of the migrated transaction, whose grace period sets its expiry.
+     */
+    smallet?: PublicKey;
+    payer?: PublicKey;
+  }): TransactionEnvelope {
+    const accounts = {
+      account,
+      payer,
+      systemProgram: SystemProgram.programId,
+    };
+    const instruction = this.programs.Smallet.instruction;
+    const migrateTransaction = () => {
+      invariant(smallet, "the smallet of the transaction is required");
+      return instruction.migrateTransaction({
+        accounts: { ...accounts, smallet },
+      });
+    };
+    const ix = {
+      globalState: () => instruction.migrateGlobalState({ accounts }),
+      smallet: () => instruction.migrateSmallet({ accounts }),
+      transaction: migrateTransaction,
+      guardianAction: () => instruction.migrateGuardianAction({ accounts }),
+      subaccountInfo: () => instruction.migrateSubaccountInfo({ accounts }),
+    }[kind]();
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
   /**
    * Loads the SDK.
    * @returns
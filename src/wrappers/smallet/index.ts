@@ -3,27 +3,37 @@ import { TransactionEnvelope } from "@saberhq/solana-contrib";
 import type { PublicKey, TransactionInstruction } from "@solana/web3.js";
 import { SystemProgram } from "@solana/web3.js";
 import BN from "bn.js";
+import invariant from "tiny-invariant";
 
 import type {
-  GuadianActionData,
+  GuardianActionData,
+  GuardianPolicy,
+  GuardianQuorum,
+  SimulationReport,
   SmalletData,
   SmalletProgram,
   SmalletTransactionData,
+  TransactionOptions,
+  TXLookupAccountMeta,
+  ValueTier,
 } from "../../programs";
 import type { SMALLETSDK } from "../../sdk";
 import {
   findGlobalState,
-  findGuadianActionAddress,
+  findGuardianActionAddress,
   findOwnerInvokerAddress,
   findTransactionAddress,
   findWalletDerivedAddress,
 } from "./pda";
 import {
   GuadianActionType,
+  GuardianActionType,
   InitSmalletWrapperArgs,
   NewTransactionArgs,
   PendingSmalletGuadianAction,
+  PendingSmalletGuardianAction,
   PendingSmalletTransaction,
+  SimulationOutcome,
 } from "./types";
 
 export * from "./pda";
@@ -64,6 +74,13 @@ export class SmalletWrapper {
     payer = this.provider.wallet.publicKey,
     instructions: ixs,
     eta,
+    prerequisites = [],
+    lookupTables = [],
+    lookupAccounts = [],
+    preAssertions = [],
+    postAssertions = [],
+    hashCommitted = false,
+    expiresAt,
   }: NewTransactionArgs): Promise<PendingSmalletTransaction> {
     const index = (await this.reloadData()).numTransactions.toNumber();
     const [txKey, txBump] = await findTransactionAddress(this.key, index);
@@ -74,11 +91,27 @@ export class SmalletWrapper {
       payer,
       systemProgram: SystemProgram.programId,
     };
+    const options: TransactionOptions = {
+      prerequisites: prerequisites.map((prerequisite) => new BN(prerequisite)),
+      lookupTables,
+      lookupAccounts,
+      preAssertions,
+      postAssertions,
+      hashCommitted,
+      expiresAt: expiresAt ?? null,
+    };
+    // The lookup tables are resolved when proposing
+    const remainingAccounts = lookupTables.map((pubkey) => ({
+      pubkey,
+      isSigner: false,
+      isWritable: false,
+    }));
     const instructions: TransactionInstruction[] = [];
     if (eta === undefined) {
       instructions.push(
-        this.program.instruction.createTransaction(txBump, ixs, {
+        this.program.instruction.createTransaction(txBump, ixs, options, {
           accounts,
+          remainingAccounts,
         })
       );
     } else {
@@ -86,9 +119,11 @@ export class SmalletWrapper {
         this.program.instruction.createTransactionWithTimelock(
           txBump,
           ixs,
+          options,
           eta,
           {
             accounts,
+            remainingAccounts,
           }
         )
       );
@@ -100,6 +135,41 @@ export class SmalletWrapper {
       index,
     };
   }
+  /**
+   * Proposes a new transaction storing only the hash of its instructions,
+   * which must be supplied again to execute it.
+   */
+  async newHashedTransaction(
+    args: NewTransactionArgs
+  ): Promise<PendingSmalletTransaction> {
+    return this.newTransaction({ ...args, hashCommitted: true });
+  }
+
+  /**
+   * Proposes a new transaction whose instructions reference accounts through
+   * address lookup tables. The instructions omit those accounts, which are
+   * inserted at their position when executing.
+   */
+  async newTransactionWithLookupTables(
+    args: NewTransactionArgs & {
+      lookupTables: PublicKey[];
+      lookupAccounts: TXLookupAccountMeta[];
+    }
+  ): Promise<PendingSmalletTransaction> {
+    return this.newTransaction(args);
+  }
+
+  /**
+   * Proposes a new transaction whose execution fails unless the
+   * pre-assertions hold before its instructions run and the post-assertions
+   * hold after.
+   */
+  async newTransactionWithAssertions(
+    args: NewTransactionArgs
+  ): Promise<PendingSmalletTransaction> {
+    return this.newTransaction(args);
+  }
+
   /**Creates a new transaction from an envelope */
 
   async newTransactionFromEnvelope({
@@ -139,6 +209,35 @@ export class SmalletWrapper {
       }),
     ]);
   }
+
+  // Cancel a pending transaction as its proposer
+  cancelTransaction(
+    transactionKey: PublicKey,
+    proposer: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.cancelTransaction({
+        accounts: {
+          smallet: this.key,
+          transaction: transactionKey,
+          proposer,
+        },
+      }),
+    ]);
+  }
+
+  // Record the expiry of a pending transaction, which anyone can do
+  expireTransaction(transactionKey: PublicKey): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.program.instruction.expireTransaction({
+        accounts: {
+          smallet: this.key,
+          transaction: transactionKey,
+        },
+      }),
+    ]);
+  }
+
   /**Executes a transaction as the new smallet */
 
   async executeTransaction({
@@ -153,6 +252,128 @@ export class SmalletWrapper {
     );
     return new TransactionEnvelope(this.provider, [ix]);
   }
+  /**Executes a hash-committed transaction with its instructions */
+
+  async executeHashedTransaction({
+    transactionKey,
+    instructions,
+    owner = this.provider.wallet.publicKey,
+  }: {
+    transactionKey: PublicKey;
+    instructions: TransactionInstruction[];
+    owner?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const ix = this.program.instruction.executeHashedTransaction(
+      instructions,
+      await this._fetchExecuteTransactionContext({
+        transactionKey,
+        owner,
+        instructions,
+      })
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Executes the next instruction of a transaction. The instructions of a
+   * hash-committed transaction must be supplied.
+   */
+
+  async executeTransactionStep({
+    transactionKey,
+    step,
+    instructions,
+    owner = this.provider.wallet.publicKey,
+  }: {
+    transactionKey: PublicKey;
+    step: number;
+    instructions?: TransactionInstruction[];
+    owner?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const context = await this._fetchExecuteTransactionContext({
+      transactionKey,
+      owner,
+      instructions,
+    });
+    const ix = instructions
+      ? this.program.instruction.executeHashedTransactionStep(
+          new BN(step),
+          instructions,
+          context
+        )
+      : this.program.instruction.executeTransactionStep(new BN(step), context);
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**
+   * Runs the instructions of a transaction regardless of its approvals. The
+   * instruction always fails, use it with `simulateTransaction` and read its
+   * logs with [parseSimulationLogs].
+   */
+  async simulateTransaction({
+    transactionKey,
+    walletIndex,
+    instructions,
+    owner = this.provider.wallet.publicKey,
+  }: {
+    transactionKey: PublicKey;
+    walletIndex?: number;
+    instructions?: TransactionInstruction[];
+    owner?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const derived =
+      walletIndex !== undefined
+        ? await this.findWalletDerivedAddress(walletIndex)
+        : null;
+    const context = await this._fetchExecuteTransactionContext({
+      transactionKey,
+      owner,
+      walletDerivedAddress: derived?.[0] ?? null,
+      instructions,
+    });
+    const ix = this.program.instruction.simulateTransaction(
+      instructions ?? null,
+      walletIndex !== undefined ? new BN(walletIndex) : null,
+      derived?.[1] ?? null,
+      context
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /**Decodes the report returned by [simulateTransaction] */
+
+  decodeSimulationReport(data: Buffer): SimulationReport {
+    return this.program.coder.types.decode<SimulationReport>(
+      "SimulationReport",
+      data
+    );
+  }
+
+  /**
+   * Reads the report of a simulation from its logs or, when an instruction
+   * failed before the report, the index of that instruction
+   */
+  parseSimulationLogs(logs: string[]): SimulationOutcome {
+    const { programId } = this.program;
+    const returnPrefix = `Program return: ${programId.toString()} `;
+    const returned = logs.find((log) => log.startsWith(returnPrefix));
+    if (returned) {
+      return {
+        report: this.decodeSimulationReport(
+          Buffer.from(returned.slice(returnPrefix.length), "base64")
+        ),
+        failedStep: null,
+      };
+    }
+    const steps = logs
+      .map((log) => /simulate: step (\d+)$/.exec(log)?.[1])
+      .filter((step): step is string => step !== undefined);
+    const lastStep = steps[steps.length - 1];
+    return {
+      report: null,
+      failedStep: lastStep !== undefined ? parseInt(lastStep) : null,
+    };
+  }
   /**Finds the derived wallet address and bump of a given index */
 
   async findWalletDerivedAddress(index: number): Promise<[PublicKey, number]> {
@@ -169,10 +390,12 @@ export class SmalletWrapper {
     transactionKey,
     owner = this.provider.wallet.publicKey,
     walletDerivedAddress = null,
+    instructions,
   }: {
     transactionKey: PublicKey;
     owner?: PublicKey;
     walletDerivedAddress?: PublicKey | null;
+    instructions?: TransactionInstruction[];
   }) {
     const data = await this.fetchTransaction(transactionKey);
     return {
@@ -181,28 +404,100 @@ export class SmalletWrapper {
         transaction: transactionKey,
         owner,
       },
-      remainingAccounts: data.instructions.flatMap((ix) => [
-        {
-          pubkey: ix.programId,
-          isSigner: false,
-          isWritable: false,
-        },
-        ...ix.keys.map((k) => {
-          if (
-            k.isSigner &&
-            ((walletDerivedAddress && k.pubkey.equals(walletDerivedAddress)) ||
-              k.pubkey.equals(this.key))
-          ) {
-            return {
-              ...k,
-              isSigner: false,
-            };
-          }
-          return k;
-        }),
-      ]),
+      remainingAccounts: [
+        ...this._instructionAccounts(
+          instructions ?? data.instructions,
+          walletDerivedAddress
+        ),
+        ...(await this._lookupAccounts(data)),
+        ...(await this._orderAccounts(data)),
+        ...this._assertionAccounts(data),
+      ],
     };
   }
+
+  /**Accounts of the instructions of a transaction */
+
+  private _instructionAccounts(
+    instructions: Pick<TransactionInstruction, "programId" | "keys">[],
+    walletDerivedAddress: PublicKey | null
+  ) {
+    return instructions.flatMap((ix) => [
+      {
+        pubkey: ix.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      ...ix.keys.map((k) => {
+        if (
+          k.isSigner &&
+          ((walletDerivedAddress && k.pubkey.equals(walletDerivedAddress)) ||
+            k.pubkey.equals(this.key))
+        ) {
+          return {
+            ...k,
+            isSigner: false,
+          };
+        }
+        return k;
+      }),
+    ]);
+  }
+
+  /**Lookup tables of a transaction and the accounts resolved from them */
+
+  private async _lookupAccounts(data: SmalletTransactionData) {
+    const tables = await Promise.all(
+      data.lookupTables.map(async (key) => {
+        const table = (
+          await this.provider.connection.getAddressLookupTable(key)
+        ).value;
+        invariant(table, `lookup table ${key.toString()} not found`);
+        return table;
+      })
+    );
+    return [
+      ...data.lookupTables.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: false,
+      })),
+      ...data.lookupAccounts.map((meta) => {
+        const table = tables[meta.tableIndex];
+        const pubkey = table?.state.addresses[meta.addressIndex];
+        invariant(pubkey, "lookup account not found");
+        return { pubkey, isSigner: false, isWritable: meta.isWritable };
+      }),
+    ];
+  }
+
+  /**Accounts the assertions of a transaction apply to */
+
+  private _assertionAccounts(data: SmalletTransactionData) {
+    return [...data.preAssertions, ...data.postAssertions]
+      .map(
+        (assertion) =>
+          (Object.values(assertion)[0] as { account: PublicKey }).account
+      )
+      .filter((account) => !account.equals(this.key))
+      .map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }));
+  }
+
+  /**Prerequisites of a transaction and, in sequential mode, the previous one */
+
+  private async _orderAccounts(data: SmalletTransactionData) {
+    const indices = data.prerequisites.map((index) => index.toNumber());
+    const { sequential } = await this.reloadData();
+    if (sequential && !data.index.isZero()) {
+      indices.push(data.index.toNumber() - 1);
+    }
+    return await Promise.all(
+      indices.map(async (index) => {
+        const [pubkey] = await findTransactionAddress(this.key, index);
+        return { pubkey, isSigner: false, isWritable: false };
+      })
+    );
+  }
   /**Executes a transaction using a wallet-derived address */
 
   async executeTransactionDerived({
@@ -333,6 +628,86 @@ export class SmalletWrapper {
     return new TransactionEnvelope(this.provider, [ix]);
   }
 
+  // Grow the smallet to hold more owners and guardians, the payer covering the rent
+  resizeSmallet({
+    maxOwners,
+    maxGuardians,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    maxOwners: number;
+    maxGuardians: number;
+    payer?: PublicKey;
+  }): TransactionEnvelope {
+    const ix = this.program.instruction.resizeSmallet(maxOwners, maxGuardians, {
+      accounts: {
+        smallet: this.key,
+        payer,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  // Close the smallet, sending its rent to the recipient.
+  // Derived subaccounts are passed with their [SubaccountInfo] to prove they are empty.
+  closeSmallet({
+    recipient = this.provider.wallet.publicKey,
+    subaccounts = [],
+  }: {
+    recipient?: PublicKey;
+    subaccounts?: { subaccountInfo: PublicKey; subaccount: PublicKey }[];
+  } = {}): TransactionEnvelope {
+    const ix = this.program.instruction.closeSmallet({
+      accounts: {
+        smallet: this.key,
+        recipient,
+      },
+      remainingAccounts: subaccounts.flatMap(
+        ({ subaccountInfo, subaccount }) => [
+          { pubkey: subaccountInfo, isSigner: false, isWritable: false },
+          { pubkey: subaccount, isSigner: false, isWritable: false },
+        ]
+      ),
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  // Reclaim the rent of an account left behind by the closed smallet
+  sweepSmalletAccount({
+    account,
+    recipient = this.provider.wallet.publicKey,
+  }: {
+    account: PublicKey;
+    recipient?: PublicKey;
+  }): TransactionEnvelope {
+    const ix = this.program.instruction.sweepSmalletAccount({
+      accounts: {
+        smallet: this.key,
+        account,
+        recipient,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  setOwnerRoles(roles: number[]): TransactionEnvelope {
+    const ix = this.program.instruction.setOwnerRoles(Buffer.from(roles), {
+      accounts: {
+        smallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  setValueTiers(valueTiers: ValueTier[]): TransactionEnvelope {
+    const ix = this.program.instruction.setValueTiers(valueTiers, {
+      accounts: {
+        smallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
   // Grant / Extend / Revoke auto-sign rights of an owner
   setSession({
     expiresAt,
@@ -350,6 +725,47 @@ export class SmalletWrapper {
     return new TransactionEnvelope(this.provider, [ix]);
   }
 
+  setFrozen(frozen: boolean): TransactionEnvelope {
+    const ix = this.program.instruction.setFrozen(frozen, {
+      accounts: {
+        smallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  // Require transactions to be executed in the order they were proposed
+  setSequential(sequential: boolean): TransactionEnvelope {
+    const ix = this.program.instruction.setSequential(sequential, {
+      accounts: {
+        smallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  setEmergencyKey(emergencyKey: PublicKey | null): TransactionEnvelope {
+    const ix = this.program.instruction.setEmergencyKey(emergencyKey, {
+      accounts: {
+        smallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  // Freeze the smallet at once as an owner with the freezer role or as the emergency key
+  emergencyFreeze(
+    freezer: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    const ix = this.program.instruction.emergencyFreeze({
+      accounts: {
+        smallet: this.key,
+        freezer,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
   // Grant / Extend / Revoke auto-sign rights of an owner
   async setFrozenAdmin({
     frozen,
@@ -358,7 +774,7 @@ export class SmalletWrapper {
     admin: PublicKey;
     frozen: boolean;
   }): Promise<TransactionEnvelope> {
-    const [globalState] = await findGlobalState();
+    const globalState = await this.findGlobalState();
 
     const ix = this.program.instruction.setFrozenAdmin(frozen, {
       accounts: {
@@ -370,113 +786,222 @@ export class SmalletWrapper {
     return new TransactionEnvelope(this.provider, [ix]);
   }
 
-  /** Perform guadian action as guadians or globalAdmin */
+  // Override the guardian parameters of the global state for this smallet
+  setGuardianPolicy(guardianPolicy: GuardianPolicy | null): TransactionEnvelope {
+    const ix = this.program.instruction.setGuardianPolicy(guardianPolicy, {
+      accounts: {
+        smallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  // Require more guardians or a delay for some kinds of guardian actions
+  setGuardianQuorums(guardianQuorums: GuardianQuorum[]): TransactionEnvelope {
+    const ix = this.program.instruction.setGuardianQuorums(guardianQuorums, {
+      accounts: {
+        smallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  setInactivityPeriod(inactivityPeriod: number): TransactionEnvelope {
+    const ix = this.program.instruction.setInactivityPeriod(
+      new BN(inactivityPeriod),
+      {
+        accounts: {
+          smallet: this.key,
+        },
+      }
+    );
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  // Record activity of an owner, postponing inheritance by the guardians
+  heartbeat(
+    owner: PublicKey = this.provider.wallet.publicKey
+  ): TransactionEnvelope {
+    const ix = this.program.instruction.heartbeat({
+      accounts: {
+        smallet: this.key,
+        owner,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  setLockDuration(lockDuration: number): TransactionEnvelope {
+    const ix = this.program.instruction.setLockDuration(new BN(lockDuration), {
+      accounts: {
+        smallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  // Reductions take effect once the current minimum delay has passed
+  setMinimumDelay(minimumDelay: number): TransactionEnvelope {
+    const ix = this.program.instruction.setMinimumDelay(new BN(minimumDelay), {
+      accounts: {
+        smallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
 
-  /// Lock smallet as any guadian
+  // Reductions take effect once the current minimum delay has passed
+  setGracePeriod(gracePeriod: number): TransactionEnvelope {
+    const ix = this.program.instruction.setGracePeriod(new BN(gracePeriod), {
+      accounts: {
+        smallet: this.key,
+      },
+    });
+    return new TransactionEnvelope(this.provider, [ix]);
+  }
+
+  /** Perform guardian action as guardians or globalAdmin */
+
+  /// Lock smallet as any guardian
   async lockSmallet({
-    guadian = this.provider.wallet.publicKey,
+    guadian,
+    guardian = guadian ?? this.provider.wallet.publicKey,
   }: {
-    guadian: PublicKey;
+    guardian?: PublicKey;
+    /** @deprecated Use `guardian`. */
+    guadian?: PublicKey;
   }): Promise<TransactionEnvelope> {
-    const [globalState] = await findGlobalState();
+    const globalState = await this.findGlobalState();
 
     const ix = this.program.instruction.lockSmallet({
       accounts: {
         smallet: this.key,
         globalState,
-        guadian,
+        guardian,
       },
     });
     return new TransactionEnvelope(this.provider, [ix]);
   }
 
-  /// Create guadian action for all guadians to sign
+  /// Create guardian action for all guardians to sign
   /// Will be performed immediately for global admin
-  async newGuadianAction({
-    guadian = this.provider.wallet.publicKey,
+  async newGuardianAction({
+    guardian = this.provider.wallet.publicKey,
     actionType,
-    newAddresses,
+    newAddresses = [],
+    threshold,
+    oldOwner,
+    newOwner,
   }: {
-    guadian: PublicKey;
-    actionType: GuadianActionType;
+    guardian: PublicKey;
+    actionType: GuardianActionType;
     newAddresses?: PublicKey[];
-  }): Promise<PendingSmalletGuadianAction> {
-    const index = (await this.reloadData()).numGudianActions.toNumber();
-    const [globalState] = await findGlobalState();
-    const [actionKey] = await findGuadianActionAddress(this.key, index);
+    /**
+     * New threshold of a [GuardianActionType.SetThreshold] action
+     */
+    threshold?: BN;
+    /**
+     * Owner replaced by a [GuardianActionType.ReplaceOwner] action
+     */
+    oldOwner?: PublicKey;
+    /**
+     * Owner added by a [GuardianActionType.ReplaceOwner] action
+     */
+    newOwner?: PublicKey;
+  }): Promise<PendingSmalletGuardianAction> {
+    const smalletData = await this.reloadData();
+    const index = smalletData.numGudianActions.toNumber();
+    const globalState = await this.findGlobalState();
+    const [actionKey] = await findGuardianActionAddress(this.key, index);
 
+    const takesAddresses =
+      actionType === GuardianActionType.SetOwners ||
+      actionType === GuardianActionType.SetGuardians ||
+      actionType === GuardianActionType.InheritOwners;
     // Not create transaction if newAddresses are empty
-    if (
-      actionType !== GuadianActionType.UnlockSmallet &&
-      !(newAddresses ?? []).length
-    )
+    if (takesAddresses && !newAddresses.length)
       return {
-        guadianActionKey: actionKey,
+        guardianActionKey: actionKey,
         tx: undefined,
         index,
       };
 
-    const smalletData = await this.program.account.smallet.fetch(actionKey);
-
     const accounts = {
-      guadian,
+      guardian,
       globalState,
       smallet: this.key,
-      guadianAction: actionKey,
+      guardianAction: actionKey,
       systemProgram: SystemProgram.programId,
     };
 
     let type: { [type: string]: {} };
     switch (actionType) {
-      case GuadianActionType.SetGuadians:
-        type = { setGuadians: {} };
+      case GuardianActionType.SetGuardians:
+        type = { setGuardians: {} };
         break;
-      case GuadianActionType.SetOwners:
+      case GuardianActionType.SetOwners:
         type = { setOwners: {} };
         break;
-      case GuadianActionType.UnlockSmallet:
+      case GuardianActionType.UnlockSmallet:
         type = { unlockSmallet: {} };
         break;
+      case GuardianActionType.InheritOwners:
+        type = { inheritOwners: {} };
+        break;
+      case GuardianActionType.SetThreshold:
+        invariant(threshold, "threshold is required");
+        type = { setThreshold: { threshold } };
+        break;
+      case GuardianActionType.ReplaceOwner:
+        invariant(oldOwner && newOwner, "oldOwner and newOwner are required");
+        type = { replaceOwner: { old: oldOwner, new: newOwner } };
+        break;
       default:
         type = { noAction: {} };
     }
 
     const instructions: TransactionInstruction[] = [];
     instructions.push(
-      this.program.instruction.createGuadianAction(
+      this.program.instruction.createGuardianAction(
         type,
-        smalletData.guadians.length,
-        newAddresses!.length, // Already handled empty case
+        smalletData.guardians.length,
+        newAddresses.length,
         {
           accounts,
+          // The new addresses are passed in order as remaining accounts
+          remainingAccounts: newAddresses.map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: false,
+          })),
         }
       )
     );
 
     return {
-      guadianActionKey: actionKey,
+      guardianActionKey: actionKey,
       tx: new TransactionEnvelope(this.provider, instructions),
       index,
     };
   }
 
-  /// Create guadian action for all guadians to sign
-  /// Will be performed immediately for global admin
-  async signGuadianActionAndTry({
-    guadian = this.provider.wallet.publicKey,
+  /// Sign a guardian action as guardian
+  /// The action is performed once enough guardians signed
+  async signGuardianActionAndTry({
+    guardian = this.provider.wallet.publicKey,
     index,
   }: {
-    guadian: PublicKey;
+    guardian: PublicKey;
     index: number;
-  }): Promise<PendingSmalletGuadianAction> {
-    const [globalState] = await findGlobalState();
-    const [actionKey] = await findGuadianActionAddress(this.key, index);
+  }): Promise<PendingSmalletGuardianAction> {
+    const globalState = await this.findGlobalState();
+    const [actionKey] = await findGuardianActionAddress(this.key, index);
 
     const accounts = {
-      guadian,
+      guardian,
       globalState,
       smallet: this.key,
-      guadianAction: actionKey,
+      guardianAction: actionKey,
     };
 
     const instructions: TransactionInstruction[] = [];
@@ -487,21 +1012,73 @@ export class SmalletWrapper {
     );
 
     return {
-      guadianActionKey: actionKey,
+      guardianActionKey: actionKey,
       tx: new TransactionEnvelope(this.provider, instructions),
       index,
     };
   }
 
+  async fetchGuardianActionByIndex(
+    index: number
+  ): Promise<GuardianActionData | null> {
+    const [txKey] = await findGuardianActionAddress(this.key, index);
+    return await this.program.account.guardianAction.fetchNullable(txKey);
+  }
+
+  async fetchGuardianAction(key: PublicKey): Promise<GuardianActionData> {
+    return await this.program.account.guardianAction.fetch(key);
+  }
+
+  /** @deprecated Use [newGuardianAction]. */
+  async newGuadianAction({
+    guadian = this.provider.wallet.publicKey,
+    actionType,
+    newAddresses = [],
+  }: {
+    guadian: PublicKey;
+    actionType: GuadianActionType;
+    newAddresses?: PublicKey[];
+  }): Promise<PendingSmalletGuadianAction> {
+    const { guardianActionKey, ...pending } = await this.newGuardianAction({
+      guardian: guadian,
+      // The legacy action types are the first current ones
+      actionType: actionType as number as GuardianActionType,
+      newAddresses,
+    });
+    return { ...pending, guadianActionKey: guardianActionKey };
+  }
+
+  /** @deprecated Use [signGuardianActionAndTry]. */
+  async signGuadianActionAndTry({
+    guadian = this.provider.wallet.publicKey,
+    index,
+  }: {
+    guadian: PublicKey;
+    index: number;
+  }): Promise<PendingSmalletGuadianAction> {
+    const { guardianActionKey, ...pending } =
+      await this.signGuardianActionAndTry({ guardian: guadian, index });
+    return { ...pending, guadianActionKey: guardianActionKey };
+  }
+
+  /** @deprecated Use [fetchGuardianActionByIndex]. */
   async fetchGuadianActionByIndex(
     index: number
-  ): Promise<GuadianActionData | null> {
-    const [txKey] = await findGuadianActionAddress(this.key, index);
-    return await this.program.account.guadianAction.fetchNullable(txKey);
+  ): Promise<GuardianActionData | null> {
+    return await this.fetchGuardianActionByIndex(index);
+  }
+
+  /** @deprecated Use [fetchGuardianAction]. */
+  async fetchGuadianAction(key: PublicKey): Promise<GuardianActionData> {
+    return await this.fetchGuardianAction(key);
   }
 
-  async fetchGuadianAction(key: PublicKey): Promise<GuadianActionData> {
-    return await this.program.account.guadianAction.fetch(key);
+  /**Finds the [GlobalState] of the domain of the smallet */
+
+  async findGlobalState(): Promise<PublicKey> {
+    const data = this._data ?? (await this.reloadData());
+    const [globalState] = await findGlobalState(data.domain);
+    return globalState;
   }
 
   /**Loads a smallet */
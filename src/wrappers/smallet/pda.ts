@@ -2,12 +2,24 @@ import { utils } from "@project-serum/anchor";
 import { getProgramAddress } from "@saberhq/solana-contrib";
 import { u64 } from "@saberhq/token-utils";
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import BN from "bn.js";
 
 import { SMALLET_ADDRESSES } from "../../constants";
 
-export const findGlobalState = async (): Promise<[PublicKey, number]> => {
+/**
+ * Seed identifying the domain of a [GlobalState]. The default domain has an
+ * empty seed, so its [GlobalState] keeps the address it had before domains.
+ */
+export const globalStateDomainSeed = (domain: number | BN = 0): Buffer => {
+  const value = new BN(domain);
+  return value.isZero() ? Buffer.alloc(0) : new u64(value).toBuffer();
+};
+
+export const findGlobalState = async (
+  domain: number | BN = 0
+): Promise<[PublicKey, number]> => {
   return await PublicKey.findProgramAddress(
-    [utils.bytes.utf8.encode("Smallet")],
+    [utils.bytes.utf8.encode("Smallet"), globalStateDomainSeed(domain)],
     SMALLET_ADDRESSES.Smallet
   );
 };
@@ -42,13 +54,13 @@ export const findTransactionAddress = async (
   );
 };
 
-export const findGuadianActionAddress = async (
+export const findGuardianActionAddress = async (
   smallet: PublicKey,
   index: number
 ): Promise<[PublicKey, number]> => {
   return await PublicKey.findProgramAddress(
     [
-      utils.bytes.utf8.encode("GuadianAction"),
+      utils.bytes.utf8.encode("GuardianAction"),
       smallet.toBuffer(),
       new u64(index).toBuffer(),
     ],
@@ -102,6 +114,9 @@ export const findSubaccountInfoAddress = async (
   );
 };
 
+/** @deprecated Use [findGuardianActionAddress]. */
+export const findGuadianActionAddress = findGuardianActionAddress;
+
 export const getSmalletAddress = (base: PublicKey): PublicKey => {
   return getProgramAddress(
     [utils.bytes.utf8.encode("Smallet"), base.toBuffer()],
@@ -109,13 +124,13 @@ export const getSmalletAddress = (base: PublicKey): PublicKey => {
   );
 };
 
-export const getGuadianActionAddress = (
+export const getGuardianActionAddress = (
   smallet: PublicKey,
   index: number
 ): PublicKey => {
   return getProgramAddress(
     [
-      utils.bytes.utf8.encode("GuadianAction"),
+      utils.bytes.utf8.encode("GuardianAction"),
       smallet.toBuffer(),
       new u64(index).toBuffer(),
     ],
@@ -123,6 +138,9 @@ export const getGuadianActionAddress = (
   );
 };
 
+/** @deprecated Use [getGuardianActionAddress]. */
+export const getGuadianActionAddress = getGuardianActionAddress;
+
 export const getTransactionAddress = (
   smallet: PublicKey,
   index: number
@@ -181,24 +199,43 @@ export const getSubaccountInfoAddress = (subaccount: PublicKey): PublicKey => {
   );
 };
 
-export const getSmalletSpaceSize = (maxOwners: number, maxGuadians: number) => {
+export const getSmalletSpaceSize = (
+  maxOwners: number,
+  maxGuardians: number
+) => {
   return (
-    8 +
-    32 +
-    1 +
-    8 * 3 +
-    4 +
-    8 +
-    4 +
-    32 * maxOwners +
-    4 +
-    8 * maxOwners +
-    4 +
-    32 * maxGuadians +
-    8 +
-    8 * 16 +
-    1 +
-    1
+    8 + // discriminator
+    32 + // base
+    1 + // bump
+    8 + // threshold
+    8 + // minimumDelay
+    8 + // gracePeriod
+    4 + // ownerSetSeqno
+    8 + // numTransactions
+    4 + 32 * maxOwners + // owners
+    4 + 8 * maxOwners + // ownerSessions
+    4 + 32 * maxGuardians + // guardians
+    8 + // numGudianActions
+    1 + // frozen
+    1 + // locked
+    4 + 48 * 8 + // valueTiers
+    4 + maxOwners + // ownerRoles
+    32 + // emergencyKey
+    8 + // lockedAt
+    8 + // lockDuration
+    8 + // lastOwnerActivity
+    8 + // inactivityPeriod
+    1 + 18 + // guardianPolicy
+    4 + 11 * 8 + // guardianQuorums
+    8 + // pendingTransactions
+    1 + // version
+    8 + // domain
+    1 + // sequential
+    8 + // pendingMinimumDelay
+    8 + // minimumDelayChangeAt
+    8 + // pendingGracePeriod
+    8 + // gracePeriodChangeAt
+    8 * 10 // reserved
   );
 };
 
@@ -2,7 +2,13 @@ import type { TransactionEnvelope } from "@saberhq/solana-contrib";
 import type { PublicKey, TransactionInstruction } from "@solana/web3.js";
 import type BN from "bn.js";
 
-import type { GlobalStateData, SmalletData } from "../../programs";
+import type {
+  GlobalStateData,
+  SimulationReport,
+  SmalletData,
+  TXAssertion,
+  TXLookupAccountMeta,
+} from "../../programs";
 import type { SmalletWrapper } from "./index";
 
 export type InitSmalletWrapperArgs = {
@@ -51,26 +57,100 @@ export interface NewTransactionArgs {
    * ETA of the new [Transaction]
    */
   readonly eta?: BN;
+  /**
+   * Indices of the [Transaction]s which must be executed before this one
+   */
+  readonly prerequisites?: number[];
+  /**
+   * Address lookup tables the instructions reference accounts from
+   */
+  readonly lookupTables?: PublicKey[];
+  /**
+   * Accounts omitted from the instructions, inserted from the lookup tables
+   */
+  readonly lookupAccounts?: TXLookupAccountMeta[];
+  /**
+   * Assertions which must hold before the instructions are executed
+   */
+  readonly preAssertions?: TXAssertion[];
+  /**
+   * Assertions which must hold after the instructions are executed
+   */
+  readonly postAssertions?: TXAssertion[];
+  /**
+   * Whether to store only the hash of the instructions
+   */
+  readonly hashCommitted?: boolean;
+  /**
+   * Time after which the [Transaction] expires, a grace period after it can
+   * be executed by default
+   */
+  readonly expiresAt?: BN;
 }
 
-export enum GuadianActionType {
+export enum GuardianActionType {
   NoAction,
   UnlockSmallet,
   SetOwners,
-  SetGuadians,
+  SetGuardians,
+  InheritOwners,
+  SetThreshold,
+  ReplaceOwner,
+}
+
+/**
+ * Role bits an owner of a smallet can hold
+ */
+export enum OwnerRole {
+  Proposer = 1 << 0,
+  Approver = 1 << 1,
+  Executor = 1 << 2,
+  Freezer = 1 << 3,
+  Invoker = 1 << 4,
+  All = Proposer | Approver | Executor | Freezer | Invoker,
 }
 
-export type PendingSmalletGuadianAction = {
+export type PendingSmalletGuardianAction = {
   /**
-   * Pubkey of the created [GuadianAction]
+   * Pubkey of the created [GuardianAction]
    */
-  readonly guadianActionKey: PublicKey;
+  readonly guardianActionKey: PublicKey;
   /**
-   * Transaction to create the [GuadianAction]
+   * Transaction to create the [GuardianAction]
    */
   readonly tx: TransactionEnvelope | undefined;
   /**
-   * Index of the [GuadianAction]
+   * Index of the [GuardianAction]
    */
   readonly index: number;
 };
+
+export type SimulationOutcome = {
+  /**
+   * Report returned by a simulation that ran every instruction
+   */
+  readonly report: SimulationReport | null;
+  /**
+   * Index of the instruction that failed, if the simulation aborted
+   */
+  readonly failedStep: number | null;
+};
+
+/** @deprecated Use [GuardianActionType]. */
+export enum GuadianActionType {
+  NoAction,
+  UnlockSmallet,
+  SetOwners,
+  SetGuadians,
+}
+
+/** @deprecated Use [PendingSmalletGuardianAction]. */
+export type PendingSmalletGuadianAction = Omit<
+  PendingSmalletGuardianAction,
+  "guardianActionKey"
+> & {
+  /**
+   * Pubkey of the created [GuardianAction]
+   */
+  readonly guadianActionKey: PublicKey;
+};
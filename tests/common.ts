@@ -4,6 +4,7 @@ import {
   LAMPORTS_PER_SOL,
   Keypair,
   Transaction,
+  TransactionInstruction,
 } from "@solana/web3.js";
 import {
   AugmentedProvider,
@@ -18,6 +19,9 @@ import {
   signWithTokenFee,
 } from "../packages/core/src";
 import { TokenFee } from "../packages/core/src/core";
+import type { SMALLETSDK } from "../src";
+import type { SmalletWrapper } from "../src/wrappers/smallet";
+import { findGlobalState } from "../src/wrappers/smallet";
 
 export async function airdropLamports(
   connection: Connection,
@@ -36,6 +40,44 @@ export async function sleep(ms: number) {
   return new Promise((resolve) => setTimeout(resolve, ms));
 }
 
+// Initializes the default GlobalState unless an earlier spec already did.
+// Smallets can only be created once it exists.
+export async function ensureGlobalState(sdk: SMALLETSDK) {
+  const [globalState] = await findGlobalState();
+  if (await sdk.provider.connection.getAccountInfo(globalState)) {
+    return;
+  }
+  const { tx } = await sdk.initializeGlobalState();
+  await tx.confirm();
+}
+
+// Proposes the instructions, approves them by every approver and executes
+// them as the proposer. Returns the key of the executed transaction.
+export async function executeAsSmallet(
+  smalletWrapper: SmalletWrapper,
+  instructions: TransactionInstruction[],
+  proposer: Keypair,
+  approvers: Keypair[] = []
+): Promise<PublicKey> {
+  const { transactionKey, tx } = await smalletWrapper.newTransaction({
+    proposer: proposer.publicKey,
+    instructions,
+  });
+  await tx.addSigners(proposer).confirm();
+  for (const approver of approvers) {
+    await smalletWrapper
+      .approveTransaction(transactionKey, approver.publicKey)
+      .addSigners(approver)
+      .confirm();
+  }
+  const executeTx = await smalletWrapper.executeTransaction({
+    transactionKey,
+    owner: proposer.publicKey,
+  });
+  await executeTx.addSigners(proposer).confirm();
+  return transactionKey;
+}
+
 export async function sendTxWithSponsor(
   feePayerKeypair: Keypair,
   sponsorPayer: Keypair,
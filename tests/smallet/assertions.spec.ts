@@ -0,0 +1,189 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import type { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+
+import type { TXAssertion } from "../../src";
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { airdropLamports, ensureGlobalState } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the pre- and post-execution assertion tests
+describe("assertions", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+  const { provider } = sdk;
+
+  const ownerA = Keypair.generate();
+  const receiver = Keypair.generate();
+
+  let smalletWrapper: SmalletWrapper;
+  let derivedWallet: PublicKey;
+
+  // Proposes the instructions guarded by the assertions as ownerA
+  const propose = async (
+    instructions: TransactionInstruction[],
+    preAssertions: TXAssertion[],
+    postAssertions: TXAssertion[]
+  ) => {
+    const { transactionKey, tx } =
+      await smalletWrapper.newTransactionWithAssertions({
+        proposer: ownerA.publicKey,
+        instructions,
+        preAssertions,
+        postAssertions,
+      });
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+    return transactionKey;
+  };
+
+  const execute = async (transactionKey: PublicKey) =>
+    (
+      await smalletWrapper.executeTransactionDerived({
+        transactionKey,
+        walletIndex: 0,
+        owner: ownerA.publicKey,
+      })
+    ).addSigners(ownerA);
+
+  const transfer = (lamports: number) =>
+    SystemProgram.transfer({
+      fromPubkey: derivedWallet,
+      toPubkey: receiver.publicKey,
+      lamports,
+    });
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 1,
+      owners: [ownerA.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+    [derivedWallet] = await smalletWrapper.findWalletDerivedAddress(0);
+    await airdropLamports(provider.connection, derivedWallet);
+  });
+
+  it("checks the pre-assertions before the execution", async () => {
+    const transactionKey = await propose(
+      [transfer(LAMPORTS_PER_SOL / 10)],
+      [
+        {
+          minLamports: {
+            account: derivedWallet,
+            lamports: new BN(10 * LAMPORTS_PER_SOL),
+          },
+        },
+      ],
+      []
+    );
+    const data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.preAssertions).to.have.lengthOf(1);
+
+    await expectTX(
+      await execute(transactionKey),
+      "execute with too few lamports"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.AssertionFailed.code.toString(16)}`
+    );
+  });
+
+  it("checks the post-assertions after the execution", async () => {
+    const limit = {
+      maxLamportsDecrease: {
+        account: derivedWallet,
+        lamports: new BN(LAMPORTS_PER_SOL / 10),
+      },
+    };
+
+    const drained = await propose(
+      [transfer(LAMPORTS_PER_SOL / 2)],
+      [],
+      [limit]
+    );
+    await expectTX(
+      await execute(drained),
+      "execute beyond the limit"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.AssertionFailed.code.toString(16)}`
+    );
+
+    const within = await propose(
+      [transfer(LAMPORTS_PER_SOL / 20)],
+      [],
+      [limit]
+    );
+    await expectTX(await execute(within), "execute within the limit").to.be
+      .fulfilled;
+    expect(
+      await provider.connection.getBalance(receiver.publicKey)
+    ).to.equal(LAMPORTS_PER_SOL / 20);
+  });
+
+  it("compares account data", async () => {
+    const discriminator = [
+      ...anchor.BorshAccountsCoder.accountDiscriminator("Smallet").values(),
+    ];
+    const matching = await propose(
+      [transfer(1_000_000)],
+      [
+        {
+          dataEquals: {
+            account: smalletWrapper.key,
+            offset: 0,
+            bytes: Buffer.from(discriminator),
+          },
+        },
+      ],
+      []
+    );
+    await expectTX(await execute(matching), "execute matching data").to.be
+      .fulfilled;
+
+    const mismatching = await propose(
+      [transfer(1_000_000)],
+      [
+        {
+          dataEquals: {
+            account: smalletWrapper.key,
+            offset: 0,
+            bytes: Buffer.from(discriminator.map((byte) => byte ^ 0xff)),
+          },
+        },
+      ],
+      []
+    );
+    await expectTX(
+      await execute(mismatching),
+      "execute mismatching data"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.AssertionFailed.code.toString(16)}`
+    );
+  });
+
+  it("rejects step execution of guarded transactions", async () => {
+    const transactionKey = await propose(
+      [transfer(1_000_000)],
+      [],
+      [{ minLamports: { account: derivedWallet, lamports: new BN(0) } }]
+    );
+    await expectTX(
+      (
+        await smalletWrapper.executeTransactionStep({
+          transactionKey,
+          step: 0,
+          owner: ownerA.publicKey,
+        })
+      ).addSigners(ownerA),
+      "execute step 0"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.AssertionsRequireFullExecution.code.toString(16)}`
+    );
+  });
+});
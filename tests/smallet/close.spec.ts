@@ -0,0 +1,103 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { ensureGlobalState } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the close and sweep tests
+describe("close smallet", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+  const { provider } = sdk;
+
+  const ownerA = Keypair.generate();
+  const recipient = Keypair.generate().publicKey;
+
+  let smalletWrapper: SmalletWrapper;
+  let pendingKey: PublicKey;
+  let closeKey: PublicKey;
+
+  const propose = async (
+    instructions: anchor.web3.TransactionInstruction[]
+  ) => {
+    const { transactionKey, tx } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions,
+    });
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+    return transactionKey;
+  };
+
+  const execute = async (transactionKey: PublicKey) =>
+    (
+      await smalletWrapper.executeTransaction({
+        transactionKey,
+        owner: ownerA.publicKey,
+      })
+    ).addSigners(ownerA);
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 1,
+      owners: [ownerA.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+  });
+
+  it("refuses to close with pending transactions", async () => {
+    pendingKey = await propose(smalletWrapper.changeThreshold(1).instructions);
+    closeKey = await propose(
+      smalletWrapper.closeSmallet({ recipient }).instructions
+    );
+
+    await expectTX(await execute(closeKey), "close while pending").to.be
+      .rejectedWith(
+        `0x${SmalletErrors.PendingTransactions.code.toString(16)}`
+      );
+  });
+
+  it("accounts of an open smallet cannot be swept", async () => {
+    await expectTX(await execute(pendingKey), "settle pending").to.be
+      .fulfilled;
+    await expectTX(
+      smalletWrapper.sweepSmalletAccount({ account: pendingKey, recipient }),
+      "sweep while open"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.SmalletNotClosed.code.toString(16)}`
+    );
+  });
+
+  it("closes the smallet and reclaims its rent", async () => {
+    const rent = (await provider.connection.getAccountInfo(smalletWrapper.key))
+      ?.lamports;
+    await expectTX(await execute(closeKey), "close").to.be.fulfilled;
+
+    expect(await provider.connection.getAccountInfo(smalletWrapper.key)).to.be
+      .null;
+    expect(await provider.connection.getBalance(recipient)).to.equal(rent);
+  });
+
+  it("anyone sweeps the accounts of a closed smallet", async () => {
+    const before = await provider.connection.getBalance(recipient);
+    const rent = await provider.connection.getBalance(pendingKey);
+    await expectTX(
+      smalletWrapper.sweepSmalletAccount({ account: pendingKey, recipient }),
+      "sweep executed transaction"
+    ).to.be.fulfilled;
+
+    expect(await provider.connection.getAccountInfo(pendingKey)).to.be.null;
+    expect(await provider.connection.getBalance(recipient)).to.equal(
+      before + rent
+    );
+  });
+});
@@ -0,0 +1,159 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { sleep } from "@saberhq/solana-contrib";
+import type { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { ensureGlobalState, executeAsSmallet } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the grace period and minimum delay tests
+describe("delays", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const SECONDS_PER_DAY = 24 * 60 * 60;
+  const MAX_DELAY_SECONDS = 365 * SECONDS_PER_DAY;
+  const MINIMUM_DELAY = 4;
+
+  const ownerA = Keypair.generate();
+
+  let smalletWrapper: SmalletWrapper;
+
+  // Proposes the instructions as ownerA, whose approval is enough
+  const propose = async (
+    instructions: TransactionInstruction[],
+    eta?: anchor.BN
+  ) =>
+    await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions,
+      eta,
+    });
+
+  const execute = async (transactionKey: PublicKey) =>
+    (
+      await smalletWrapper.executeTransaction({
+        transactionKey,
+        owner: ownerA.publicKey,
+      })
+    ).addSigners(ownerA);
+
+  // Proposes the instructions after the minimum delay and executes them
+  const executeDelayed = async (instructions: TransactionInstruction[]) => {
+    const eta = Math.floor(Date.now() / 1000) + MINIMUM_DELAY + 2;
+    const { transactionKey, tx } = await propose(instructions, new BN(eta));
+    await expectTX(tx.addSigners(ownerA), "propose delayed").to.be.fulfilled;
+    await sleep((eta - Date.now() / 1000 + 2) * 1000);
+    await expectTX(await execute(transactionKey), "execute delayed").to.be
+      .fulfilled;
+  };
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 1,
+      owners: [ownerA.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+  });
+
+  it("bounds the delays by the maximum delay", async () => {
+    for (const ix of [
+      smalletWrapper.setGracePeriod(MAX_DELAY_SECONDS + 1),
+      smalletWrapper.setMinimumDelay(MAX_DELAY_SECONDS + 1),
+    ]) {
+      const { transactionKey, tx } = await propose(ix.instructions);
+      await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+      await expectTX(
+        await execute(transactionKey),
+        "execute beyond the maximum"
+      ).to.be.rejectedWith(
+        `0x${SmalletErrors.DelayTooHigh.code.toString(16)}`
+      );
+    }
+
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setGracePeriod(MAX_DELAY_SECONDS).instructions,
+      ownerA
+    );
+    expect(
+      (await smalletWrapper.reloadData()).gracePeriod.toNumber()
+    ).to.equal(MAX_DELAY_SECONDS);
+  });
+
+  it("reduces the grace period at once without a minimum delay", async () => {
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setGracePeriod(SECONDS_PER_DAY).instructions,
+      ownerA
+    );
+    const data = await smalletWrapper.reloadData();
+    expect(data.gracePeriod.toNumber()).to.equal(SECONDS_PER_DAY);
+    expect(data.gracePeriodChangeAt.toNumber()).to.equal(0);
+  });
+
+  it("requires an ETA after the minimum delay", async () => {
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setMinimumDelay(MINIMUM_DELAY).instructions,
+      ownerA
+    );
+    expect(
+      (await smalletWrapper.reloadData()).minimumDelay.toNumber()
+    ).to.equal(MINIMUM_DELAY);
+
+    const { tx: withoutEta } = await propose(
+      smalletWrapper.setLockDuration(100).instructions
+    );
+    await expectTX(
+      withoutEta.addSigners(ownerA),
+      "propose without an ETA"
+    ).to.be.rejectedWith(`0x${SmalletErrors.InvalidETA.code.toString(16)}`);
+
+    const { tx: early } = await propose(
+      smalletWrapper.setLockDuration(100).instructions,
+      new BN(Math.floor(Date.now() / 1000) + 1)
+    );
+    await expectTX(
+      early.addSigners(ownerA),
+      "propose before the minimum delay"
+    ).to.be.rejectedWith(`0x${SmalletErrors.InvalidETA.code.toString(16)}`);
+  });
+
+  it("delays reductions by the current minimum delay", async () => {
+    await executeDelayed([
+      ...smalletWrapper.setMinimumDelay(MINIMUM_DELAY / 2).instructions,
+      ...smalletWrapper.setGracePeriod(SECONDS_PER_DAY / 2).instructions,
+    ]);
+
+    const data = await smalletWrapper.reloadData();
+    expect(data.minimumDelay.toNumber()).to.equal(MINIMUM_DELAY);
+    expect(data.pendingMinimumDelay.toNumber()).to.equal(MINIMUM_DELAY / 2);
+    expect(data.minimumDelayChangeAt.toNumber()).to.not.equal(0);
+    expect(data.gracePeriod.toNumber()).to.equal(SECONDS_PER_DAY);
+    expect(data.pendingGracePeriod.toNumber()).to.equal(SECONDS_PER_DAY / 2);
+    expect(data.gracePeriodChangeAt.toNumber()).to.not.equal(0);
+  });
+
+  it("delays increases of the grace period too", async () => {
+    // The reductions above have taken effect by the time this executes
+    await executeDelayed(
+      smalletWrapper.setGracePeriod(SECONDS_PER_DAY * 2).instructions
+    );
+
+    const data = await smalletWrapper.reloadData();
+    expect(data.minimumDelay.toNumber()).to.equal(MINIMUM_DELAY / 2);
+    expect(data.gracePeriod.toNumber()).to.equal(SECONDS_PER_DAY / 2);
+    expect(data.pendingGracePeriod.toNumber()).to.equal(SECONDS_PER_DAY * 2);
+    expect(data.gracePeriodChangeAt.toNumber()).to.not.equal(0);
+  });
+});
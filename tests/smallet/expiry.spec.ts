@@ -0,0 +1,181 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { sleep } from "@saberhq/solana-contrib";
+import type { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { ensureGlobalState, executeAsSmallet } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the transaction expiry tests
+describe("expiry", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const GRACE_PERIOD = 5;
+
+  const ownerA = Keypair.generate();
+  const ownerB = Keypair.generate();
+
+  let smalletWrapper: SmalletWrapper;
+
+  // Proposes the instructions as ownerA, whose approval is enough
+  const propose = async (instructions: TransactionInstruction[]) => {
+    const { transactionKey, tx } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions,
+    });
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+    return transactionKey;
+  };
+
+  // Proposes the instructions as ownerA with the given expiry
+  const proposeWithExpiry = (expiresAt: number, eta?: number) =>
+    smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions: smalletWrapper.setLockDuration(500).instructions,
+      eta: eta === undefined ? undefined : new BN(eta),
+      expiresAt: new BN(expiresAt),
+    });
+
+  const now = () => Math.floor(Date.now() / 1000);
+
+  const execute = async (transactionKey: PublicKey) =>
+    (
+      await smalletWrapper.executeTransaction({
+        transactionKey,
+        owner: ownerA.publicKey,
+      })
+    ).addSigners(ownerA);
+
+  const waitForExpiry = () => sleep((GRACE_PERIOD + 3) * 1000);
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 2,
+      owners: [ownerA.publicKey, ownerB.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+
+    // Without a minimum delay, the reduction takes effect at once
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setGracePeriod(GRACE_PERIOD).instructions,
+      ownerA
+    );
+  });
+
+  it("rejects approving and executing expired transactions", async () => {
+    const transactionKey = await propose(
+      smalletWrapper.setLockDuration(100).instructions
+    );
+    const data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.expiresAt.toNumber()).to.not.equal(0);
+    await waitForExpiry();
+
+    await expectTX(
+      smalletWrapper
+        .approveTransaction(transactionKey, ownerB.publicKey)
+        .addSigners(ownerB),
+      "approve expired"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.TransactionExpired.code.toString(16)}`
+    );
+    await expectTX(
+      await execute(transactionKey),
+      "execute expired"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.TransactionExpired.code.toString(16)}`
+    );
+  });
+
+  it("lets anyone record the expiry once", async () => {
+    const transactionKey = await propose(
+      smalletWrapper.setLockDuration(200).instructions
+    );
+    await expectTX(
+      smalletWrapper.expireTransaction(transactionKey),
+      "expire before the expiry"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.TransactionNotExpired.code.toString(16)}`
+    );
+
+    await waitForExpiry();
+    const pending = (await smalletWrapper.reloadData()).pendingTransactions;
+    await expectTX(
+      smalletWrapper.expireTransaction(transactionKey),
+      "expire"
+    ).to.be.fulfilled;
+    const data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.expiredAt.toNumber()).to.not.equal(0);
+    expect(
+      (await smalletWrapper.reloadData()).pendingTransactions.toNumber()
+    ).to.equal(pending.toNumber() - 1);
+
+    await expectTX(
+      smalletWrapper.expireTransaction(transactionKey),
+      "expire again"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.TransactionExpired.code.toString(16)}`
+    );
+  });
+
+  it("settles expired transactions in sequential mode", async () => {
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setSequential(true).instructions,
+      ownerA
+    );
+    await propose(smalletWrapper.setLockDuration(300).instructions);
+    await waitForExpiry();
+
+    const transactionKey = await propose(
+      smalletWrapper.setLockDuration(400).instructions
+    );
+    await expectTX(await execute(transactionKey), "execute after expiry").to
+      .be.fulfilled;
+    expect(
+      (await smalletWrapper.reloadData()).lockDuration.toNumber()
+    ).to.equal(400);
+  });
+
+  it("uses the expiry set by the proposer", async () => {
+    const expiresAt = now() + 100;
+    const { transactionKey, tx } = await proposeWithExpiry(expiresAt);
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+    const data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.expiresAt.toNumber()).to.equal(expiresAt);
+
+    const eta = now() + 60;
+    const { transactionKey: delayedKey, tx: delayed } =
+      await proposeWithExpiry(eta + 1, eta);
+    await expectTX(delayed.addSigners(ownerA), "propose with an ETA").to.be
+      .fulfilled;
+    const delayedData = await smalletWrapper.fetchTransaction(delayedKey);
+    expect(delayedData.expiresAt.toNumber()).to.equal(eta + 1);
+  });
+
+  it("rejects an expiry before the transaction can be executed", async () => {
+    const { tx } = await proposeWithExpiry(now() - 10);
+    await expectTX(tx.addSigners(ownerA), "propose expired").to.be.rejectedWith(
+      `0x${SmalletErrors.InvalidExpiry.code.toString(16)}`
+    );
+
+    const eta = now() + 60;
+    const { tx: delayed } = await proposeWithExpiry(eta - 30, eta);
+    await expectTX(
+      delayed.addSigners(ownerA),
+      "propose expiring before the ETA"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.InvalidExpiry.code.toString(16)}`
+    );
+  });
+});
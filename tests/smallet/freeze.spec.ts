@@ -0,0 +1,132 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { OwnerRole } from "../../src/wrappers/smallet";
+import { ensureGlobalState, executeAsSmallet } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the emergency freeze tests
+describe("emergency freeze", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const ownerA = Keypair.generate();
+  const ownerB = Keypair.generate();
+  const ownerC = Keypair.generate();
+  const emergencyKey = Keypair.generate();
+  const owners = [ownerA.publicKey, ownerB.publicKey, ownerC.publicKey];
+
+  let smalletWrapper: SmalletWrapper;
+  // Proposal to unfreeze, created while the smallet is not frozen
+  let unfreezeKey: PublicKey;
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: owners.length,
+      owners,
+      numGuardians: owners.length,
+      guardians: owners,
+      threshold: new BN(2),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+
+    // ownerB may not freeze, the emergency key may
+    await executeAsSmallet(
+      smalletWrapper,
+      [
+        ...smalletWrapper.setOwnerRoles([
+          OwnerRole.All,
+          OwnerRole.All & ~OwnerRole.Freezer,
+          OwnerRole.All,
+        ]).instructions,
+        ...smalletWrapper.setEmergencyKey(emergencyKey.publicKey).instructions,
+      ],
+      ownerA,
+      [ownerC]
+    );
+    const data = await smalletWrapper.reloadData();
+    expect(data.emergencyKey).to.eqAddress(emergencyKey.publicKey);
+
+    const { transactionKey, tx: proposeTx } =
+      await smalletWrapper.newTransaction({
+        proposer: ownerA.publicKey,
+        instructions: smalletWrapper.setFrozen(false).instructions,
+      });
+    await expectTX(proposeTx.addSigners(ownerA), "propose unfreeze").to.be
+      .fulfilled;
+    unfreezeKey = transactionKey;
+  });
+
+  it("rejects freezers without the freezer role", async () => {
+    await expectTX(
+      smalletWrapper.emergencyFreeze(ownerB.publicKey).addSigners(ownerB),
+      "freeze as ownerB"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.InvalidFreezer.code.toString(16)}`
+    );
+
+    const stranger = Keypair.generate();
+    await expectTX(
+      smalletWrapper.emergencyFreeze(stranger.publicKey).addSigners(stranger),
+      "freeze as a stranger"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.InvalidFreezer.code.toString(16)}`
+    );
+  });
+
+  it("a single owner freezes the smallet at once", async () => {
+    await expectTX(
+      smalletWrapper.emergencyFreeze(ownerA.publicKey).addSigners(ownerA),
+      "freeze as ownerA"
+    ).to.be.fulfilled;
+    expect((await smalletWrapper.reloadData()).frozen).to.be.true;
+
+    // No new proposals while frozen
+    const { tx } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions: smalletWrapper.changeThreshold(1).instructions,
+    });
+    await expectTX(tx.addSigners(ownerA), "propose while frozen").to.be
+      .rejectedWith(`0x${SmalletErrors.AccountFrozen.code.toString(16)}`);
+  });
+
+  it("unfreezing requires the threshold", async () => {
+    const executeTx = async () =>
+      (
+        await smalletWrapper.executeTransaction({
+          transactionKey: unfreezeKey,
+          owner: ownerA.publicKey,
+        })
+      ).addSigners(ownerA);
+
+    await expectTX(await executeTx(), "unfreeze with one approval").to.be
+      .rejectedWith(`0x${SmalletErrors.NotEnoughSigners.code.toString(16)}`);
+
+    await expectTX(
+      smalletWrapper
+        .approveTransaction(unfreezeKey, ownerC.publicKey)
+        .addSigners(ownerC),
+      "ownerC approves unfreeze"
+    ).to.be.fulfilled;
+    await expectTX(await executeTx(), "unfreeze with two approvals").to.be
+      .fulfilled;
+    expect((await smalletWrapper.reloadData()).frozen).to.be.false;
+  });
+
+  it("the emergency key freezes the smallet at once", async () => {
+    await expectTX(
+      smalletWrapper
+        .emergencyFreeze(emergencyKey.publicKey)
+        .addSigners(emergencyKey),
+      "freeze as emergency key"
+    ).to.be.fulfilled;
+    expect((await smalletWrapper.reloadData()).frozen).to.be.true;
+  });
+});
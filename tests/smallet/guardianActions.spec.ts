@@ -0,0 +1,139 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import invariant from "tiny-invariant";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { GuardianActionType, OwnerRole } from "../../src/wrappers/smallet";
+import { ensureGlobalState, executeAsSmallet } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the threshold and owner replacement guardian action tests
+describe("guardian threshold and owner replacement", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const ownerA = Keypair.generate();
+  const ownerB = Keypair.generate();
+  const ownerC = Keypair.generate();
+  const newOwner = Keypair.generate();
+  const guardian = Keypair.generate();
+  const owners = [ownerA.publicKey, ownerB.publicKey, ownerC.publicKey];
+
+  let smalletWrapper: SmalletWrapper;
+
+  // Creates a guardian action signed by the only guardian
+  const guardianAction = async (
+    args: Omit<
+      Parameters<SmalletWrapper["newGuardianAction"]>[0],
+      "guardian"
+    >
+  ) => {
+    const { tx } = await smalletWrapper.newGuardianAction({
+      guardian: guardian.publicKey,
+      ...args,
+    });
+    invariant(tx, "guardian action was not created");
+    return tx.addSigners(guardian);
+  };
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: owners.length,
+      owners,
+      numGuardians: 1,
+      guardians: [guardian.publicKey],
+      threshold: new BN(3),
+      guardianPolicy: {
+        changePeriod: new BN(0),
+        actionExpiresTime: new BN(3600),
+        minAgreePermyriad: 5000,
+      },
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+  });
+
+  it("guardians lower an unreachable threshold", async () => {
+    await expectTX(
+      await guardianAction({
+        actionType: GuardianActionType.SetThreshold,
+        threshold: new BN(4),
+      }),
+      "threshold above the owners"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.InvalidThreshold.code.toString(16)}`
+    );
+
+    await expectTX(
+      await guardianAction({
+        actionType: GuardianActionType.SetThreshold,
+        threshold: new BN(2),
+      }),
+      "lower threshold"
+    ).to.be.fulfilled;
+    const data = await smalletWrapper.reloadData();
+    expect(data.threshold).to.be.bignumber.equal(new BN(2));
+  });
+
+  it("rejects replacing an owner by another owner", async () => {
+    await expectTX(
+      await guardianAction({
+        actionType: GuardianActionType.ReplaceOwner,
+        oldOwner: ownerC.publicKey,
+        newOwner: ownerA.publicKey,
+      }),
+      "replace by an owner"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.DuplicateAddresses.code.toString(16)}`
+    );
+  });
+
+  it("guardians replace a lost owner key", async () => {
+    // ownerC keeps a restricted role set
+    const roles = [
+      OwnerRole.All,
+      OwnerRole.All,
+      OwnerRole.Approver | OwnerRole.Proposer,
+    ];
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setOwnerRoles(roles).instructions,
+      ownerA,
+      [ownerB]
+    );
+    await expectTX(
+      smalletWrapper
+        .setSession({
+          owner: ownerC.publicKey,
+          expiresAt: new BN(Math.floor(Date.now() / 1000) + 3600),
+        })
+        .addSigners(ownerC),
+      "ownerC opens a session"
+    ).to.be.fulfilled;
+
+    const before = await smalletWrapper.reloadData();
+    await expectTX(
+      await guardianAction({
+        actionType: GuardianActionType.ReplaceOwner,
+        oldOwner: ownerC.publicKey,
+        newOwner: newOwner.publicKey,
+      }),
+      "replace ownerC"
+    ).to.be.fulfilled;
+
+    const data = await smalletWrapper.reloadData();
+    expect(data.owners).to.deep.equal([
+      ownerA.publicKey,
+      ownerB.publicKey,
+      newOwner.publicKey,
+    ]);
+    // The new key takes the roles but not the session of the old one
+    expect([...data.ownerRoles]).to.deep.equal(roles);
+    expect(data.ownerSessions[2]?.toNumber()).to.equal(-1);
+    expect(data.ownerSetSeqno).to.equal(before.ownerSetSeqno + 1);
+  });
+});
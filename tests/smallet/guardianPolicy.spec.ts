@@ -0,0 +1,104 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import invariant from "tiny-invariant";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { GuardianActionType } from "../../src/wrappers/smallet";
+import { ensureGlobalState, executeAsSmallet } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the per-smallet guardian policy tests
+describe("guardian policy", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const ownerA = Keypair.generate();
+  const guardianA = Keypair.generate();
+  const guardianB = Keypair.generate();
+  const guardians = [guardianA.publicKey, guardianB.publicKey];
+
+  let smalletWrapper: SmalletWrapper;
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    // Every guardian must agree on actions of this smallet
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 1,
+      owners: [ownerA.publicKey],
+      numGuardians: guardians.length,
+      guardians,
+      threshold: new BN(1),
+      guardianPolicy: {
+        changePeriod: new BN(0),
+        actionExpiresTime: new BN(3600),
+        minAgreePermyriad: 10000,
+      },
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+  });
+
+  it("the smallet policy decides the guardian quorum", async () => {
+    await expectTX(
+      (
+        await smalletWrapper.lockSmallet({ guardian: guardianA.publicKey })
+      ).addSigners(guardianA),
+      "lock as guardianA"
+    ).to.be.fulfilled;
+
+    const { tx, guardianActionKey, index } =
+      await smalletWrapper.newGuardianAction({
+        guardian: guardianA.publicKey,
+        actionType: GuardianActionType.UnlockSmallet,
+      });
+    invariant(tx, "guardian action was not created");
+    await expectTX(tx.addSigners(guardianA), "guardianA requests unlock").to.be
+      .fulfilled;
+
+    // A single guardian is not enough
+    let action = await smalletWrapper.fetchGuardianAction(guardianActionKey);
+    expect(action.performed).to.be.false;
+    expect((await smalletWrapper.reloadData()).locked).to.be.true;
+
+    const { tx: signTx } = await smalletWrapper.signGuardianActionAndTry({
+      guardian: guardianB.publicKey,
+      index,
+    });
+    invariant(signTx, "guardian action was not signed");
+    await expectTX(signTx.addSigners(guardianB), "guardianB agrees").to.be
+      .fulfilled;
+
+    action = await smalletWrapper.fetchGuardianAction(guardianActionKey);
+    expect(action.performed).to.be.true;
+    expect((await smalletWrapper.reloadData()).locked).to.be.false;
+  });
+
+  it("rejects a policy whose actions expire before guardians can change", async () => {
+    const [ix] = smalletWrapper.setGuardianPolicy({
+      changePeriod: new BN(3600),
+      actionExpiresTime: new BN(60),
+      minAgreePermyriad: 5000,
+    }).instructions;
+    try {
+      await executeAsSmallet(smalletWrapper, [ix], ownerA);
+      expect.fail("invalid guardian policy was accepted");
+    } catch (e) {
+      const err = e as Error;
+      expect(err.message).to.include(
+        `0x${SmalletErrors.ActionExpiresBeforeChangePeriod.code.toString(16)}`
+      );
+    }
+  });
+
+  it("clearing the policy falls back to the global state", async () => {
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setGuardianPolicy(null).instructions,
+      ownerA
+    );
+    expect((await smalletWrapper.reloadData()).guardianPolicy).to.be.null;
+  });
+});
@@ -0,0 +1,223 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import invariant from "tiny-invariant";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { GuardianActionType } from "../../src/wrappers/smallet";
+import { ensureGlobalState, executeAsSmallet, sleep } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the per-action guardian quorum tests
+describe("guardian quorums", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const ownerA = Keypair.generate();
+  const ownerB = Keypair.generate();
+  const guardianA = Keypair.generate();
+  const guardianB = Keypair.generate();
+  const guardians = [guardianA.publicKey, guardianB.publicKey];
+  const unlockDelay = 3;
+
+  let smalletWrapper: SmalletWrapper;
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    // A single guardian is enough unless a quorum says otherwise
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 2,
+      owners: [ownerA.publicKey, ownerB.publicKey],
+      numGuardians: guardians.length,
+      guardians,
+      threshold: new BN(1),
+      guardianPolicy: {
+        changePeriod: new BN(0),
+        actionExpiresTime: new BN(60),
+        minAgreePermyriad: 0,
+      },
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setGuardianQuorums([
+        {
+          actionKind: { setThreshold: {} },
+          minAgreePermyriad: 10000,
+          delay: new BN(0),
+        },
+        {
+          actionKind: { unlockSmallet: {} },
+          minAgreePermyriad: 0,
+          delay: new BN(unlockDelay),
+        },
+        {
+          actionKind: { replaceOwner: {} },
+          minAgreePermyriad: 0,
+          delay: new BN(120),
+        },
+      ]).instructions,
+      ownerA
+    );
+    const data = await smalletWrapper.reloadData();
+    expect(data.guardianQuorums).to.have.lengthOf(3);
+  });
+
+  it("rejects two quorums for the same action", async () => {
+    const quorum = {
+      actionKind: { setOwners: {} },
+      minAgreePermyriad: 5000,
+      delay: new BN(0),
+    };
+    try {
+      await executeAsSmallet(
+        smalletWrapper,
+        smalletWrapper.setGuardianQuorums([quorum, quorum]).instructions,
+        ownerA
+      );
+      expect.fail("duplicate quorums were accepted");
+    } catch (e) {
+      const err = e as Error;
+      expect(err.message).to.include(
+        `0x${SmalletErrors.InvalidGuardianQuorums.code.toString(16)}`
+      );
+    }
+  });
+
+  it("a quorum can require every guardian", async () => {
+    const { tx, guardianActionKey, index } =
+      await smalletWrapper.newGuardianAction({
+        guardian: guardianA.publicKey,
+        actionType: GuardianActionType.SetThreshold,
+        threshold: new BN(2),
+      });
+    invariant(tx, "guardian action was not created");
+    await expectTX(tx.addSigners(guardianA), "guardianA sets threshold").to.be
+      .fulfilled;
+    let action = await smalletWrapper.fetchGuardianAction(guardianActionKey);
+    expect(action.performed).to.be.false;
+
+    const { tx: signTx } = await smalletWrapper.signGuardianActionAndTry({
+      guardian: guardianB.publicKey,
+      index,
+    });
+    invariant(signTx, "guardian action was not signed");
+    const receipt = await signTx.addSigners(guardianB).confirm();
+    const events = (receipt.response.meta?.logMessages ?? [])
+      .filter((log) => log.startsWith("Program data: "))
+      .map((log) =>
+        smalletWrapper.program.coder.events.decode(
+          log.slice("Program data: ".length)
+        )
+      );
+    const event = events.find((e) => e?.name === "GuardianActionPerformEvent");
+    expect(event).to.exist;
+    expect(event?.data.guardianAction).to.eqAddress(guardianActionKey);
+
+    action = await smalletWrapper.fetchGuardianAction(guardianActionKey);
+    expect(action.performed).to.be.true;
+    expect((await smalletWrapper.reloadData()).threshold).to.be.bignumber.equal(
+      new BN(2)
+    );
+  });
+
+  it("a quorum can delay the action", async () => {
+    await expectTX(
+      (
+        await smalletWrapper.lockSmallet({ guardian: guardianA.publicKey })
+      ).addSigners(guardianA),
+      "lock as guardianA"
+    ).to.be.fulfilled;
+
+    const { tx, guardianActionKey, index } =
+      await smalletWrapper.newGuardianAction({
+        guardian: guardianA.publicKey,
+        actionType: GuardianActionType.UnlockSmallet,
+      });
+    invariant(tx, "guardian action was not created");
+    await expectTX(tx.addSigners(guardianA), "guardianA requests unlock").to.be
+      .fulfilled;
+    let action = await smalletWrapper.fetchGuardianAction(guardianActionKey);
+    expect(action.performed).to.be.false;
+
+    await sleep((unlockDelay + 1) * 1000);
+    const { tx: signTx } = await smalletWrapper.signGuardianActionAndTry({
+      guardian: guardianA.publicKey,
+      index,
+    });
+    invariant(signTx, "guardian action was not signed");
+    await expectTX(signTx.addSigners(guardianA), "retry after the delay").to.be
+      .fulfilled;
+
+    action = await smalletWrapper.fetchGuardianAction(guardianActionKey);
+    expect(action.performed).to.be.true;
+    expect((await smalletWrapper.reloadData()).locked).to.be.false;
+  });
+
+  it("rejects actions whose delay exceeds their expiry", async () => {
+    const { tx } = await smalletWrapper.newGuardianAction({
+      guardian: guardianA.publicKey,
+      actionType: GuardianActionType.ReplaceOwner,
+      oldOwner: ownerB.publicKey,
+      newOwner: Keypair.generate().publicKey,
+    });
+    invariant(tx, "guardian action was not created");
+    await expectTX(tx.addSigners(guardianA), "replace owner").to.be.rejectedWith(
+      `0x${SmalletErrors.QuorumDelayExceedsExpiry.code.toString(16)}`
+    );
+  });
+
+  it("a quorum never goes below the smallet policy", async () => {
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setGuardianPolicy({
+        changePeriod: new BN(0),
+        actionExpiresTime: new BN(60),
+        minAgreePermyriad: 10000,
+      }).instructions,
+      ownerA
+    );
+    await expectTX(
+      (
+        await smalletWrapper.lockSmallet({ guardian: guardianA.publicKey })
+      ).addSigners(guardianA),
+      "lock as guardianA"
+    ).to.be.fulfilled;
+
+    const { tx, guardianActionKey, index } =
+      await smalletWrapper.newGuardianAction({
+        guardian: guardianA.publicKey,
+        actionType: GuardianActionType.UnlockSmallet,
+      });
+    invariant(tx, "guardian action was not created");
+    await expectTX(tx.addSigners(guardianA), "guardianA requests unlock").to.be
+      .fulfilled;
+
+    await sleep((unlockDelay + 1) * 1000);
+    const { tx: retryTx } = await smalletWrapper.signGuardianActionAndTry({
+      guardian: guardianA.publicKey,
+      index,
+    });
+    invariant(retryTx, "guardian action was not signed");
+    await expectTX(retryTx.addSigners(guardianA), "retry after the delay").to
+      .be.fulfilled;
+    let action = await smalletWrapper.fetchGuardianAction(guardianActionKey);
+    expect(action.performed).to.be.false;
+
+    const { tx: signTx } = await smalletWrapper.signGuardianActionAndTry({
+      guardian: guardianB.publicKey,
+      index,
+    });
+    invariant(signTx, "guardian action was not signed");
+    await expectTX(signTx.addSigners(guardianB), "guardianB agrees").to.be
+      .fulfilled;
+
+    action = await smalletWrapper.fetchGuardianAction(guardianActionKey);
+    expect(action.performed).to.be.true;
+    expect((await smalletWrapper.reloadData()).locked).to.be.false;
+  });
+});
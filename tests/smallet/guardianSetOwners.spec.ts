@@ -0,0 +1,121 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import invariant from "tiny-invariant";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { GuardianActionType } from "../../src/wrappers/smallet";
+import { ensureGlobalState } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the guardian SetOwners tests
+describe("guardian set owners", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+  const { provider } = sdk;
+
+  const ownerA = Keypair.generate();
+  const ownerB = Keypair.generate();
+  const guardian = Keypair.generate();
+  const heir = Keypair.generate();
+
+  let smalletWrapper: SmalletWrapper;
+
+  // Creates a SetOwners guardian action signed by the only guardian
+  const setOwnersAction = async (newAddresses: anchor.web3.PublicKey[]) => {
+    const { tx } = await smalletWrapper.newGuardianAction({
+      guardian: guardian.publicKey,
+      actionType: GuardianActionType.SetOwners,
+      newAddresses,
+    });
+    invariant(tx, "guardian action was not created");
+    return tx.addSigners(guardian);
+  };
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 2,
+      owners: [ownerA.publicKey, ownerB.publicKey],
+      numGuardians: 1,
+      guardians: [guardian.publicKey],
+      threshold: new BN(2),
+      guardianPolicy: {
+        changePeriod: new BN(0),
+        actionExpiresTime: new BN(3600),
+        minAgreePermyriad: 5000,
+      },
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+  });
+
+  it("rejects duplicate owners", async () => {
+    await expectTX(
+      await setOwnersAction([heir.publicKey, heir.publicKey]),
+      "duplicate owners"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.DuplicateAddresses.code.toString(16)}`
+    );
+  });
+
+  it("rejects owners beyond the smallet capacity", async () => {
+    const owners = Array.from(
+      { length: 16 },
+      () => Keypair.generate().publicKey
+    );
+    await expectTX(await setOwnersAction(owners), "too many owners").to.be
+      .rejectedWith(
+        `0x${SmalletErrors.SmalletCapacityExceeded.code.toString(16)}`
+      );
+  });
+
+  it("lowers the threshold and invalidates pending transactions", async () => {
+    const { transactionKey, tx } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions: smalletWrapper.changeThreshold(1).instructions,
+    });
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+    await expectTX(
+      smalletWrapper
+        .approveTransaction(transactionKey, ownerB.publicKey)
+        .addSigners(ownerB),
+      "ownerB approves"
+    ).to.be.fulfilled;
+
+    await expectTX(await setOwnersAction([heir.publicKey]), "set owners").to
+      .be.fulfilled;
+    const data = await smalletWrapper.reloadData();
+    expect(data.owners).to.deep.equal([heir.publicKey]);
+    expect(data.threshold).to.be.bignumber.equal(new BN(1));
+    expect(data.ownerSetSeqno).to.equal(1);
+
+    // Approvals of the previous owners no longer count
+    await expectTX(
+      (
+        await smalletWrapper.executeTransaction({
+          transactionKey,
+          owner: heir.publicKey,
+        })
+      ).addSigners(heir),
+      "execute with the old owner set"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.OwnerSetChanged.code.toString(16)}`
+    );
+  });
+
+  it("the global admin sets owners at once", async () => {
+    const { tx } = await smalletWrapper.newGuardianAction({
+      guardian: provider.wallet.publicKey,
+      actionType: GuardianActionType.SetOwners,
+      newAddresses: [ownerA.publicKey, ownerB.publicKey],
+    });
+    invariant(tx, "guardian action was not created");
+    await expectTX(tx, "set owners as global admin").to.be.fulfilled;
+
+    const data = await smalletWrapper.reloadData();
+    expect(data.owners).to.deep.equal([ownerA.publicKey, ownerB.publicKey]);
+  });
+});
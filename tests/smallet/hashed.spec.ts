@@ -0,0 +1,127 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import type { TransactionInstruction } from "@solana/web3.js";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { ensureGlobalState } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the hash-committed proposal tests
+describe("hashed proposals", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const ownerA = Keypair.generate();
+
+  let smalletWrapper: SmalletWrapper;
+
+  // Proposes the hash of the instructions as ownerA
+  const proposeHashed = async (instructions: TransactionInstruction[]) => {
+    const { transactionKey, tx } = await smalletWrapper.newHashedTransaction({
+      proposer: ownerA.publicKey,
+      instructions,
+    });
+    await expectTX(tx.addSigners(ownerA), "propose hashed").to.be.fulfilled;
+    return transactionKey;
+  };
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 1,
+      owners: [ownerA.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+  });
+
+  it("stores only the hash of the instructions", async () => {
+    const transactionKey = await proposeHashed(
+      smalletWrapper.setLockDuration(100).instructions
+    );
+    const data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.instructions).to.be.empty;
+    expect(data.instructionsHash.some((byte) => byte !== 0)).to.be.true;
+
+    // The instructions are not stored, so they must be supplied
+    await expectTX(
+      (
+        await smalletWrapper.executeTransaction({
+          transactionKey,
+          owner: ownerA.publicKey,
+        })
+      ).addSigners(ownerA),
+      "execute without instructions"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.MissingInstructions.code.toString(16)}`
+    );
+  });
+
+  it("rejects instructions other than the committed ones", async () => {
+    const transactionKey = await proposeHashed(
+      smalletWrapper.setLockDuration(200).instructions
+    );
+    await expectTX(
+      (
+        await smalletWrapper.executeHashedTransaction({
+          transactionKey,
+          instructions: smalletWrapper.setLockDuration(201).instructions,
+          owner: ownerA.publicKey,
+        })
+      ).addSigners(ownerA),
+      "execute other instructions"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.InstructionsHashMismatch.code.toString(16)}`
+    );
+  });
+
+  it("executes with the committed instructions", async () => {
+    const instructions = smalletWrapper.setLockDuration(300).instructions;
+    const transactionKey = await proposeHashed(instructions);
+    await expectTX(
+      (
+        await smalletWrapper.executeHashedTransaction({
+          transactionKey,
+          instructions,
+          owner: ownerA.publicKey,
+        })
+      ).addSigners(ownerA),
+      "execute hashed"
+    ).to.be.fulfilled;
+    expect(
+      (await smalletWrapper.reloadData()).lockDuration.toNumber()
+    ).to.equal(300);
+  });
+
+  it("executes hashed proposals step by step", async () => {
+    const instructions = [
+      ...smalletWrapper.setLockDuration(400).instructions,
+      ...smalletWrapper.setInactivityPeriod(500).instructions,
+    ];
+    const transactionKey = await proposeHashed(instructions);
+    for (const step of [0, 1]) {
+      await expectTX(
+        (
+          await smalletWrapper.executeTransactionStep({
+            transactionKey,
+            step,
+            instructions,
+            owner: ownerA.publicKey,
+          })
+        ).addSigners(ownerA),
+        `execute step ${step}`
+      ).to.be.fulfilled;
+    }
+    const data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.executedAt.toNumber()).to.not.equal(-1);
+    const smallet = await smalletWrapper.reloadData();
+    expect(smallet.lockDuration.toNumber()).to.equal(400);
+    expect(smallet.inactivityPeriod.toNumber()).to.equal(500);
+  });
+});
@@ -0,0 +1,124 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import invariant from "tiny-invariant";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { GuardianActionType } from "../../src/wrappers/smallet";
+import { ensureGlobalState, executeAsSmallet, sleep } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the inheritance tests
+describe("inheritance", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const ownerA = Keypair.generate();
+  const guardian = Keypair.generate();
+  const heir = Keypair.generate();
+  const inactivityPeriod = 3;
+
+  let smalletWrapper: SmalletWrapper;
+
+  // Creates a guardian action of `actionType` signed by the only guardian
+  const guardianAction = async (
+    actionType: GuardianActionType,
+    newAddresses = [heir.publicKey]
+  ) => {
+    const { tx } = await smalletWrapper.newGuardianAction({
+      guardian: guardian.publicKey,
+      actionType,
+      newAddresses,
+    });
+    invariant(tx, "guardian action was not created");
+    return tx.addSigners(guardian);
+  };
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 1,
+      owners: [ownerA.publicKey],
+      numGuardians: 1,
+      guardians: [guardian.publicKey],
+      threshold: new BN(1),
+      guardianPolicy: {
+        changePeriod: new BN(0),
+        actionExpiresTime: new BN(3600),
+        minAgreePermyriad: 5000,
+      },
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setInactivityPeriod(inactivityPeriod).instructions,
+      ownerA
+    );
+    const data = await smalletWrapper.reloadData();
+    expect(data.inactivityPeriod).to.be.bignumber.equal(
+      new BN(inactivityPeriod)
+    );
+  });
+
+  it("guardians cannot inherit active owners", async () => {
+    await expectTX(
+      await guardianAction(GuardianActionType.InheritOwners),
+      "inherit active owners"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.OwnersStillActive.code.toString(16)}`
+    );
+  });
+
+  it("a heartbeat keeps the owners active", async () => {
+    await sleep((inactivityPeriod + 1) * 1000);
+    await expectTX(
+      smalletWrapper.heartbeat(ownerA.publicKey).addSigners(ownerA),
+      "heartbeat"
+    ).to.be.fulfilled;
+
+    await expectTX(
+      await guardianAction(GuardianActionType.InheritOwners),
+      "inherit after a heartbeat"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.OwnersStillActive.code.toString(16)}`
+    );
+  });
+
+  it("owners cannot send heartbeats while locked", async () => {
+    await expectTX(
+      (
+        await smalletWrapper.lockSmallet({ guardian: guardian.publicKey })
+      ).addSigners(guardian),
+      "lock as guardian"
+    ).to.be.fulfilled;
+
+    await expectTX(
+      smalletWrapper.heartbeat(ownerA.publicKey).addSigners(ownerA),
+      "heartbeat while locked"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.AccountLocked.code.toString(16)}`
+    );
+
+    await expectTX(
+      await guardianAction(GuardianActionType.UnlockSmallet, []),
+      "unlock as guardian"
+    ).to.be.fulfilled;
+    expect((await smalletWrapper.reloadData()).locked).to.be.false;
+  });
+
+  it("guardians inherit inactive owners", async () => {
+    await sleep((inactivityPeriod + 1) * 1000);
+    await expectTX(
+      await guardianAction(GuardianActionType.InheritOwners),
+      "inherit inactive owners"
+    ).to.be.fulfilled;
+
+    const data = await smalletWrapper.reloadData();
+    expect(data.owners).to.deep.equal([heir.publicKey]);
+    expect(data.ownerSetSeqno).to.equal(1);
+  });
+});
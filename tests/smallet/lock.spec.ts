@@ -0,0 +1,96 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { ensureGlobalState, executeAsSmallet, sleep } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the guardian lock tests
+describe("lock smallet", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const ownerA = Keypair.generate();
+  const guardian = Keypair.generate();
+  const lockDuration = 5;
+
+  let smalletWrapper: SmalletWrapper;
+
+  const proposeThresholdChange = async () => {
+    const { tx } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions: smalletWrapper.changeThreshold(1).instructions,
+    });
+    return tx.addSigners(ownerA);
+  };
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 1,
+      owners: [ownerA.publicKey],
+      numGuardians: 1,
+      guardians: [guardian.publicKey],
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setLockDuration(lockDuration).instructions,
+      ownerA
+    );
+    const data = await smalletWrapper.reloadData();
+    expect(data.lockDuration).to.be.bignumber.equal(new BN(lockDuration));
+  });
+
+  it("only guardians can lock", async () => {
+    const stranger = Keypair.generate();
+    await expectTX(
+      (
+        await smalletWrapper.lockSmallet({ guardian: stranger.publicKey })
+      ).addSigners(stranger),
+      "lock as a stranger"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.InvalidGuardian.code.toString(16)}`
+    );
+  });
+
+  it("a guardian locks the smallet", async () => {
+    await expectTX(
+      (
+        await smalletWrapper.lockSmallet({ guardian: guardian.publicKey })
+      ).addSigners(guardian),
+      "lock as guardian"
+    ).to.be.fulfilled;
+    const data = await smalletWrapper.reloadData();
+    expect(data.locked).to.be.true;
+
+    await expectTX(await proposeThresholdChange(), "propose while locked").to
+      .be.rejectedWith(`0x${SmalletErrors.AccountLocked.code.toString(16)}`);
+  });
+
+  it("locking again does not extend the lock", async () => {
+    const { lockedAt } = await smalletWrapper.reloadData();
+    await sleep(1500);
+    await expectTX(
+      (
+        await smalletWrapper.lockSmallet({ guardian: guardian.publicKey })
+      ).addSigners(guardian),
+      "lock again as guardian"
+    ).to.be.fulfilled;
+
+    const data = await smalletWrapper.reloadData();
+    expect(data.lockedAt).to.be.bignumber.equal(lockedAt);
+  });
+
+  it("the lock expires after the lock duration", async () => {
+    await sleep((lockDuration + 1) * 1000);
+    await expectTX(await proposeThresholdChange(), "propose after the lock").to
+      .be.fulfilled;
+  });
+});
@@ -0,0 +1,176 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { TransactionEnvelope } from "@saberhq/solana-contrib";
+import {
+  createAccount,
+  createMint,
+  createTransferInstruction,
+  getAccount,
+  mintTo,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { AddressLookupTableProgram, Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { createHash } from "crypto";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { airdropLamports, ensureGlobalState } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the address lookup table tests
+describe("lookup tables", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+  const { provider } = sdk;
+
+  const ownerA = Keypair.generate();
+  const payer = Keypair.generate();
+
+  let smalletWrapper: SmalletWrapper;
+  let derivedWallet: PublicKey;
+  let source: PublicKey;
+  let destination: PublicKey;
+  let lookupTable: PublicKey;
+
+  // Transfers `amount` tokens to the destination resolved from the lookup
+  // table, which sits between the source and the authority
+  const proposeTransfer = async (
+    amount: number,
+    position: number,
+    addressIndex = 0
+  ) => {
+    const ix = createTransferInstruction(
+      source,
+      destination,
+      derivedWallet,
+      amount
+    );
+    ix.keys = ix.keys.filter((key) => !key.pubkey.equals(destination));
+    return await smalletWrapper.newTransactionWithLookupTables({
+      proposer: ownerA.publicKey,
+      instructions: [ix],
+      lookupTables: [lookupTable],
+      lookupAccounts: [
+        {
+          instructionIndex: 0,
+          tableIndex: 0,
+          addressIndex,
+          isWritable: true,
+          position,
+        },
+      ],
+    });
+  };
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    await airdropLamports(provider.connection, payer.publicKey);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 1,
+      owners: [ownerA.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+    [derivedWallet] = await smalletWrapper.findWalletDerivedAddress(0);
+
+    const mint = await createMint(
+      provider.connection,
+      payer,
+      payer.publicKey,
+      null,
+      0
+    );
+    source = await createAccount(
+      provider.connection,
+      payer,
+      mint,
+      derivedWallet,
+      Keypair.generate()
+    );
+    destination = await createAccount(
+      provider.connection,
+      payer,
+      mint,
+      payer.publicKey,
+      Keypair.generate()
+    );
+    await mintTo(provider.connection, payer, mint, source, payer, 100);
+
+    const [createIx, table] = AddressLookupTableProgram.createLookupTable({
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+      recentSlot: await provider.connection.getSlot("finalized"),
+    });
+    const extendIx = AddressLookupTableProgram.extendLookupTable({
+      lookupTable: table,
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+      addresses: [destination],
+    });
+    await expectTX(
+      new TransactionEnvelope(provider, [createIx, extendIx]),
+      "create lookup table"
+    ).to.be.fulfilled;
+    lookupTable = table;
+  });
+
+  it("inserts lookup accounts at their position", async () => {
+    const { transactionKey, tx } = await proposeTransfer(10, 1);
+    await expectTX(tx.addSigners(ownerA), "propose transfer").to.be.fulfilled;
+
+    const data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.lookupAccounts[0]?.position).to.equal(1);
+    // The resolved destination is recorded when proposing
+    expect(Buffer.from(data.lookupAddressesHash)).to.deep.equal(
+      createHash("sha256").update(destination.toBuffer()).digest()
+    );
+
+    const executeTx = await smalletWrapper.executeTransactionDerived({
+      transactionKey,
+      walletIndex: 0,
+      owner: ownerA.publicKey,
+    });
+    await expectTX(executeTx.addSigners(ownerA), "execute transfer").to.be
+      .fulfilled;
+
+    const account = await getAccount(provider.connection, destination);
+    expect(account.amount.toString()).to.equal("10");
+  });
+
+  it("rejects a position beyond the keys of its instruction", async () => {
+    const { tx } = await proposeTransfer(10, 3);
+    await expectTX(
+      tx.addSigners(ownerA),
+      "propose transfer"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.InvalidLookupAccount.code.toString(16)}`
+    );
+  });
+
+  it("rejects an address beyond the end of the table", async () => {
+    const { tx } = await proposeTransfer(10, 1, 1);
+    await expectTX(
+      tx.addSigners(ownerA),
+      "propose transfer"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.InvalidLookupAccount.code.toString(16)}`
+    );
+  });
+
+  it("requires the lookup tables when proposing", async () => {
+    const { tx } = await proposeTransfer(10, 1);
+    for (const ix of tx.instructions) {
+      ix.keys = ix.keys.filter((key) => !key.pubkey.equals(lookupTable));
+    }
+    await expectTX(
+      tx.addSigners(ownerA),
+      "propose without the table"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.MissingLookupTable.code.toString(16)}`
+    );
+  });
+});
@@ -18,11 +18,13 @@ import invariant from "tiny-invariant";
 import { SmalletErrors } from "../../src/idls/smallet";
 import type { SmalletWrapper } from "../../src/wrappers/smallet";
 import {
+  findGlobalState,
   findSmallet,
   findSubaccountInfoAddress,
   findTransactionAddress,
   findWalletDerivedAddress,
 } from "../../src/wrappers/smallet";
+import { ensureGlobalState } from "../common";
 import { makeSDK } from "../workspace";
 
 // Define the smallet tests
@@ -35,8 +37,7 @@ describe("smallet", () => {
     const { provider } = sdk;
 
     before(async () => {
-      const { tx } = await sdk.initializeGlobalState();
-      await expectTX(tx, "Initialize global pool").to.be.fulfilled;
+      await ensureGlobalState(sdk);
       await sdk.reloadGlobalData();
 
       expect(sdk.globalData?.globalAdmin).to.eqAddress(
@@ -58,12 +59,90 @@ describe("smallet", () => {
       const { tx } = await sdk.setGlobalThresholds({
         changePeriod: 36,
       });
+      const before = await sdk.reloadGlobalData();
       await expectTX(tx, "Change guadians change period").to.be.fulfilled;
       await sdk.reloadGlobalData();
 
-      expect(sdk.globalData?.guadiansChangePeriod).to.be.bignumber.equal(
-        new BN(36)
+      // The change is only scheduled until the notice period passes
+      expect(sdk.globalData?.guardiansChangePeriod).to.be.bignumber.equal(
+        before.guardiansChangePeriod
       );
+      expect(
+        sdk.globalData?.pendingThresholds.changePeriod
+      ).to.be.bignumber.equal(new BN(36));
+      expect(
+        sdk.globalData?.thresholdsActivateAt.toNumber()
+      ).to.be.greaterThan(0);
+
+      const { tx: activateTx } = await sdk.activateGlobalThresholds();
+      await expectTX(
+        activateTx,
+        "Activate before notice period"
+      ).to.be.rejectedWith(
+        `0x${SmalletErrors.ThresholdsNoticePeriodNotPassed.code.toString(16)}`
+      );
+    });
+
+    it("Test partial global thresholds change keeps the scheduled values", async () => {
+      const { tx } = await sdk.setGlobalThresholds({
+        minAgreePermyriad: 6000,
+      });
+      await expectTX(tx, "Change min agree permyriad").to.be.fulfilled;
+      await sdk.reloadGlobalData();
+
+      expect(
+        sdk.globalData?.pendingThresholds.changePeriod
+      ).to.be.bignumber.equal(new BN(36));
+      expect(sdk.globalData?.pendingThresholds.minAgreePermyriad).to.equal(
+        6000
+      );
+    });
+
+    it("Test smallet joins the domain of its global state", async () => {
+      const domain = new BN(7);
+      const { tx } = await sdk.initializeGlobalState({ domain });
+      await expectTX(tx, "Initialize global state of domain").to.be.fulfilled;
+      const globalData = await sdk.reloadGlobalData(domain);
+      expect(globalData.domain).to.be.bignumber.equal(domain);
+      expect(globalData.globalAdmin).to.eqAddress(provider.wallet.publicKey);
+
+      const owners = [provider.wallet.publicKey];
+      const { smalletWrapper, tx: createTx } = await sdk.newSmallet({
+        numOwners: owners.length,
+        owners,
+        numGuardians: owners.length,
+        guardians: owners,
+        threshold: new BN(1),
+        domain,
+      });
+      await expectTX(createTx, "create smallet in domain").to.be.fulfilled;
+      const data = await smalletWrapper.reloadData();
+      expect(data.domain).to.be.bignumber.equal(domain);
+
+      // The admin of another domain has no authority over the smallet
+      const [defaultGlobalState] = await findGlobalState();
+      const ix = program.instruction.setFrozenAdmin(true, {
+        accounts: {
+          smallet: smalletWrapper.key,
+          globalState: defaultGlobalState,
+          admin: provider.wallet.publicKey,
+        },
+      });
+      await expectTX(
+        new TransactionEnvelope(provider, [ix]),
+        "freeze with another domain"
+      ).to.be.rejectedWith(
+        `0x${SmalletErrors.InvalidDomain.code.toString(16)}`
+      );
+
+      await expectTX(
+        await smalletWrapper.setFrozenAdmin({
+          admin: provider.wallet.publicKey,
+          frozen: true,
+        }),
+        "freeze with own domain"
+      ).to.be.fulfilled;
+      expect((await smalletWrapper.reloadData()).frozen).to.be.true;
     });
   });
 
@@ -0,0 +1,182 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { readFileSync } from "fs";
+import path from "path";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import { getSmalletSpaceSize, OwnerRole } from "../../src/wrappers/smallet";
+import { makeSDK } from "../workspace";
+
+// Address of an account loaded from tests/fixtures by Anchor.toml
+const fixtureAddress = (name: string): PublicKey => {
+  const file = path.join(__dirname, "../fixtures", `${name}.json`);
+  const fixture = JSON.parse(readFileSync(file, "utf8")) as { pubkey: string };
+  return new PublicKey(fixture.pubkey);
+};
+
+// Define the account migration tests
+describe("migration", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+  const accounts = sdk.programs.Smallet.account;
+
+  const legacyGlobalState = fixtureAddress("legacy-global-state");
+  const legacySmallet = fixtureAddress("legacy-smallet");
+  const legacySmalletV1 = fixtureAddress("legacy-smallet-v1");
+  // Two owners in an account allocated for three
+  const legacySmalletFull = fixtureAddress("legacy-smallet-full");
+  const legacyTransaction = fixtureAddress("legacy-transaction");
+  const legacyTransactionV2 = fixtureAddress("legacy-transaction-v2");
+
+  it("migrates a global state without a version", async () => {
+    await expectTX(
+      sdk.migrateAccount({ kind: "globalState", account: legacyGlobalState }),
+      "migrate global state"
+    ).to.be.fulfilled;
+
+    const globalState = await accounts.globalState.fetch(legacyGlobalState);
+    expect(globalState.version).to.equal(1);
+    expect(globalState.minAgreePermyriad).to.equal(5000);
+    expect(globalState.domain.toNumber()).to.equal(0);
+  });
+
+  it("migrates a smallet without a version", async () => {
+    await expectTX(
+      sdk.migrateAccount({ kind: "smallet", account: legacySmallet }),
+      "migrate smallet"
+    ).to.be.fulfilled;
+
+    const smallet = await accounts.smallet.fetch(legacySmallet);
+    expect(smallet.version).to.equal(2);
+    expect(smallet.threshold.toNumber()).to.equal(1);
+    expect(smallet.numTransactions.toNumber()).to.equal(1);
+    expect(smallet.owners).to.have.lengthOf(1);
+    // Legacy owners keep every permission
+    expect([...smallet.ownerRoles]).to.deep.equal([OwnerRole.All]);
+    expect(smallet.domain.toNumber()).to.equal(0);
+  });
+
+  it("migrates a version 1 smallet", async () => {
+    await expectTX(
+      sdk.migrateAccount({ kind: "smallet", account: legacySmalletV1 }),
+      "migrate smallet"
+    ).to.be.fulfilled;
+
+    const smallet = await accounts.smallet.fetch(legacySmalletV1);
+    expect(smallet.version).to.equal(2);
+    expect([...smallet.ownerRoles]).to.deep.equal([
+      OwnerRole.Proposer | OwnerRole.Approver | OwnerRole.Executor,
+    ]);
+    expect(smallet.pendingTransactions.toNumber()).to.equal(1);
+    expect(smallet.sequential).to.equal(false);
+  });
+
+  it("keeps the spare capacity of a migrated smallet", async () => {
+    await expectTX(
+      sdk.migrateAccount({ kind: "smallet", account: legacySmalletFull }),
+      "migrate smallet"
+    ).to.be.fulfilled;
+
+    const info = await sdk.provider.connection.getAccountInfo(
+      legacySmalletFull
+    );
+    const space = getSmalletSpaceSize(3, 1);
+    expect(info?.data.length).to.equal(space);
+
+    // The third owner still fits next to full vectors and the optional policy
+    const smallet = await accounts.smallet.fetch(legacySmalletFull);
+    expect(smallet.owners).to.have.lengthOf(2);
+    const encoded = await sdk.programs.Smallet.coder.accounts.encode(
+      "Smallet",
+      {
+        ...smallet,
+        owners: [...smallet.owners, smallet.base],
+        ownerSessions: [new BN(0), new BN(0), new BN(0)],
+        ownerRoles: [OwnerRole.All, OwnerRole.All, OwnerRole.All],
+        valueTiers: Array.from({ length: 8 }, () => ({
+          mint: smallet.base,
+          maxAmount: new BN(0),
+          threshold: new BN(1),
+        })),
+        guardianPolicy: {
+          changePeriod: new BN(0),
+          actionExpiresTime: new BN(0),
+          minAgreePermyriad: 0,
+        },
+        guardianQuorums: Array.from({ length: 8 }, () => ({
+          actionKind: { noAction: {} },
+          minAgreePermyriad: 0,
+          delay: new BN(0),
+        })),
+      }
+    );
+    expect(encoded.length).to.equal(space);
+  });
+
+  it("migrates a transaction without a version", async () => {
+    await expectTX(
+      sdk.migrateAccount({
+        kind: "transaction",
+        account: legacyTransaction,
+        smallet: legacySmallet,
+      }),
+      "migrate transaction"
+    ).to.be.fulfilled;
+
+    const tx = await accounts.transaction.fetch(legacyTransaction);
+    expect(tx.version).to.equal(4);
+    expect(tx.smallet).to.eqAddress(legacySmallet);
+    expect(tx.signers).to.deep.equal([true]);
+    expect(tx.executedAt.toNumber()).to.equal(-1);
+    expect(tx.lookupTables).to.be.empty;
+    // Without an ETA, it expires a grace period after the migration
+    const smallet = await accounts.smallet.fetch(legacySmallet);
+    const now = Math.floor(Date.now() / 1000);
+    expect(tx.expiresAt.toNumber()).to.be.within(
+      now + smallet.gracePeriod.toNumber() - 60,
+      now + smallet.gracePeriod.toNumber() + 60
+    );
+  });
+
+  it("migrates a version 2 transaction", async () => {
+    await expectTX(
+      sdk.migrateAccount({
+        kind: "transaction",
+        account: legacyTransactionV2,
+        smallet: legacySmalletV1,
+      }),
+      "migrate transaction"
+    ).to.be.fulfilled;
+
+    const tx = await accounts.transaction.fetch(legacyTransactionV2);
+    expect(tx.version).to.equal(4);
+    expect(tx.smallet).to.eqAddress(legacySmalletV1);
+    expect(tx.executedSteps.toNumber()).to.equal(0);
+    expect(tx.prerequisites).to.be.empty;
+    expect(tx.expiresAt.toNumber()).to.not.equal(0);
+  });
+
+  it("rejects migrating a transaction with another smallet", async () => {
+    await expectTX(
+      sdk.migrateAccount({
+        kind: "transaction",
+        account: legacyTransactionV2,
+        smallet: legacySmallet,
+      }),
+      "migrate transaction"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.NotSmalletAccount.code.toString(16)}`
+    );
+  });
+
+  it("keeps migrated accounts unchanged when migrated again", async () => {
+    const before = await accounts.smallet.fetch(legacySmallet);
+    await expectTX(
+      sdk.migrateAccount({ kind: "smallet", account: legacySmallet }),
+      "migrate smallet again"
+    ).to.be.fulfilled;
+    expect(await accounts.smallet.fetch(legacySmallet)).to.deep.equal(before);
+  });
+});
@@ -0,0 +1,98 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import { ensureGlobalState, executeAsSmallet } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the owner set validation tests
+describe("owner set validation", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const ownerA = Keypair.generate();
+  const ownerB = Keypair.generate();
+
+  // Creates a smallet guarded by ownerA and expects it to fail with `code`
+  const expectCreateRejected = async (
+    owners: PublicKey[],
+    threshold: number,
+    code: number
+  ) => {
+    const { tx } = await sdk.newSmallet({
+      numOwners: 3,
+      owners,
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(threshold),
+    });
+    await expectTX(tx, "create invalid smallet").to.be.rejectedWith(
+      `0x${code.toString(16)}`
+    );
+  };
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+  });
+
+  it("rejects duplicate owners", async () => {
+    await expectCreateRejected(
+      [ownerA.publicKey, ownerA.publicKey],
+      1,
+      SmalletErrors.DuplicateAddresses.code
+    );
+  });
+
+  it("rejects the default pubkey as an owner", async () => {
+    await expectCreateRejected(
+      [ownerA.publicKey, anchor.web3.PublicKey.default],
+      1,
+      SmalletErrors.DefaultAddress.code
+    );
+  });
+
+  it("rejects a zero threshold", async () => {
+    await expectCreateRejected(
+      [ownerA.publicKey, ownerB.publicKey],
+      0,
+      SmalletErrors.ZeroThreshold.code
+    );
+  });
+
+  it("rejects a threshold above the number of owners", async () => {
+    await expectCreateRejected(
+      [ownerA.publicKey, ownerB.publicKey],
+      3,
+      SmalletErrors.InvalidThreshold.code
+    );
+  });
+
+  it("rejects removing owners below the threshold", async () => {
+    const { smalletWrapper, tx } = await sdk.newSmallet({
+      numOwners: 3,
+      owners: [ownerA.publicKey, ownerB.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(2),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+
+    try {
+      await executeAsSmallet(
+        smalletWrapper,
+        smalletWrapper.setOwners([ownerA.publicKey]).instructions,
+        ownerA,
+        [ownerB]
+      );
+      expect.fail("owner set below the threshold was accepted");
+    } catch (e) {
+      const err = e as Error;
+      expect(err.message).to.include(
+        `0x${SmalletErrors.InvalidThreshold.code.toString(16)}`
+      );
+    }
+  });
+});
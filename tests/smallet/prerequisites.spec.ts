@@ -0,0 +1,229 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import type { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { OwnerRole } from "../../src/wrappers/smallet";
+import { ensureGlobalState, executeAsSmallet } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the execution order and cancellation tests
+describe("prerequisites", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const ownerA = Keypair.generate();
+  const ownerB = Keypair.generate();
+
+  let smalletWrapper: SmalletWrapper;
+
+  // Proposes the instructions as ownerA, whose approval is enough
+  const propose = async (
+    instructions: TransactionInstruction[],
+    prerequisites?: number[]
+  ) => {
+    const { transactionKey, tx, index } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions,
+      prerequisites,
+    });
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+    return { transactionKey, index };
+  };
+
+  const execute = async (transactionKey: PublicKey) =>
+    (
+      await smalletWrapper.executeTransaction({
+        transactionKey,
+        owner: ownerA.publicKey,
+      })
+    ).addSigners(ownerA);
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 2,
+      owners: [ownerA.publicKey, ownerB.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+  });
+
+  it("waits for the prerequisites to be executed", async () => {
+    const first = await propose(
+      smalletWrapper.setLockDuration(100).instructions
+    );
+    const second = await propose(
+      smalletWrapper.setLockDuration(200).instructions,
+      [first.index]
+    );
+    const data = await smalletWrapper.fetchTransaction(second.transactionKey);
+    expect(data.prerequisites.map((index) => index.toNumber())).to.deep.equal([
+      first.index,
+    ]);
+
+    await expectTX(
+      await execute(second.transactionKey),
+      "execute before the prerequisite"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.PrerequisiteNotExecuted.code.toString(16)}`
+    );
+    await expectTX(await execute(first.transactionKey), "execute first").to.be
+      .fulfilled;
+    await expectTX(await execute(second.transactionKey), "execute second").to
+      .be.fulfilled;
+    expect(
+      (await smalletWrapper.reloadData()).lockDuration.toNumber()
+    ).to.equal(200);
+  });
+
+  it("rejects prerequisites that were not created", async () => {
+    const { numTransactions } = await smalletWrapper.reloadData();
+    const { tx } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions: smalletWrapper.setLockDuration(300).instructions,
+      prerequisites: [numTransactions.toNumber()],
+    });
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.rejectedWith(
+      `0x${SmalletErrors.InvalidPrerequisite.code.toString(16)}`
+    );
+  });
+
+  it("combines prerequisites with the other options", async () => {
+    const first = await propose(
+      smalletWrapper.setLockDuration(300).instructions
+    );
+    const { transactionKey, tx } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions: smalletWrapper.setLockDuration(400).instructions,
+      prerequisites: [first.index],
+      postAssertions: [
+        { minLamports: { account: smalletWrapper.key, lamports: new BN(0) } },
+      ],
+      hashCommitted: true,
+    });
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+
+    const data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.prerequisites.map((index) => index.toNumber())).to.deep.equal([
+      first.index,
+    ]);
+    expect(data.postAssertions).to.have.lengthOf(1);
+    expect(data.instructions).to.be.empty;
+  });
+
+  it("executes in order in sequential mode", async () => {
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setSequential(true).instructions,
+      ownerA
+    );
+    expect((await smalletWrapper.reloadData()).sequential).to.be.true;
+
+    const first = await propose(
+      smalletWrapper.setLockDuration(400).instructions
+    );
+    const second = await propose(
+      smalletWrapper.setLockDuration(500).instructions
+    );
+    await expectTX(
+      await execute(second.transactionKey),
+      "execute before the previous one"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.PreviousTransactionPending.code.toString(16)}`
+    );
+    await expectTX(await execute(first.transactionKey), "execute first").to.be
+      .fulfilled;
+    await expectTX(await execute(second.transactionKey), "execute second").to
+      .be.fulfilled;
+
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setSequential(false).instructions,
+      ownerA
+    );
+    expect((await smalletWrapper.reloadData()).sequential).to.be.false;
+  });
+
+  it("lets only the proposer cancel", async () => {
+    const { transactionKey } = await propose(
+      smalletWrapper.setLockDuration(600).instructions
+    );
+    const pending = (await smalletWrapper.reloadData()).pendingTransactions;
+
+    await expectTX(
+      smalletWrapper
+        .cancelTransaction(transactionKey, ownerB.publicKey)
+        .addSigners(ownerB),
+      "cancel as another owner"
+    ).to.be.rejected;
+    await expectTX(
+      smalletWrapper
+        .cancelTransaction(transactionKey, ownerA.publicKey)
+        .addSigners(ownerA),
+      "cancel as the proposer"
+    ).to.be.fulfilled;
+
+    const data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.cancelledAt.toNumber()).to.not.equal(0);
+    expect(
+      (await smalletWrapper.reloadData()).pendingTransactions.toNumber()
+    ).to.equal(pending.toNumber() - 1);
+  });
+
+  it("requires the proposer role to cancel", async () => {
+    const { transactionKey } = await propose(
+      smalletWrapper.setLockDuration(700).instructions
+    );
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setOwnerRoles([
+        OwnerRole.Approver | OwnerRole.Executor,
+        OwnerRole.All,
+      ]).instructions,
+      ownerB
+    );
+
+    await expectTX(
+      smalletWrapper
+        .cancelTransaction(transactionKey, ownerA.publicKey)
+        .addSigners(ownerA),
+      "cancel without the proposer role"
+    ).to.be.rejectedWith(`0x${SmalletErrors.MissingRole.code.toString(16)}`);
+
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setOwnerRoles([OwnerRole.All, OwnerRole.All]).instructions,
+      ownerB
+    );
+    await expectTX(
+      smalletWrapper
+        .cancelTransaction(transactionKey, ownerA.publicKey)
+        .addSigners(ownerA),
+      "cancel with the proposer role"
+    ).to.be.fulfilled;
+  });
+
+  it("rejects cancellation while frozen", async () => {
+    const { transactionKey } = await propose(
+      smalletWrapper.setLockDuration(800).instructions
+    );
+    await expectTX(
+      smalletWrapper.emergencyFreeze(ownerA.publicKey).addSigners(ownerA),
+      "freeze"
+    ).to.be.fulfilled;
+
+    await expectTX(
+      smalletWrapper
+        .cancelTransaction(transactionKey, ownerA.publicKey)
+        .addSigners(ownerA),
+      "cancel while frozen"
+    ).to.be.rejectedWith(`0x${SmalletErrors.AccountFrozen.code.toString(16)}`);
+  });
+});
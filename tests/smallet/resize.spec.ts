@@ -0,0 +1,127 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { getSmalletSpaceSize, OwnerRole } from "../../src/wrappers/smallet";
+import { ensureGlobalState, executeAsSmallet } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the smallet resizing tests
+describe("resize smallet", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+  const { provider } = sdk;
+
+  const ownerA = Keypair.generate();
+  const newOwners = [
+    ownerA.publicKey,
+    ...Array.from({ length: 15 }, () => Keypair.generate().publicKey),
+  ];
+
+  let smalletWrapper: SmalletWrapper;
+
+  // Executes the instructions as the smallet, returning the error if any
+  const tryExecute = async (
+    instructions: anchor.web3.TransactionInstruction[]
+  ) => {
+    try {
+      await executeAsSmallet(smalletWrapper, instructions, ownerA);
+    } catch (e) {
+      return e as Error;
+    }
+    return null;
+  };
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 1,
+      owners: [ownerA.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+  });
+
+  it("fits a smallet at full capacity in its space", async () => {
+    const info = await provider.connection.getAccountInfo(smalletWrapper.key);
+    const space = getSmalletSpaceSize(1, 1);
+    expect(info?.data.length).to.equal(space);
+
+    // Every vector is full and the optional policy is set
+    const data = await smalletWrapper.reloadData();
+    const encoded = await smalletWrapper.program.coder.accounts.encode(
+      "Smallet",
+      {
+        ...data,
+        owners: [ownerA.publicKey],
+        ownerSessions: [new BN(0)],
+        ownerRoles: [OwnerRole.All],
+        guardians: [ownerA.publicKey],
+        valueTiers: Array.from({ length: 8 }, () => ({
+          mint: ownerA.publicKey,
+          maxAmount: new BN(0),
+          threshold: new BN(1),
+        })),
+        guardianPolicy: {
+          changePeriod: new BN(0),
+          actionExpiresTime: new BN(0),
+          minAgreePermyriad: 0,
+        },
+        guardianQuorums: Array.from({ length: 8 }, () => ({
+          actionKind: { noAction: {} },
+          minAgreePermyriad: 0,
+          delay: new BN(0),
+        })),
+      }
+    );
+    expect(encoded.length).to.equal(space);
+  });
+
+  it("rejects owners beyond the capacity", async () => {
+    const err = await tryExecute(
+      smalletWrapper.setOwners(newOwners).instructions
+    );
+    expect(err?.message).to.include(
+      `0x${SmalletErrors.SmalletCapacityExceeded.code.toString(16)}`
+    );
+  });
+
+  it("grows the smallet in place", async () => {
+    const err = await tryExecute(
+      smalletWrapper.resizeSmallet({
+        maxOwners: newOwners.length,
+        maxGuardians: 1,
+      }).instructions
+    );
+    expect(err).to.be.null;
+
+    const info = await provider.connection.getAccountInfo(smalletWrapper.key);
+    expect(info?.data.length).to.equal(
+      getSmalletSpaceSize(newOwners.length, 1)
+    );
+
+    expect(
+      await tryExecute(smalletWrapper.setOwners(newOwners).instructions)
+    ).to.be.null;
+    const data = await smalletWrapper.reloadData();
+    expect(data.owners).to.deep.equal(newOwners);
+  });
+
+  it("rejects shrinking the smallet", async () => {
+    const err = await tryExecute(
+      smalletWrapper.resizeSmallet({ maxOwners: 1, maxGuardians: 1 })
+        .instructions
+    );
+    expect(err).to.not.be.null;
+    const info = await provider.connection.getAccountInfo(smalletWrapper.key);
+    expect(info?.data.length).to.equal(
+      getSmalletSpaceSize(newOwners.length, 1)
+    );
+  });
+});
@@ -0,0 +1,123 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import type { TransactionEnvelope } from "@saberhq/solana-contrib";
+import {
+  createAmountToUiAmountInstruction,
+  createGetAccountDataSizeInstruction,
+  createMint,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { airdropLamports, ensureGlobalState } from "../common";
+import { makeSDK } from "../workspace";
+
+type ReturnData = { programId: PublicKey; data: Buffer } | null;
+
+// Define the CPI return data tests
+describe("return data", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+  const { provider } = sdk;
+
+  const ownerA = Keypair.generate();
+  const payer = Keypair.generate();
+
+  let smalletWrapper: SmalletWrapper;
+  let mint: PublicKey;
+
+  // Proposes the instructions as ownerA, whose approval is enough
+  const propose = async (instructions: TransactionInstruction[]) => {
+    const { transactionKey, tx } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions,
+    });
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+    return transactionKey;
+  };
+
+  // Sends the transaction and decodes the smallet events of its logs
+  const sendAndDecodeEvents = async (tx: TransactionEnvelope) => {
+    const receipt = await tx.confirm();
+    const logs = receipt.response.meta?.logMessages ?? [];
+    return logs
+      .filter((log) => log.startsWith("Program data: "))
+      .map((log) =>
+        smalletWrapper.program.coder.events.decode(
+          log.slice("Program data: ".length)
+        )
+      )
+      .filter((event): event is NonNullable<typeof event> => !!event);
+  };
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    await airdropLamports(provider.connection, payer.publicKey);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 1,
+      owners: [ownerA.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+    mint = await createMint(
+      provider.connection,
+      payer,
+      payer.publicKey,
+      null,
+      0
+    );
+  });
+
+  it("emits the return data of each instruction", async () => {
+    const transactionKey = await propose([
+      createGetAccountDataSizeInstruction(mint),
+      createAmountToUiAmountInstruction(mint, 42),
+    ]);
+    const events = await sendAndDecodeEvents(
+      (
+        await smalletWrapper.executeTransaction({
+          transactionKey,
+          owner: ownerA.publicKey,
+        })
+      ).addSigners(ownerA)
+    );
+
+    const event = events.find(({ name }) => name === "TransactionExecuteEvent");
+    expect(event).to.exist;
+    const returnData = event?.data.returnData as ReturnData[];
+    expect(returnData).to.have.lengthOf(2);
+    expect(returnData[0]?.programId).to.eqAddress(TOKEN_PROGRAM_ID);
+    expect(returnData[0]?.data.readBigUInt64LE()).to.equal(BigInt(165));
+    expect(returnData[1]?.data.toString()).to.equal("42");
+  });
+
+  it("emits the return data of each step", async () => {
+    const transactionKey = await propose([
+      createAmountToUiAmountInstruction(mint, 7),
+      createAmountToUiAmountInstruction(mint, 8),
+    ]);
+    const events = await sendAndDecodeEvents(
+      (
+        await smalletWrapper.executeTransactionStep({
+          transactionKey,
+          step: 0,
+          owner: ownerA.publicKey,
+        })
+      ).addSigners(ownerA)
+    );
+
+    const event = events.find(
+      ({ name }) => name === "TransactionStepExecuteEvent"
+    );
+    expect(event).to.exist;
+    const returnData = event?.data.returnData as ReturnData;
+    expect(returnData?.programId).to.eqAddress(TOKEN_PROGRAM_ID);
+    expect(returnData?.data.toString()).to.equal("7");
+  });
+});
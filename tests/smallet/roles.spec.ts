@@ -0,0 +1,128 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { NATIVE_MINT } from "@solana/spl-token";
+import type { TransactionInstruction } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { OwnerRole } from "../../src/wrappers/smallet";
+import { ensureGlobalState, executeAsSmallet } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the owner role tests
+describe("owner roles", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const ownerA = Keypair.generate();
+  const ownerB = Keypair.generate();
+  const ownerC = Keypair.generate();
+  const ownerD = Keypair.generate();
+  const owners = [ownerA.publicKey, ownerB.publicKey, ownerC.publicKey];
+
+  let smalletWrapper: SmalletWrapper;
+
+  // Executes `ix` as the smallet, approved by ownerA and ownerC
+  const executeAuth = (ix: TransactionInstruction) =>
+    executeAsSmallet(smalletWrapper, [ix], ownerA, [ownerC]);
+
+  // Expects executing `ix` as the smallet to fail with `code`
+  const expectAuthRejected = async (
+    ix: TransactionInstruction,
+    code: number
+  ) => {
+    try {
+      await executeAuth(ix);
+      expect.fail("instruction was accepted");
+    } catch (e) {
+      const err = e as Error;
+      expect(err.message).to.include(`0x${code.toString(16)}`);
+    }
+  };
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 4,
+      owners,
+      numGuardians: owners.length,
+      guardians: owners,
+      threshold: new BN(2),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+  });
+
+  it("restricts ownerB to proposing and executing", async () => {
+    const roles = [
+      OwnerRole.All,
+      OwnerRole.Proposer | OwnerRole.Executor,
+      OwnerRole.All,
+    ];
+    const [ix] = smalletWrapper.setOwnerRoles(roles).instructions;
+    await executeAuth(ix);
+
+    const data = await smalletWrapper.reloadData();
+    expect([...data.ownerRoles]).to.deep.equal(roles);
+  });
+
+  it("only approvers can approve", async () => {
+    const { transactionKey, tx } = await smalletWrapper.newTransaction({
+      proposer: ownerB.publicKey,
+      instructions: smalletWrapper.changeThreshold(1).instructions,
+    });
+    await expectTX(tx.addSigners(ownerB), "ownerB proposes").to.be.fulfilled;
+
+    // The proposal of ownerB does not count as an approval
+    const txData = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(txData.signers[1]).to.be.false;
+
+    await expectTX(
+      smalletWrapper
+        .approveTransaction(transactionKey, ownerB.publicKey)
+        .addSigners(ownerB),
+      "ownerB approves"
+    ).to.be.rejectedWith(`0x${SmalletErrors.MissingRole.code.toString(16)}`);
+  });
+
+  it("rejects roles leaving the threshold unreachable", async () => {
+    const [ix] = smalletWrapper.setOwnerRoles([
+      OwnerRole.All,
+      OwnerRole.Proposer,
+      OwnerRole.Proposer,
+    ]).instructions;
+    await expectAuthRejected(ix, SmalletErrors.InvalidOwnerRoles.code);
+  });
+
+  it("rejects a threshold above the number of approvers", async () => {
+    const [ix] = smalletWrapper.changeThreshold(3).instructions;
+    await expectAuthRejected(ix, SmalletErrors.InvalidOwnerRoles.code);
+  });
+
+  it("rejects value tiers above the number of approvers", async () => {
+    const [ix] = smalletWrapper.setValueTiers([
+      {
+        mint: NATIVE_MINT,
+        maxAmount: new BN(LAMPORTS_PER_SOL),
+        threshold: new BN(3),
+      },
+    ]).instructions;
+    await expectAuthRejected(ix, SmalletErrors.InvalidOwnerRoles.code);
+  });
+
+  it("retained owners keep their roles when owners change", async () => {
+    const newOwners = [ownerA.publicKey, ownerB.publicKey, ownerD.publicKey];
+    const [ix] = smalletWrapper.setOwners(newOwners).instructions;
+    await executeAuth(ix);
+
+    const data = await smalletWrapper.reloadData();
+    expect(data.owners).to.deep.equal(newOwners);
+    expect([...data.ownerRoles]).to.deep.equal([
+      OwnerRole.All,
+      OwnerRole.Proposer | OwnerRole.Executor,
+      OwnerRole.All,
+    ]);
+  });
+});
@@ -0,0 +1,198 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import type { TransactionEnvelope } from "@saberhq/solana-contrib";
+import type { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { OwnerRole } from "../../src/wrappers/smallet";
+import {
+  airdropLamports,
+  ensureGlobalState,
+  executeAsSmallet,
+} from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the dry-run execution tests
+describe("simulate transaction", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+  const { provider } = sdk;
+
+  const ownerA = Keypair.generate();
+  const ownerB = Keypair.generate();
+  const payer = Keypair.generate();
+  const receiver = Keypair.generate();
+
+  let smalletWrapper: SmalletWrapper;
+  let derivedWallet: PublicKey;
+
+  // Proposes the instructions as ownerA, without further approvals
+  const propose = async (
+    instructions: TransactionInstruction[],
+    prerequisites?: number[]
+  ) => {
+    const { transactionKey, tx, index } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions,
+      prerequisites,
+    });
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+    return { transactionKey, index };
+  };
+
+  // Simulates the transaction signed by the owner, returning the custom
+  // error code it failed with and its logs
+  const simulate = async (tx: TransactionEnvelope, owner: Keypair) => {
+    const transaction = tx.build(payer.publicKey);
+    const { value } = await provider.connection.simulateTransaction(
+      transaction,
+      [payer, owner]
+    );
+    const [, error] = (value.err as {
+      InstructionError: [number, { Custom: number }];
+    }).InstructionError;
+    return { code: error.Custom, logs: value.logs ?? [] };
+  };
+
+  const transfer = (lamports: number) =>
+    SystemProgram.transfer({
+      fromPubkey: derivedWallet,
+      toPubkey: receiver.publicKey,
+      lamports,
+    });
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 2,
+      owners: [ownerA.publicKey, ownerB.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(2),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+    [derivedWallet] = await smalletWrapper.findWalletDerivedAddress(0);
+    await airdropLamports(provider.connection, payer.publicKey, derivedWallet);
+  });
+
+  it("reports the effects without approvals and reverts", async () => {
+    const { transactionKey } = await propose([
+      transfer(LAMPORTS_PER_SOL / 10),
+    ]);
+    const { code, logs } = await simulate(
+      await smalletWrapper.simulateTransaction({
+        transactionKey,
+        walletIndex: 0,
+        owner: ownerA.publicKey,
+      }),
+      ownerA
+    );
+
+    expect(code).to.equal(SmalletErrors.SimulationComplete.code);
+    const { report, failedStep } = smalletWrapper.parseSimulationLogs(logs);
+    expect(failedStep).to.be.null;
+    expect(report?.executed.toNumber()).to.equal(1);
+    expect(report?.failedPostAssertion).to.be.null;
+    const delta = report?.lamportDeltas.find(({ account }) =>
+      account.equals(derivedWallet)
+    );
+    expect(delta?.delta.toString()).to.equal(`-${LAMPORTS_PER_SOL / 10}`);
+    expect(await provider.connection.getBalance(derivedWallet)).to.equal(
+      LAMPORTS_PER_SOL
+    );
+    const data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.executedAt.toNumber()).to.equal(-1);
+  });
+
+  it("checks the prerequisites as on execution", async () => {
+    const first = await propose([transfer(LAMPORTS_PER_SOL / 10)]);
+    const { transactionKey } = await propose(
+      [transfer(LAMPORTS_PER_SOL / 10)],
+      [first.index]
+    );
+    const { code } = await simulate(
+      await smalletWrapper.simulateTransaction({
+        transactionKey,
+        walletIndex: 0,
+        owner: ownerA.publicKey,
+      }),
+      ownerA
+    );
+    expect(code).to.equal(SmalletErrors.PrerequisiteNotExecuted.code);
+  });
+
+  it("checks the assertions as on execution", async () => {
+    const { transactionKey, tx } =
+      await smalletWrapper.newTransactionWithAssertions({
+        proposer: ownerA.publicKey,
+        instructions: [transfer(LAMPORTS_PER_SOL / 2)],
+        postAssertions: [
+          {
+            maxLamportsDecrease: {
+              account: derivedWallet,
+              lamports: new BN(LAMPORTS_PER_SOL / 10),
+            },
+          },
+        ],
+      });
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+
+    const { code, logs } = await simulate(
+      await smalletWrapper.simulateTransaction({
+        transactionKey,
+        walletIndex: 0,
+        owner: ownerA.publicKey,
+      }),
+      ownerA
+    );
+    expect(code).to.equal(SmalletErrors.SimulationComplete.code);
+    const { report } = smalletWrapper.parseSimulationLogs(logs);
+    expect(report?.failedPreAssertion).to.be.null;
+    expect(report?.failedPostAssertion?.toNumber()).to.equal(0);
+  });
+
+  it("reports the failing instruction", async () => {
+    const { transactionKey } = await propose([
+      transfer(LAMPORTS_PER_SOL / 10),
+      transfer(LAMPORTS_PER_SOL * 2),
+    ]);
+    const { logs } = await simulate(
+      await smalletWrapper.simulateTransaction({
+        transactionKey,
+        walletIndex: 0,
+        owner: ownerA.publicKey,
+      }),
+      ownerA
+    );
+    const { report, failedStep } = smalletWrapper.parseSimulationLogs(logs);
+    expect(report).to.be.null;
+    expect(failedStep).to.equal(1);
+  });
+
+  it("requires the executor role", async () => {
+    await executeAsSmallet(
+      smalletWrapper,
+      smalletWrapper.setOwnerRoles([OwnerRole.All, OwnerRole.Approver])
+        .instructions,
+      ownerA,
+      [ownerB]
+    );
+    const { transactionKey } = await propose([
+      transfer(LAMPORTS_PER_SOL / 10),
+    ]);
+
+    const { code } = await simulate(
+      await smalletWrapper.simulateTransaction({
+        transactionKey,
+        walletIndex: 0,
+        owner: ownerB.publicKey,
+      }),
+      ownerB
+    );
+    expect(code).to.equal(SmalletErrors.MissingRole.code);
+  });
+});
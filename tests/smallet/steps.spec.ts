@@ -0,0 +1,123 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import type { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import { ensureGlobalState } from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the step by step execution tests
+describe("step execution", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+
+  const ownerA = Keypair.generate();
+  const ownerB = Keypair.generate();
+
+  let smalletWrapper: SmalletWrapper;
+
+  // Proposes the instructions as ownerA, whose approval is enough
+  const propose = async (instructions: TransactionInstruction[]) => {
+    const { transactionKey, tx } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions,
+    });
+    await expectTX(tx.addSigners(ownerA), "propose").to.be.fulfilled;
+    return transactionKey;
+  };
+
+  const executeStep = async (transactionKey: PublicKey, step: number) =>
+    (
+      await smalletWrapper.executeTransactionStep({
+        transactionKey,
+        step,
+        owner: ownerA.publicKey,
+      })
+    ).addSigners(ownerA);
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: 2,
+      owners: [ownerA.publicKey],
+      numGuardians: 1,
+      guardians: [ownerA.publicKey],
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+  });
+
+  it("executes a proposal one instruction at a time", async () => {
+    const transactionKey = await propose([
+      ...smalletWrapper.setLockDuration(100).instructions,
+      ...smalletWrapper.setInactivityPeriod(200).instructions,
+    ]);
+
+    await expectTX(await executeStep(transactionKey, 0), "execute step 0").to
+      .be.fulfilled;
+    let data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.executedSteps.toNumber()).to.equal(1);
+    expect(data.executedAt.toNumber()).to.equal(-1);
+    // The approvals required are fixed when the execution starts
+    expect(data.requiredThreshold.toNumber()).to.equal(1);
+    expect(
+      (await smalletWrapper.reloadData()).lockDuration.toNumber()
+    ).to.equal(100);
+
+    await expectTX(await executeStep(transactionKey, 1), "execute step 1").to
+      .be.fulfilled;
+    data = await smalletWrapper.fetchTransaction(transactionKey);
+    expect(data.executedSteps.toNumber()).to.equal(2);
+    expect(data.executedAt.toNumber()).to.not.equal(-1);
+    expect(
+      (await smalletWrapper.reloadData()).inactivityPeriod.toNumber()
+    ).to.equal(200);
+  });
+
+  it("rejects steps out of order", async () => {
+    const transactionKey = await propose([
+      ...smalletWrapper.setLockDuration(300).instructions,
+      ...smalletWrapper.setLockDuration(400).instructions,
+    ]);
+
+    await expectTX(
+      await executeStep(transactionKey, 1),
+      "execute step 1 first"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.InvalidExecutionStep.code.toString(16)}`
+    );
+    await expectTX(await executeStep(transactionKey, 0), "execute step 0").to
+      .be.fulfilled;
+    await expectTX(await executeStep(transactionKey, 1), "execute step 1").to
+      .be.fulfilled;
+  });
+
+  it("rejects the steps after a step changes the owner set", async () => {
+    const transactionKey = await propose([
+      ...smalletWrapper.setOwners([ownerA.publicKey, ownerB.publicKey])
+        .instructions,
+      ...smalletWrapper.setLockDuration(500).instructions,
+    ]);
+
+    await expectTX(await executeStep(transactionKey, 0), "execute step 0").to
+      .be.fulfilled;
+    const smallet = await smalletWrapper.reloadData();
+    expect(smallet.owners).to.have.lengthOf(2);
+    expect(smallet.pendingTransactions.toNumber()).to.equal(0);
+
+    // Like every other transaction, the rest was approved by the old owner set
+    await expectTX(
+      await executeStep(transactionKey, 1),
+      "execute step 1"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.OwnerSetChanged.code.toString(16)}`
+    );
+    expect(
+      (await smalletWrapper.reloadData()).lockDuration.toNumber()
+    ).to.not.equal(500);
+  });
+});
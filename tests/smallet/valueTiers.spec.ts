@@ -0,0 +1,189 @@
+import * as anchor from "@project-serum/anchor";
+import { expectTX } from "@saberhq/chai-solana";
+import { NATIVE_MINT } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { SmalletErrors } from "../../src/idls/smallet";
+import type { SmalletWrapper } from "../../src/wrappers/smallet";
+import {
+  airdropLamports,
+  ensureGlobalState,
+  executeAsSmallet,
+} from "../common";
+import { makeSDK } from "../workspace";
+
+// Define the value tier tests
+describe("value tiers", () => {
+  const { BN } = anchor;
+  const sdk = makeSDK();
+  const { provider } = sdk;
+
+  const ownerA = Keypair.generate();
+  const ownerB = Keypair.generate();
+  const ownerC = Keypair.generate();
+  const owners = [ownerA.publicKey, ownerB.publicKey, ownerC.publicKey];
+  const recipient = Keypair.generate().publicKey;
+
+  let smalletWrapper: SmalletWrapper;
+  let derivedWallet: PublicKey;
+
+  // Proposes a transfer of `lamports` from `from` as ownerA
+  const proposeTransfer = async (from: PublicKey, lamports: number) => {
+    const { transactionKey, tx } = await smalletWrapper.newTransaction({
+      proposer: ownerA.publicKey,
+      instructions: [
+        SystemProgram.transfer({
+          fromPubkey: from,
+          toPubkey: recipient,
+          lamports,
+        }),
+      ],
+    });
+    await expectTX(tx.addSigners(ownerA), "propose transfer").to.be.fulfilled;
+    return transactionKey;
+  };
+
+  const executeDerived = async (transactionKey: PublicKey) =>
+    (
+      await smalletWrapper.executeTransactionDerived({
+        transactionKey,
+        walletIndex: 0,
+        owner: ownerA.publicKey,
+      })
+    ).addSigners(ownerA);
+
+  before(async () => {
+    await ensureGlobalState(sdk);
+    const { smalletWrapper: wrapperInner, tx } = await sdk.newSmallet({
+      numOwners: owners.length,
+      owners,
+      numGuardians: owners.length,
+      guardians: owners,
+      threshold: new BN(1),
+    });
+    await expectTX(tx, "create new smallet").to.be.fulfilled;
+    smalletWrapper = wrapperInner;
+
+    // Below 0.1 SOL one approval is enough, below 0.5 SOL two are needed
+    // and larger outflows need every owner
+    const [setTiersIx] = smalletWrapper.setValueTiers([
+      {
+        mint: NATIVE_MINT,
+        maxAmount: new BN(LAMPORTS_PER_SOL / 10),
+        threshold: new BN(1),
+      },
+      {
+        mint: NATIVE_MINT,
+        maxAmount: new BN(LAMPORTS_PER_SOL / 2),
+        threshold: new BN(2),
+      },
+    ]).instructions;
+    await executeAsSmallet(smalletWrapper, [setTiersIx], ownerA);
+    const data = await smalletWrapper.reloadData();
+    expect(data.valueTiers).to.have.lengthOf(2);
+
+    [derivedWallet] = await smalletWrapper.findWalletDerivedAddress(0);
+    await airdropLamports(provider.connection, derivedWallet);
+  });
+
+  it("rejects tiers out of order", async () => {
+    const [setTiersIx] = smalletWrapper.setValueTiers([
+      {
+        mint: NATIVE_MINT,
+        maxAmount: new BN(LAMPORTS_PER_SOL),
+        threshold: new BN(2),
+      },
+      {
+        mint: NATIVE_MINT,
+        maxAmount: new BN(LAMPORTS_PER_SOL / 2),
+        threshold: new BN(1),
+      },
+    ]).instructions;
+    try {
+      await executeAsSmallet(smalletWrapper, [setTiersIx], ownerA);
+      expect.fail("out of order tiers were accepted");
+    } catch (e) {
+      const err = e as Error;
+      expect(err.message).to.include(
+        `0x${SmalletErrors.InvalidValueTiers.code.toString(16)}`
+      );
+    }
+  });
+
+  it("executes small transfers at the smallet threshold", async () => {
+    const transactionKey = await proposeTransfer(
+      derivedWallet,
+      LAMPORTS_PER_SOL / 20
+    );
+    await expectTX(await executeDerived(transactionKey), "small transfer").to
+      .be.fulfilled;
+  });
+
+  it("requires the tier threshold for larger transfers", async () => {
+    const transactionKey = await proposeTransfer(
+      derivedWallet,
+      LAMPORTS_PER_SOL / 5
+    );
+    await expectTX(
+      await executeDerived(transactionKey),
+      "tier transfer with one approval"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.NotEnoughSigners.code.toString(16)}`
+    );
+
+    await expectTX(
+      smalletWrapper
+        .approveTransaction(transactionKey, ownerB.publicKey)
+        .addSigners(ownerB),
+      "ownerB approves"
+    ).to.be.fulfilled;
+    await expectTX(
+      await executeDerived(transactionKey),
+      "tier transfer with two approvals"
+    ).to.be.fulfilled;
+  });
+
+  it("requires every owner above the highest tier", async () => {
+    const transactionKey = await proposeTransfer(
+      derivedWallet,
+      LAMPORTS_PER_SOL / 2
+    );
+    await expectTX(
+      smalletWrapper
+        .approveTransaction(transactionKey, ownerB.publicKey)
+        .addSigners(ownerB),
+      "ownerB approves"
+    ).to.be.fulfilled;
+    await expectTX(
+      await executeDerived(transactionKey),
+      "large transfer with two approvals"
+    ).to.be.rejectedWith(
+      `0x${SmalletErrors.NotEnoughSigners.code.toString(16)}`
+    );
+
+    await expectTX(
+      smalletWrapper
+        .approveTransaction(transactionKey, ownerC.publicKey)
+        .addSigners(ownerC),
+      "ownerC approves"
+    ).to.be.fulfilled;
+    await expectTX(
+      await executeDerived(transactionKey),
+      "large transfer with every approval"
+    ).to.be.fulfilled;
+  });
+
+  it("ignores transfers the smallet does not sign", async () => {
+    // The fee payer signs this transfer itself, so no smallet funds move
+    const transactionKey = await proposeTransfer(
+      provider.wallet.publicKey,
+      LAMPORTS_PER_SOL / 2
+    );
+    await expectTX(
+      await executeDerived(transactionKey),
+      "foreign transfer with one approval"
+    ).to.be.fulfilled;
+  });
+});